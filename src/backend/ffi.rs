@@ -0,0 +1,1094 @@
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+use blas_sys;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use complex::Complex;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use lapack_sys;
+
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+use traits::Backend;
+use traits::Element;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use traits::{Lapack, LapackBackend};
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+use {Diagonal, Side, Transpose, Uplo};
+use Ffi;
+
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+fn blas_diag(diag: &Diagonal) -> blas_sys::Diag {
+    match *diag {
+        Diagonal::NonUnit => blas_sys::Diag::NonUnit,
+        Diagonal::Unit => blas_sys::Diag::Unit,
+    }
+}
+
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+fn blas_side(side: &Side) -> blas_sys::Side {
+    match *side {
+        Side::Left => blas_sys::Side::Left,
+        Side::Right => blas_sys::Side::Right,
+    }
+}
+
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+fn blas_uplo(uplo: &Uplo) -> blas_sys::Uplo {
+    match *uplo {
+        Uplo::Lower => blas_sys::Uplo::Lower,
+        Uplo::Upper => blas_sys::Uplo::Upper,
+    }
+}
+
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+fn transpose(trans: &Transpose) -> blas_sys::Transpose {
+    match *trans {
+        Transpose::No => blas_sys::Transpose::No,
+        Transpose::Yes => blas_sys::Transpose::Yes,
+        Transpose::Conj => blas_sys::Transpose::Conj,
+    }
+}
+
+// NB Read-only arrays are cast to `*mut T` because not all the FFI signatures mark them as
+// `*const T`
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+impl<T> Backend<T> for Ffi where
+    T: Element,
+    T: blas_sys::Axpy + blas_sys::Copy + blas_sys::Dot + blas_sys::Gbmv + blas_sys::Gemm,
+    T: blas_sys::Gemv + blas_sys::Ger,
+    T: blas_sys::Nrm2<Output=<T as Element>::Real>,
+    T: blas_sys::Scal<T> + blas_sys::Scal<<T as Element>::Real>,
+    T: blas_sys::Symm + blas_sys::Symv + blas_sys::Syrk,
+    T: blas_sys::Trmm + blas_sys::Trmv + blas_sys::Trsm + blas_sys::Trsv,
+{
+    unsafe fn axpy(&self, n: &i32, alpha: &T, x: *const T, incx: &i32, y: *mut T, incy: &i32) {
+        let axpy = <T as blas_sys::Axpy>::axpy();
+
+        axpy(n, alpha, x, incx, y, incy)
+    }
+
+    unsafe fn copy(&self, n: &i32, x: *const T, incx: &i32, y: *mut T, incy: &i32) {
+        let copy = <T as blas_sys::Copy>::copy();
+
+        copy(n, x, incx, y, incy)
+    }
+
+    unsafe fn dot(&self, n: &i32, x: *const T, incx: &i32, y: *const T, incy: &i32) -> T {
+        let dot = <T as blas_sys::Dot>::dot();
+
+        dot(n, x as *mut T, incx, y as *mut T, incy)
+    }
+
+    unsafe fn gbmv(
+        &self,
+        trans: &Transpose,
+        m: &i32,
+        n: &i32,
+        kl: &i32,
+        ku: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    ) {
+        let gbmv = <T as blas_sys::Gbmv>::gbmv();
+        let ref trans = transpose(trans);
+        let a = a as *mut T;
+        let x = x as *mut T;
+
+        gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn gemm(
+        &self,
+        transa: &Transpose,
+        transb: &Transpose,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *const T,
+        ldb: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    ) {
+        let gemm = <T as blas_sys::Gemm>::gemm();
+        let ref transa = transpose(transa);
+        let ref transb = transpose(transb);
+        let a = a as *mut T;
+        let b = b as *mut T;
+
+        gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+
+    unsafe fn gemv(
+        &self,
+        trans: &Transpose,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    ) {
+        let gemv = <T as blas_sys::Gemv>::gemv();
+        let ref trans = transpose(trans);
+        let a = a as *mut T;
+        let x = x as *mut T;
+
+        gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn ger(
+        &self,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        x: *const T,
+        incx: &i32,
+        y: *const T,
+        incy: &i32,
+        a: *mut T,
+        lda: &i32,
+    ) {
+        let ger = <T as blas_sys::Ger>::ger();
+
+        ger(m, n, alpha, x as *mut T, incx, y as *mut T, incy, a, lda)
+    }
+
+    unsafe fn nrm2(&self, n: &i32, x: *const T, incx: &i32) -> T::Real {
+        let nrm2 = <T as blas_sys::Nrm2>::nrm2();
+
+        nrm2(n, x, incx)
+    }
+
+    unsafe fn rscal(&self, n: &i32, alpha: &T::Real, x: *mut T, incx: &i32) {
+        let scal = <T as blas_sys::Scal<T::Real>>::scal();
+
+        scal(n, alpha, x, incx)
+    }
+
+    unsafe fn scal(&self, n: &i32, alpha: &T, x: *mut T, incx: &i32) {
+        let scal = <T as blas_sys::Scal<T>>::scal();
+
+        scal(n, alpha, x, incx)
+    }
+
+    unsafe fn symm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *const T,
+        ldb: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    ) {
+        let symm = <T as blas_sys::Symm>::symm();
+        let ref side = blas_side(side);
+        let ref uplo = blas_uplo(uplo);
+        let a = a as *mut T;
+        let b = b as *mut T;
+
+        symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+
+    unsafe fn symv(
+        &self,
+        uplo: &Uplo,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    ) {
+        let symv = <T as blas_sys::Symv>::symv();
+        let ref uplo = blas_uplo(uplo);
+        let a = a as *mut T;
+        let x = x as *mut T;
+
+        symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn syrk(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        n: &i32,
+        k: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    ) {
+        let syrk = <T as blas_sys::Syrk>::syrk();
+        let ref uplo = blas_uplo(uplo);
+        let ref trans = transpose(trans);
+        let a = a as *mut T;
+
+        syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+    }
+
+    unsafe fn trmm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        transa: &Transpose,
+        diag: &Diagonal,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+    ) {
+        let trmm = <T as blas_sys::Trmm>::trmm();
+        let ref side = blas_side(side);
+        let ref uplo = blas_uplo(uplo);
+        let ref transa = transpose(transa);
+        let ref diag = blas_diag(diag);
+        let a = a as *mut T;
+
+        trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+    }
+
+    unsafe fn trmv(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        diag: &Diagonal,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        x: *mut T,
+        incx: &i32,
+    ) {
+        let trmv = <T as blas_sys::Trmv>::trmv();
+        let ref uplo = blas_uplo(uplo);
+        let ref trans = transpose(trans);
+        let ref diag = blas_diag(diag);
+        let a = a as *mut T;
+
+        trmv(uplo, trans, diag, n, a, lda, x, incx)
+    }
+
+    unsafe fn trsm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        transa: &Transpose,
+        diag: &Diagonal,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+    ) {
+        let trsm = <T as blas_sys::Trsm>::trsm();
+        let ref side = blas_side(side);
+        let ref uplo = blas_uplo(uplo);
+        let ref transa = transpose(transa);
+        let ref diag = blas_diag(diag);
+        let a = a as *mut T;
+
+        trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+    }
+
+    unsafe fn trsv(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        diag: &Diagonal,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        x: *mut T,
+        incx: &i32,
+    ) {
+        let trsv = <T as blas_sys::Trsv>::trsv();
+        let ref uplo = blas_uplo(uplo);
+        let ref trans = transpose(trans);
+        let ref diag = blas_diag(diag);
+        let a = a as *mut T;
+
+        trsv(uplo, trans, diag, n, a, lda, x, incx)
+    }
+}
+
+/// The LAPACK routines whose real and complex flavors take different arguments
+///
+/// The `LapackBackend` signature of these routines is the union of both flavors, each
+/// implementation only forwards the arguments that its flavor takes
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub trait Flavor: Element {
+    /// See `LapackBackend::gecon`
+    unsafe fn gecon(
+        norm: &u8,
+        n: &i32,
+        a: *const Self,
+        lda: &i32,
+        anorm: &Self::Real,
+        rcond: &mut Self::Real,
+        work: *mut Self,
+        rwork: *mut Self::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    );
+
+    /// See `LapackBackend::geev`
+    unsafe fn geev(
+        jobvl: &u8,
+        jobvr: &u8,
+        n: &i32,
+        a: *mut Self,
+        lda: &i32,
+        w: *mut Self,
+        wi: *mut Self,
+        vl: *mut Self,
+        ldvl: &i32,
+        vr: *mut Self,
+        ldvr: &i32,
+        work: *mut Self,
+        lwork: &i32,
+        rwork: *mut Self::Real,
+        info: &mut i32,
+    );
+
+    /// See `LapackBackend::gelsd`
+    unsafe fn gelsd(
+        m: &i32,
+        n: &i32,
+        nrhs: &i32,
+        a: *mut Self,
+        lda: &i32,
+        b: *mut Self,
+        ldb: &i32,
+        s: *mut Self::Real,
+        rcond: &Self::Real,
+        rank: &mut i32,
+        work: *mut Self,
+        lwork: &i32,
+        rwork: *mut Self::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    );
+
+    /// See `LapackBackend::gesdd`
+    unsafe fn gesdd(
+        jobz: &u8,
+        m: &i32,
+        n: &i32,
+        a: *mut Self,
+        lda: &i32,
+        s: *mut Self::Real,
+        u: *mut Self,
+        ldu: &i32,
+        vt: *mut Self,
+        ldvt: &i32,
+        work: *mut Self,
+        lwork: &i32,
+        rwork: *mut Self::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    );
+
+    /// See `LapackBackend::heevd`
+    unsafe fn heevd(
+        jobz: &u8,
+        uplo: &u8,
+        n: &i32,
+        a: *mut Self,
+        lda: &i32,
+        w: *mut Self::Real,
+        work: *mut Self,
+        lwork: &i32,
+        rwork: *mut Self::Real,
+        lrwork: &i32,
+        iwork: *mut i32,
+        liwork: &i32,
+        info: &mut i32,
+    );
+
+    /// See `LapackBackend::heevr`
+    unsafe fn heevr(
+        jobz: &u8,
+        range: &u8,
+        uplo: &u8,
+        n: &i32,
+        a: *mut Self,
+        lda: &i32,
+        vl: &Self::Real,
+        vu: &Self::Real,
+        il: &i32,
+        iu: &i32,
+        abstol: &Self::Real,
+        m: &mut i32,
+        w: *mut Self::Real,
+        z: *mut Self,
+        ldz: &i32,
+        isuppz: *mut i32,
+        work: *mut Self,
+        lwork: &i32,
+        rwork: *mut Self::Real,
+        lrwork: &i32,
+        iwork: *mut i32,
+        liwork: &i32,
+        info: &mut i32,
+    );
+}
+
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+macro_rules! flavor {
+    ($($t:ident),+) => {
+        $(
+            impl Flavor for $t {
+                unsafe fn gecon(
+                    norm: &u8,
+                    n: &i32,
+                    a: *const $t,
+                    lda: &i32,
+                    anorm: &$t,
+                    rcond: &mut $t,
+                    work: *mut $t,
+                    _: *mut $t,
+                    iwork: *mut i32,
+                    info: &mut i32,
+                ) {
+                    let gecon = <$t as lapack_sys::Gecon>::gecon();
+
+                    gecon(norm, n, a as *mut $t, lda, anorm, rcond, work, iwork, info)
+                }
+
+                unsafe fn geev(
+                    jobvl: &u8,
+                    jobvr: &u8,
+                    n: &i32,
+                    a: *mut $t,
+                    lda: &i32,
+                    wr: *mut $t,
+                    wi: *mut $t,
+                    vl: *mut $t,
+                    ldvl: &i32,
+                    vr: *mut $t,
+                    ldvr: &i32,
+                    work: *mut $t,
+                    lwork: &i32,
+                    _: *mut $t,
+                    info: &mut i32,
+                ) {
+                    let geev = <$t as lapack_sys::Geev>::geev();
+
+                    geev(
+                        jobvl, jobvr, n, a, lda, wr, wi, vl, ldvl, vr, ldvr, work, lwork, info,
+                    )
+                }
+
+                unsafe fn gelsd(
+                    m: &i32,
+                    n: &i32,
+                    nrhs: &i32,
+                    a: *mut $t,
+                    lda: &i32,
+                    b: *mut $t,
+                    ldb: &i32,
+                    s: *mut $t,
+                    rcond: &$t,
+                    rank: &mut i32,
+                    work: *mut $t,
+                    lwork: &i32,
+                    _: *mut $t,
+                    iwork: *mut i32,
+                    info: &mut i32,
+                ) {
+                    let gelsd = <$t as lapack_sys::Gelsd>::gelsd();
+
+                    gelsd(m, n, nrhs, a, lda, b, ldb, s, rcond, rank, work, lwork, iwork, info)
+                }
+
+                unsafe fn gesdd(
+                    jobz: &u8,
+                    m: &i32,
+                    n: &i32,
+                    a: *mut $t,
+                    lda: &i32,
+                    s: *mut $t,
+                    u: *mut $t,
+                    ldu: &i32,
+                    vt: *mut $t,
+                    ldvt: &i32,
+                    work: *mut $t,
+                    lwork: &i32,
+                    _: *mut $t,
+                    iwork: *mut i32,
+                    info: &mut i32,
+                ) {
+                    let gesdd = <$t as lapack_sys::Gesdd>::gesdd();
+
+                    gesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, iwork, info)
+                }
+
+                unsafe fn heevd(
+                    jobz: &u8,
+                    uplo: &u8,
+                    n: &i32,
+                    a: *mut $t,
+                    lda: &i32,
+                    w: *mut $t,
+                    work: *mut $t,
+                    lwork: &i32,
+                    _: *mut $t,
+                    _: &i32,
+                    iwork: *mut i32,
+                    liwork: &i32,
+                    info: &mut i32,
+                ) {
+                    let syevd = <$t as lapack_sys::Syevd>::syevd();
+
+                    syevd(jobz, uplo, n, a, lda, w, work, lwork, iwork, liwork, info)
+                }
+
+                unsafe fn heevr(
+                    jobz: &u8,
+                    range: &u8,
+                    uplo: &u8,
+                    n: &i32,
+                    a: *mut $t,
+                    lda: &i32,
+                    vl: &$t,
+                    vu: &$t,
+                    il: &i32,
+                    iu: &i32,
+                    abstol: &$t,
+                    m: &mut i32,
+                    w: *mut $t,
+                    z: *mut $t,
+                    ldz: &i32,
+                    isuppz: *mut i32,
+                    work: *mut $t,
+                    lwork: &i32,
+                    _: *mut $t,
+                    _: &i32,
+                    iwork: *mut i32,
+                    liwork: &i32,
+                    info: &mut i32,
+                ) {
+                    let syevr = <$t as lapack_sys::Syevr>::syevr();
+
+                    syevr(
+                        jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz,
+                        isuppz, work, lwork, iwork, liwork, info,
+                    )
+                }
+            }
+
+            impl Flavor for Complex<$t> {
+                unsafe fn gecon(
+                    norm: &u8,
+                    n: &i32,
+                    a: *const Complex<$t>,
+                    lda: &i32,
+                    anorm: &$t,
+                    rcond: &mut $t,
+                    work: *mut Complex<$t>,
+                    rwork: *mut $t,
+                    _: *mut i32,
+                    info: &mut i32,
+                ) {
+                    let gecon = <Complex<$t> as lapack_sys::Gecon>::gecon();
+                    let a = a as *mut Complex<$t>;
+
+                    gecon(norm, n, a, lda, anorm, rcond, work, rwork, info)
+                }
+
+                unsafe fn geev(
+                    jobvl: &u8,
+                    jobvr: &u8,
+                    n: &i32,
+                    a: *mut Complex<$t>,
+                    lda: &i32,
+                    w: *mut Complex<$t>,
+                    _: *mut Complex<$t>,
+                    vl: *mut Complex<$t>,
+                    ldvl: &i32,
+                    vr: *mut Complex<$t>,
+                    ldvr: &i32,
+                    work: *mut Complex<$t>,
+                    lwork: &i32,
+                    rwork: *mut $t,
+                    info: &mut i32,
+                ) {
+                    let geev = <Complex<$t> as lapack_sys::Geev>::geev();
+
+                    geev(
+                        jobvl, jobvr, n, a, lda, w, vl, ldvl, vr, ldvr, work, lwork, rwork, info,
+                    )
+                }
+
+                unsafe fn gelsd(
+                    m: &i32,
+                    n: &i32,
+                    nrhs: &i32,
+                    a: *mut Complex<$t>,
+                    lda: &i32,
+                    b: *mut Complex<$t>,
+                    ldb: &i32,
+                    s: *mut $t,
+                    rcond: &$t,
+                    rank: &mut i32,
+                    work: *mut Complex<$t>,
+                    lwork: &i32,
+                    rwork: *mut $t,
+                    iwork: *mut i32,
+                    info: &mut i32,
+                ) {
+                    let gelsd = <Complex<$t> as lapack_sys::Gelsd>::gelsd();
+
+                    gelsd(
+                        m, n, nrhs, a, lda, b, ldb, s, rcond, rank, work, lwork, rwork, iwork,
+                        info,
+                    )
+                }
+
+                unsafe fn gesdd(
+                    jobz: &u8,
+                    m: &i32,
+                    n: &i32,
+                    a: *mut Complex<$t>,
+                    lda: &i32,
+                    s: *mut $t,
+                    u: *mut Complex<$t>,
+                    ldu: &i32,
+                    vt: *mut Complex<$t>,
+                    ldvt: &i32,
+                    work: *mut Complex<$t>,
+                    lwork: &i32,
+                    rwork: *mut $t,
+                    iwork: *mut i32,
+                    info: &mut i32,
+                ) {
+                    let gesdd = <Complex<$t> as lapack_sys::Gesdd>::gesdd();
+
+                    gesdd(
+                        jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, rwork, iwork, info,
+                    )
+                }
+
+                unsafe fn heevd(
+                    jobz: &u8,
+                    uplo: &u8,
+                    n: &i32,
+                    a: *mut Complex<$t>,
+                    lda: &i32,
+                    w: *mut $t,
+                    work: *mut Complex<$t>,
+                    lwork: &i32,
+                    rwork: *mut $t,
+                    lrwork: &i32,
+                    iwork: *mut i32,
+                    liwork: &i32,
+                    info: &mut i32,
+                ) {
+                    let heevd = <Complex<$t> as lapack_sys::Heevd>::heevd();
+
+                    heevd(
+                        jobz, uplo, n, a, lda, w, work, lwork, rwork, lrwork, iwork, liwork, info,
+                    )
+                }
+
+                unsafe fn heevr(
+                    jobz: &u8,
+                    range: &u8,
+                    uplo: &u8,
+                    n: &i32,
+                    a: *mut Complex<$t>,
+                    lda: &i32,
+                    vl: &$t,
+                    vu: &$t,
+                    il: &i32,
+                    iu: &i32,
+                    abstol: &$t,
+                    m: &mut i32,
+                    w: *mut $t,
+                    z: *mut Complex<$t>,
+                    ldz: &i32,
+                    isuppz: *mut i32,
+                    work: *mut Complex<$t>,
+                    lwork: &i32,
+                    rwork: *mut $t,
+                    lrwork: &i32,
+                    iwork: *mut i32,
+                    liwork: &i32,
+                    info: &mut i32,
+                ) {
+                    let heevr = <Complex<$t> as lapack_sys::Heevr>::heevr();
+
+                    heevr(
+                        jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz,
+                        isuppz, work, lwork, rwork, lrwork, iwork, liwork, info,
+                    )
+                }
+            }
+         )+
+    }
+}
+
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+flavor!(f32, f64);
+
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+impl<T> LapackBackend<T> for Ffi where
+    T: Flavor + Lapack,
+    T: lapack_sys::Gbsv + lapack_sys::Geqrf + lapack_sys::Getrf,
+    T: lapack_sys::Getri + lapack_sys::Getrs + lapack_sys::Gtsv,
+    T: lapack_sys::Lange<Output=<T as Element>::Real> + lapack_sys::Orgqr,
+    T: lapack_sys::Ormqr + lapack_sys::Potrf + lapack_sys::Potri + lapack_sys::Potrs,
+    T: lapack_sys::Trtrs,
+{
+    unsafe fn gbsv(
+        &self,
+        n: &i32,
+        kl: &i32,
+        ku: &i32,
+        nrhs: &i32,
+        ab: *mut T,
+        ldab: &i32,
+        ipiv: *mut i32,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        let gbsv = <T as lapack_sys::Gbsv>::gbsv();
+
+        gbsv(n, kl, ku, nrhs, ab, ldab, ipiv, b, ldb, info)
+    }
+
+    unsafe fn gecon(
+        &self,
+        norm: &u8,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        anorm: &T::Real,
+        rcond: &mut T::Real,
+        work: *mut T,
+        rwork: *mut T::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    ) {
+        T::gecon(norm, n, a, lda, anorm, rcond, work, rwork, iwork, info)
+    }
+
+    unsafe fn geev(
+        &self,
+        jobvl: &u8,
+        jobvr: &u8,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        w: *mut T,
+        wi: *mut T,
+        vl: *mut T,
+        ldvl: &i32,
+        vr: *mut T,
+        ldvr: &i32,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        info: &mut i32,
+    ) {
+        T::geev(jobvl, jobvr, n, a, lda, w, wi, vl, ldvl, vr, ldvr, work, lwork, rwork, info)
+    }
+
+    unsafe fn gelsd(
+        &self,
+        m: &i32,
+        n: &i32,
+        nrhs: &i32,
+        a: *mut T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+        s: *mut T::Real,
+        rcond: &T::Real,
+        rank: &mut i32,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    ) {
+        T::gelsd(m, n, nrhs, a, lda, b, ldb, s, rcond, rank, work, lwork, rwork, iwork, info)
+    }
+
+    unsafe fn geqrf(
+        &self,
+        m: &i32,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        tau: *mut T,
+        work: *mut T,
+        lwork: &i32,
+        info: &mut i32,
+    ) {
+        let geqrf = <T as lapack_sys::Geqrf>::geqrf();
+
+        geqrf(m, n, a, lda, tau, work, lwork, info)
+    }
+
+    unsafe fn gesdd(
+        &self,
+        jobz: &u8,
+        m: &i32,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: &i32,
+        vt: *mut T,
+        ldvt: &i32,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    ) {
+        T::gesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, rwork, iwork, info)
+    }
+
+    unsafe fn getrf(
+        &self,
+        m: &i32,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        ipiv: *mut i32,
+        info: &mut i32,
+    ) {
+        let getrf = <T as lapack_sys::Getrf>::getrf();
+
+        getrf(m, n, a, lda, ipiv, info)
+    }
+
+    unsafe fn getri(
+        &self,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        ipiv: *const i32,
+        work: *mut T,
+        lwork: &i32,
+        info: &mut i32,
+    ) {
+        let getri = <T as lapack_sys::Getri>::getri();
+
+        getri(n, a, lda, ipiv, work, lwork, info)
+    }
+
+    unsafe fn getrs(
+        &self,
+        trans: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const T,
+        lda: &i32,
+        ipiv: *const i32,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        let getrs = <T as lapack_sys::Getrs>::getrs();
+
+        getrs(trans, n, nrhs, a as *mut T, lda, ipiv, b, ldb, info)
+    }
+
+    unsafe fn gtsv(
+        &self,
+        n: &i32,
+        nrhs: &i32,
+        dl: *mut T,
+        d: *mut T,
+        du: *mut T,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        let gtsv = <T as lapack_sys::Gtsv>::gtsv();
+
+        gtsv(n, nrhs, dl, d, du, b, ldb, info)
+    }
+
+    unsafe fn heevd(
+        &self,
+        jobz: &u8,
+        uplo: &u8,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        w: *mut T::Real,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        lrwork: &i32,
+        iwork: *mut i32,
+        liwork: &i32,
+        info: &mut i32,
+    ) {
+        T::heevd(jobz, uplo, n, a, lda, w, work, lwork, rwork, lrwork, iwork, liwork, info)
+    }
+
+    unsafe fn heevr(
+        &self,
+        jobz: &u8,
+        range: &u8,
+        uplo: &u8,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        vl: &T::Real,
+        vu: &T::Real,
+        il: &i32,
+        iu: &i32,
+        abstol: &T::Real,
+        m: &mut i32,
+        w: *mut T::Real,
+        z: *mut T,
+        ldz: &i32,
+        isuppz: *mut i32,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        lrwork: &i32,
+        iwork: *mut i32,
+        liwork: &i32,
+        info: &mut i32,
+    ) {
+        T::heevr(
+            jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz, isuppz, work,
+            lwork, rwork, lrwork, iwork, liwork, info,
+        )
+    }
+
+    unsafe fn lange(
+        &self,
+        norm: &u8,
+        m: &i32,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        work: *mut T::Real,
+    ) -> T::Real {
+        let lange = <T as lapack_sys::Lange>::lange();
+
+        lange(norm, m, n, a as *mut T, lda, work)
+    }
+
+    unsafe fn orgqr(
+        &self,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        a: *mut T,
+        lda: &i32,
+        tau: *const T,
+        work: *mut T,
+        lwork: &i32,
+        info: &mut i32,
+    ) {
+        let orgqr = <T as lapack_sys::Orgqr>::orgqr();
+
+        orgqr(m, n, k, a, lda, tau, work, lwork, info)
+    }
+
+    unsafe fn ormqr(
+        &self,
+        side: &u8,
+        trans: &u8,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        a: *const T,
+        lda: &i32,
+        tau: *const T,
+        c: *mut T,
+        ldc: &i32,
+        work: *mut T,
+        lwork: &i32,
+        info: &mut i32,
+    ) {
+        let ormqr = <T as lapack_sys::Ormqr>::ormqr();
+        let a = a as *mut T;
+
+        ormqr(side, trans, m, n, k, a, lda, tau, c, ldc, work, lwork, info)
+    }
+
+    unsafe fn potrf(&self, uplo: &u8, n: &i32, a: *mut T, lda: &i32, info: &mut i32) {
+        let potrf = <T as lapack_sys::Potrf>::potrf();
+
+        potrf(uplo, n, a, lda, info)
+    }
+
+    unsafe fn potri(&self, uplo: &u8, n: &i32, a: *mut T, lda: &i32, info: &mut i32) {
+        let potri = <T as lapack_sys::Potri>::potri();
+
+        potri(uplo, n, a, lda, info)
+    }
+
+    unsafe fn potrs(
+        &self,
+        uplo: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        let potrs = <T as lapack_sys::Potrs>::potrs();
+
+        potrs(uplo, n, nrhs, a as *mut T, lda, b, ldb, info)
+    }
+
+    unsafe fn trtrs(
+        &self,
+        uplo: &u8,
+        trans: &u8,
+        diag: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        let trtrs = <T as lapack_sys::Trtrs>::trtrs();
+
+        trtrs(uplo, trans, diag, n, nrhs, a as *mut T, lda, b, ldb, info)
+    }
+}
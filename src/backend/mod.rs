@@ -0,0 +1,111 @@
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use std::cell::RefCell;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use std::rc::Rc;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use std::thread::LocalKey;
+
+use complex::Complex;
+
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use traits::{Lapack, LapackBackend};
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+use Ffi as DefaultBackend;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use Ffi;
+#[cfg(any(not(feature = "blas"), feature = "pure-rust"))]
+use Native as DefaultBackend;
+use Native;
+
+#[cfg(all(any(feature = "blas", feature = "lapack"), not(feature = "pure-rust")))]
+mod ffi;
+mod native;
+
+/// Implements `Element` for a list of types, each thread starts with `$backend` as the backend
+/// of these types
+///
+/// ``` ignore
+/// #[derive(Clone, Copy, PartialEq)]
+/// struct Rational { .. }
+///
+/// // + impls of `Add`, `Mul`, `One` and `Zero`
+///
+/// element!(Native {
+///     Rational => Rational,
+/// });
+///
+/// // The `Native` backend only needs the arithmetic
+/// impl Scalar for Rational {}
+///
+/// let a = Mat::<Rational>::ones((2, 3));
+/// let b = Mat::<Rational>::ones((3, 4));
+/// let c = (&a * &b).eval();
+/// ```
+#[macro_export]
+macro_rules! element {
+    ($backend:path { $($t:ty => $real:ty),+, }) => {
+        $(
+            impl $crate::traits::Element for $t {
+                type Real = $real;
+
+                fn registry() -> &'static ::std::thread::LocalKey<
+                    ::std::cell::RefCell<::std::rc::Rc<Box<$crate::traits::Backend<$t>>>>
+                > {
+                    thread_local!(static BACKEND: ::std::cell::RefCell<
+                        ::std::rc::Rc<Box<$crate::traits::Backend<$t>>>
+                    > = {
+                        let backend = Box::new($backend) as Box<$crate::traits::Backend<$t>>;
+
+                        ::std::cell::RefCell::new(::std::rc::Rc::new(backend))
+                    });
+
+                    &BACKEND
+                }
+            }
+         )+
+    }
+}
+
+element!(DefaultBackend {
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => f32,
+    Complex<f64> => f64,
+});
+
+element!(Native {
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    isize => isize,
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    usize => usize,
+});
+
+/// Implements `Lapack` for a list of types, each thread starts with `Ffi` as the LAPACK backend of
+/// these types
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+macro_rules! lapack {
+    ($($t:ty),+) => {
+        $(
+            impl Lapack for $t {
+                fn lapack_registry() -> &'static LocalKey<RefCell<Rc<Box<LapackBackend<$t>>>>> {
+                    thread_local!(static BACKEND: RefCell<Rc<Box<LapackBackend<$t>>>> = {
+                        let backend = Box::new(Ffi) as Box<LapackBackend<$t>>;
+
+                        RefCell::new(Rc::new(backend))
+                    });
+
+                    &BACKEND
+                }
+            }
+         )+
+    }
+}
+
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+lapack!(f32, f64, Complex<f32>, Complex<f64>);
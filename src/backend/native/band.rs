@@ -0,0 +1,101 @@
+//! Band matrix-vector kernel
+
+use std::cmp;
+
+use cast::From;
+
+use traits::Scalar;
+use Transpose;
+
+use super::start;
+
+/// y := alpha * op(A) * x + beta * y, where `A` is a band matrix with `kl` sub-diagonals and `ku`
+/// super-diagonals
+///
+/// NB `A[i, j]` is stored at `a[ku + i - j, j]`
+pub unsafe fn gbmv<T>(
+    trans: &Transpose,
+    m: &i32,
+    n: &i32,
+    kl: &i32,
+    ku: &i32,
+    alpha: &T,
+    a: *const T,
+    lda: &i32,
+    x: *const T,
+    incx: &i32,
+    beta: &T,
+    y: *mut T,
+    incy: &i32,
+) where
+    T: Scalar,
+{
+    let m = isize::from(*m);
+    let n = isize::from(*n);
+    let kl = isize::from(*kl);
+    let ku = isize::from(*ku);
+    let lda = isize::from(*lda);
+    let incx = isize::from(*incx);
+    let incy = isize::from(*incy);
+    let alpha = *alpha;
+    let beta = *beta;
+
+    if m == 0 || n == 0 { return }
+
+    let (lenx, leny) = match *trans {
+        Transpose::No => (n, m),
+        Transpose::Yes | Transpose::Conj => (m, n),
+    };
+    let kx = start(lenx, incx);
+    let ky = start(leny, incy);
+
+    // y := beta * y
+    if beta != T::one() {
+        let mut iy = ky;
+
+        for _ in 0..leny {
+            let y = y.offset(iy);
+
+            // NB `y` may be uninitialized when `beta == 0`
+            *y = if beta == T::zero() { T::zero() } else { beta * *y };
+
+            iy += incy;
+        }
+    }
+
+    if alpha == T::zero() { return }
+
+    match *trans {
+        Transpose::No => {
+            for j in 0..n {
+                let a = a.offset(j * lda + ku - j);
+                let temp = alpha * *x.offset(kx + j * incx);
+
+                for i in cmp::max(0, j - ku)..cmp::min(m, j + kl + 1) {
+                    let y = y.offset(ky + i * incy);
+
+                    *y = *y + temp * *a.offset(i);
+                }
+            }
+        },
+        Transpose::Yes | Transpose::Conj => {
+            let conj = *trans == Transpose::Conj;
+
+            for j in 0..n {
+                let a = a.offset(j * lda + ku - j);
+                let mut temp = T::zero();
+
+                for i in cmp::max(0, j - ku)..cmp::min(m, j + kl + 1) {
+                    let a = *a.offset(i);
+                    let a = if conj { a.conj() } else { a };
+
+                    temp = temp + a * *x.offset(kx + i * incx);
+                }
+
+                let y = y.offset(ky + j * incy);
+
+                *y = *y + alpha * temp;
+            }
+        },
+    }
+}
@@ -0,0 +1,117 @@
+//! Blocked matrix-matrix multiplication
+
+use std::cmp;
+
+use cast::From;
+use extract::Extract;
+
+use Transpose;
+use traits::Scalar;
+
+/// Number of rows of `op(A)` that are packed together
+const MC: isize = 64;
+
+/// Number of columns of `op(A)` that are packed together
+const KC: isize = 256;
+
+/// Returns the element at `(i, j)` of `op(A)`
+unsafe fn at<T>(trans: &Transpose, a: *const T, lda: isize, i: isize, j: isize) -> T where
+    T: Scalar,
+{
+    match *trans {
+        Transpose::No => *a.offset(j * lda + i),
+        Transpose::Yes => *a.offset(i * lda + j),
+        Transpose::Conj => (*a.offset(i * lda + j)).conj(),
+    }
+}
+
+/// C := alpha * op(A) * op(B) + beta * C
+///
+/// `op(A)` is processed in `MC x KC` blocks. Each block is packed, in column major order, into a
+/// contiguous buffer small enough to stay in cache while it gets multiplied by the whole `KC`-row
+/// stripe of `op(B)`. Packing also means that the innermost loop always traverses contiguous
+/// memory, regardless of the transposition of `A`.
+pub unsafe fn gemm<T>(
+    transa: &Transpose,
+    transb: &Transpose,
+    m: &i32,
+    n: &i32,
+    k: &i32,
+    alpha: &T,
+    a: *const T,
+    lda: &i32,
+    b: *const T,
+    ldb: &i32,
+    beta: &T,
+    c: *mut T,
+    ldc: &i32,
+) where
+    T: Scalar,
+{
+    let m = isize::from(*m);
+    let n = isize::from(*n);
+    let k = isize::from(*k);
+    let lda = isize::from(*lda);
+    let ldb = isize::from(*ldb);
+    let ldc = isize::from(*ldc);
+    let alpha = *alpha;
+    let beta = *beta;
+
+    if m == 0 || n == 0 { return }
+
+    // C := beta * C
+    if beta != T::one() {
+        for j in 0..n {
+            let c = c.offset(j * ldc);
+
+            for i in 0..m {
+                let c = c.offset(i);
+
+                // NB `C` may be uninitialized when `beta == 0`
+                *c = if beta == T::zero() { T::zero() } else { beta * *c };
+            }
+        }
+    }
+
+    if k == 0 || alpha == T::zero() { return }
+
+    let mut block = Vec::with_capacity(usize::from(MC * KC).extract());
+    let block = block.as_mut_ptr();
+
+    let mut pc = 0;
+    while pc < k {
+        let kc = cmp::min(KC, k - pc);
+
+        let mut ic = 0;
+        while ic < m {
+            let mc = cmp::min(MC, m - ic);
+
+            // block := op(A)[ic..ic+mc, pc..pc+kc]
+            for p in 0..kc {
+                for i in 0..mc {
+                    *block.offset(p * mc + i) = at(transa, a, lda, ic + i, pc + p);
+                }
+            }
+
+            // C[ic..ic+mc, :] += alpha * block * op(B)[pc..pc+kc, :]
+            for j in 0..n {
+                let c = c.offset(j * ldc + ic);
+
+                for p in 0..kc {
+                    let a = block.offset(p * mc);
+                    let temp = alpha * at(transb, b, ldb, pc + p, j);
+
+                    for i in 0..mc {
+                        let c = c.offset(i);
+
+                        *c = *c + temp * *a.offset(i);
+                    }
+                }
+            }
+
+            ic += mc;
+        }
+
+        pc += kc;
+    }
+}
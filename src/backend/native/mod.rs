@@ -0,0 +1,621 @@
+//! Native Rust implementation of the BLAS routines
+//!
+//! The kernels follow the semantics of the reference BLAS (`beta == 0` means that the output is
+//! not read, negative increments traverse the vector backwards, etc).
+//!
+//! NB The kernels only require the arithmetic provided by `Scalar`, that's why `Native` can
+//! operate on any element type, e.g. integers. The triangular solvers also require `Field`, and
+//! `nrm2`/`rscal` require `Normed`.
+
+use std::ops::Div;
+
+use cast::From;
+use complex::Complex;
+
+use traits::{Backend, Field, Normed, Scalar};
+use {Diagonal, Native, Side, Transpose, Uplo};
+
+mod band;
+mod gemm;
+mod sym;
+mod tri;
+
+/// A floating point number
+trait Real: Div<Output=Self> + PartialOrd + Scalar {
+    /// Returns the absolute value of `self`
+    fn abs(self) -> Self;
+
+    /// Returns the square root of `self`
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! float {
+    ($($t:ident),+) => {
+        $(
+            impl Real for $t {
+                fn abs(self) -> $t {
+                    $t::abs(self)
+                }
+
+                fn sqrt(self) -> $t {
+                    $t::sqrt(self)
+                }
+            }
+
+            impl Scalar for $t {}
+
+            impl Field for $t {
+                fn difference(self, rhs: $t) -> $t {
+                    self - rhs
+                }
+
+                fn quotient(self, rhs: $t) -> $t {
+                    self / rhs
+                }
+            }
+
+            impl Normed for $t {
+                unsafe fn nrm2(n: &i32, x: *const $t, incx: &i32) -> $t {
+                    nrm2(n, x, incx)
+                }
+
+                unsafe fn rscal(n: &i32, alpha: &$t, x: *mut $t, incx: &i32) {
+                    scal(n, alpha, x, incx)
+                }
+            }
+
+            impl Scalar for Complex<$t> {
+                fn conj(self) -> Complex<$t> {
+                    Complex { re: self.re, im: -self.im }
+                }
+            }
+
+            impl Field for Complex<$t> {
+                fn difference(self, rhs: Complex<$t>) -> Complex<$t> {
+                    Complex { re: self.re - rhs.re, im: self.im - rhs.im }
+                }
+
+                fn quotient(self, rhs: Complex<$t>) -> Complex<$t> {
+                    let den = rhs.re * rhs.re + rhs.im * rhs.im;
+
+                    Complex {
+                        re: (self.re * rhs.re + self.im * rhs.im) / den,
+                        im: (self.im * rhs.re - self.re * rhs.im) / den,
+                    }
+                }
+            }
+
+            impl Normed for Complex<$t> {
+                unsafe fn nrm2(n: &i32, x: *const Complex<$t>, incx: &i32) -> $t {
+                    cnrm2(n, x, incx)
+                }
+
+                unsafe fn rscal(n: &i32, alpha: &$t, x: *mut Complex<$t>, incx: &i32) {
+                    rscal(n, alpha, x, incx)
+                }
+            }
+         )+
+    }
+}
+
+float!(f32, f64);
+
+macro_rules! integer {
+    ($($t:ident),+) => {
+        $(
+            impl Scalar for $t {}
+
+            impl Field for $t {
+                fn difference(self, rhs: $t) -> $t {
+                    self - rhs
+                }
+
+                fn quotient(self, rhs: $t) -> $t {
+                    self / rhs
+                }
+            }
+         )+
+    }
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<T> Backend<T> for Native where T: Scalar {
+    unsafe fn axpy(&self, n: &i32, alpha: &T, x: *const T, incx: &i32, y: *mut T, incy: &i32) {
+        axpy(n, alpha, x, incx, y, incy)
+    }
+
+    unsafe fn copy(&self, n: &i32, x: *const T, incx: &i32, y: *mut T, incy: &i32) {
+        copy(n, x, incx, y, incy)
+    }
+
+    unsafe fn dot(&self, n: &i32, x: *const T, incx: &i32, y: *const T, incy: &i32) -> T {
+        dot(n, x, incx, y, incy)
+    }
+
+    unsafe fn gbmv(
+        &self,
+        trans: &Transpose,
+        m: &i32,
+        n: &i32,
+        kl: &i32,
+        ku: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    ) {
+        band::gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn gemm(
+        &self,
+        transa: &Transpose,
+        transb: &Transpose,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *const T,
+        ldb: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    ) {
+        gemm::gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+
+    unsafe fn gemv(
+        &self,
+        trans: &Transpose,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    ) {
+        gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn ger(
+        &self,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        x: *const T,
+        incx: &i32,
+        y: *const T,
+        incy: &i32,
+        a: *mut T,
+        lda: &i32,
+    ) {
+        ger(m, n, alpha, x, incx, y, incy, a, lda)
+    }
+
+    unsafe fn nrm2(&self, n: &i32, x: *const T, incx: &i32) -> T::Real where T: Normed {
+        T::nrm2(n, x, incx)
+    }
+
+    unsafe fn rscal(&self, n: &i32, alpha: &T::Real, x: *mut T, incx: &i32) where T: Normed {
+        T::rscal(n, alpha, x, incx)
+    }
+
+    unsafe fn scal(&self, n: &i32, alpha: &T, x: *mut T, incx: &i32) {
+        scal(n, alpha, x, incx)
+    }
+
+    unsafe fn symm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *const T,
+        ldb: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    ) {
+        sym::symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+
+    unsafe fn symv(
+        &self,
+        uplo: &Uplo,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    ) {
+        sym::symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn syrk(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        n: &i32,
+        k: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    ) {
+        sym::syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+    }
+
+    unsafe fn trmm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        transa: &Transpose,
+        diag: &Diagonal,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+    ) {
+        tri::trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+    }
+
+    unsafe fn trmv(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        diag: &Diagonal,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        x: *mut T,
+        incx: &i32,
+    ) {
+        tri::trmv(uplo, trans, diag, n, a, lda, x, incx)
+    }
+
+    unsafe fn trsm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        transa: &Transpose,
+        diag: &Diagonal,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+    ) where
+        T: Field,
+    {
+        tri::trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+    }
+
+    unsafe fn trsv(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        diag: &Diagonal,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        x: *mut T,
+        incx: &i32,
+    ) where
+        T: Field,
+    {
+        tri::trsv(uplo, trans, diag, n, a, lda, x, incx)
+    }
+}
+
+/// Returns the offset of the first element of a vector of length `n` and increment `inc`
+fn start(n: isize, inc: isize) -> isize {
+    if inc < 0 { (1 - n) * inc } else { 0 }
+}
+
+/// y := alpha * x + y
+unsafe fn axpy<T>(n: &i32, alpha: &T, x: *const T, incx: &i32, y: *mut T, incy: &i32) where
+    T: Scalar,
+{
+    let n = isize::from(*n);
+    let alpha = *alpha;
+
+    if n <= 0 || alpha == T::zero() { return }
+
+    let incx = isize::from(*incx);
+    let incy = isize::from(*incy);
+    let mut ix = start(n, incx);
+    let mut iy = start(n, incy);
+
+    for _ in 0..n {
+        let y = y.offset(iy);
+
+        *y = *y + alpha * *x.offset(ix);
+
+        ix += incx;
+        iy += incy;
+    }
+}
+
+/// y := x
+unsafe fn copy<T>(n: &i32, x: *const T, incx: &i32, y: *mut T, incy: &i32) where T: Scalar {
+    let n = isize::from(*n);
+    let incx = isize::from(*incx);
+    let incy = isize::from(*incy);
+    let mut ix = start(n, incx);
+    let mut iy = start(n, incy);
+
+    for _ in 0..n {
+        *y.offset(iy) = *x.offset(ix);
+
+        ix += incx;
+        iy += incy;
+    }
+}
+
+/// Returns `x' * y`
+unsafe fn dot<T>(n: &i32, x: *const T, incx: &i32, y: *const T, incy: &i32) -> T where
+    T: Scalar,
+{
+    let n = isize::from(*n);
+    let incx = isize::from(*incx);
+    let incy = isize::from(*incy);
+    let mut ix = start(n, incx);
+    let mut iy = start(n, incy);
+
+    let mut dot = T::zero();
+    for _ in 0..n {
+        dot = dot + *x.offset(ix) * *y.offset(iy);
+
+        ix += incx;
+        iy += incy;
+    }
+
+    dot
+}
+
+/// y := alpha * op(A) * x + beta * y
+unsafe fn gemv<T>(
+    trans: &Transpose,
+    m: &i32,
+    n: &i32,
+    alpha: &T,
+    a: *const T,
+    lda: &i32,
+    x: *const T,
+    incx: &i32,
+    beta: &T,
+    y: *mut T,
+    incy: &i32,
+) where
+    T: Scalar,
+{
+    let m = isize::from(*m);
+    let n = isize::from(*n);
+    let lda = isize::from(*lda);
+    let incx = isize::from(*incx);
+    let incy = isize::from(*incy);
+    let alpha = *alpha;
+    let beta = *beta;
+
+    if m == 0 || n == 0 { return }
+
+    let (lenx, leny) = match *trans {
+        Transpose::No => (n, m),
+        Transpose::Yes | Transpose::Conj => (m, n),
+    };
+    let kx = start(lenx, incx);
+    let ky = start(leny, incy);
+
+    // y := beta * y
+    if beta != T::one() {
+        let mut iy = ky;
+
+        for _ in 0..leny {
+            let y = y.offset(iy);
+
+            // NB `y` may be uninitialized when `beta == 0`
+            *y = if beta == T::zero() { T::zero() } else { beta * *y };
+
+            iy += incy;
+        }
+    }
+
+    if alpha == T::zero() { return }
+
+    match *trans {
+        Transpose::No => {
+            let mut jx = kx;
+
+            for j in 0..n {
+                let a = a.offset(j * lda);
+                let temp = alpha * *x.offset(jx);
+                let mut iy = ky;
+
+                for i in 0..m {
+                    let y = y.offset(iy);
+
+                    *y = *y + temp * *a.offset(i);
+
+                    iy += incy;
+                }
+
+                jx += incx;
+            }
+        },
+        Transpose::Yes | Transpose::Conj => {
+            let conj = *trans == Transpose::Conj;
+            let mut jy = ky;
+
+            for j in 0..n {
+                let a = a.offset(j * lda);
+                let mut temp = T::zero();
+                let mut ix = kx;
+
+                for i in 0..m {
+                    let a = *a.offset(i);
+                    let a = if conj { a.conj() } else { a };
+
+                    temp = temp + a * *x.offset(ix);
+
+                    ix += incx;
+                }
+
+                let y = y.offset(jy);
+                *y = *y + alpha * temp;
+
+                jy += incy;
+            }
+        },
+    }
+}
+
+/// A := alpha * x * y' + A
+unsafe fn ger<T>(
+    m: &i32,
+    n: &i32,
+    alpha: &T,
+    x: *const T,
+    incx: &i32,
+    y: *const T,
+    incy: &i32,
+    a: *mut T,
+    lda: &i32,
+) where
+    T: Scalar,
+{
+    let m = isize::from(*m);
+    let n = isize::from(*n);
+    let lda = isize::from(*lda);
+    let incx = isize::from(*incx);
+    let incy = isize::from(*incy);
+    let alpha = *alpha;
+
+    if m == 0 || n == 0 || alpha == T::zero() { return }
+
+    let kx = start(m, incx);
+    let mut jy = start(n, incy);
+
+    for j in 0..n {
+        let a = a.offset(j * lda);
+        let temp = alpha * *y.offset(jy);
+        let mut ix = kx;
+
+        for i in 0..m {
+            let a = a.offset(i);
+
+            *a = *a + *x.offset(ix) * temp;
+
+            ix += incx;
+        }
+
+        jy += incy;
+    }
+}
+
+/// Updates the scaled sum of squares: `scale^2 * ssq := scale^2 * ssq + x^2`
+///
+/// NB Keeping the sum scaled avoids unnecessary overflow and underflow
+fn lassq<T>(x: T, scale: &mut T, ssq: &mut T) where T: Real {
+    if x != T::zero() {
+        let x = x.abs();
+
+        if *scale < x {
+            let r = *scale / x;
+
+            *ssq = T::one() + *ssq * r * r;
+            *scale = x;
+        } else {
+            let r = x / *scale;
+
+            *ssq = *ssq + r * r;
+        }
+    }
+}
+
+/// Returns the euclidean norm of `x`
+unsafe fn nrm2<T>(n: &i32, x: *const T, incx: &i32) -> T where T: Real {
+    let n = isize::from(*n);
+    let incx = isize::from(*incx);
+
+    if n < 1 || incx < 1 { return T::zero() }
+
+    let mut scale = T::zero();
+    let mut ssq = T::one();
+    for i in 0..n {
+        lassq(*x.offset(i * incx), &mut scale, &mut ssq);
+    }
+
+    scale * ssq.sqrt()
+}
+
+/// Returns the euclidean norm of `x`
+unsafe fn cnrm2<T>(n: &i32, x: *const Complex<T>, incx: &i32) -> T where T: Real {
+    let n = isize::from(*n);
+    let incx = isize::from(*incx);
+
+    if n < 1 || incx < 1 { return T::zero() }
+
+    let mut scale = T::zero();
+    let mut ssq = T::one();
+    for i in 0..n {
+        let x = *x.offset(i * incx);
+
+        lassq(x.re, &mut scale, &mut ssq);
+        lassq(x.im, &mut scale, &mut ssq);
+    }
+
+    scale * ssq.sqrt()
+}
+
+/// x := alpha * x
+unsafe fn scal<T>(n: &i32, alpha: &T, x: *mut T, incx: &i32) where T: Scalar {
+    let n = isize::from(*n);
+    let incx = isize::from(*incx);
+
+    if n < 1 || incx < 1 { return }
+
+    for i in 0..n {
+        let x = x.offset(i * incx);
+
+        *x = *alpha * *x;
+    }
+}
+
+/// x := alpha * x, where `alpha` is real
+unsafe fn rscal<T>(n: &i32, alpha: &T, x: *mut Complex<T>, incx: &i32) where T: Real {
+    let n = isize::from(*n);
+    let incx = isize::from(*incx);
+
+    if n < 1 || incx < 1 { return }
+
+    for i in 0..n {
+        let x = x.offset(i * incx);
+
+        *x = Complex { re: *alpha * (*x).re, im: *alpha * (*x).im };
+    }
+}
@@ -0,0 +1,156 @@
+//! Symmetric matrix-vector and matrix-matrix kernels
+
+use cast::From;
+
+use traits::Scalar;
+use {Side, Transpose, Uplo};
+
+use super::start;
+
+/// Returns the element at `(i, j)` of the symmetric matrix `A`, only the `uplo` triangular part of
+/// `A` is read
+unsafe fn at<T>(uplo: &Uplo, a: *const T, lda: isize, i: isize, j: isize) -> T where T: Copy {
+    let (i, j) = if (i <= j) == (*uplo == Uplo::Upper) { (i, j) } else { (j, i) };
+
+    *a.offset(j * lda + i)
+}
+
+/// Returns `alpha * temp + beta * c`
+///
+/// NB `c` is not read when `beta == 0`
+unsafe fn update<T>(alpha: T, temp: T, beta: T, c: *const T) -> T where T: Scalar {
+    if beta == T::zero() { alpha * temp } else { alpha * temp + beta * *c }
+}
+
+/// C := alpha * A * B + beta * C, or C := alpha * B * A + beta * C, where `A` is symmetric
+pub unsafe fn symm<T>(
+    side: &Side,
+    uplo: &Uplo,
+    m: &i32,
+    n: &i32,
+    alpha: &T,
+    a: *const T,
+    lda: &i32,
+    b: *const T,
+    ldb: &i32,
+    beta: &T,
+    c: *mut T,
+    ldc: &i32,
+) where
+    T: Scalar,
+{
+    let m = isize::from(*m);
+    let n = isize::from(*n);
+    let lda = isize::from(*lda);
+    let ldb = isize::from(*ldb);
+    let ldc = isize::from(*ldc);
+    let alpha = *alpha;
+    let beta = *beta;
+
+    let b = |i: isize, j: isize| *b.offset(j * ldb + i);
+
+    for j in 0..n {
+        for i in 0..m {
+            let mut temp = T::zero();
+
+            match *side {
+                Side::Left => for k in 0..m {
+                    temp = temp + at(uplo, a, lda, i, k) * b(k, j);
+                },
+                Side::Right => for k in 0..n {
+                    temp = temp + b(i, k) * at(uplo, a, lda, k, j);
+                },
+            }
+
+            let c = c.offset(j * ldc + i);
+
+            *c = update(alpha, temp, beta, c);
+        }
+    }
+}
+
+/// y := alpha * A * x + beta * y, where `A` is symmetric
+pub unsafe fn symv<T>(
+    uplo: &Uplo,
+    n: &i32,
+    alpha: &T,
+    a: *const T,
+    lda: &i32,
+    x: *const T,
+    incx: &i32,
+    beta: &T,
+    y: *mut T,
+    incy: &i32,
+) where
+    T: Scalar,
+{
+    let n = isize::from(*n);
+    let lda = isize::from(*lda);
+    let incx = isize::from(*incx);
+    let incy = isize::from(*incy);
+    let alpha = *alpha;
+    let beta = *beta;
+
+    let kx = start(n, incx);
+    let ky = start(n, incy);
+
+    for i in 0..n {
+        let mut temp = T::zero();
+
+        for j in 0..n {
+            temp = temp + at(uplo, a, lda, i, j) * *x.offset(kx + j * incx);
+        }
+
+        let y = y.offset(ky + i * incy);
+
+        *y = update(alpha, temp, beta, y);
+    }
+}
+
+/// C := alpha * op(A) * op(A)' + beta * C, where `C` is symmetric
+///
+/// NB Only the `uplo` triangular part of `C` is referenced, `op` can't be `Transpose::Conj`
+pub unsafe fn syrk<T>(
+    uplo: &Uplo,
+    trans: &Transpose,
+    n: &i32,
+    k: &i32,
+    alpha: &T,
+    a: *const T,
+    lda: &i32,
+    beta: &T,
+    c: *mut T,
+    ldc: &i32,
+) where
+    T: Scalar,
+{
+    debug_assert!(*trans != Transpose::Conj);
+
+    let n = isize::from(*n);
+    let k = isize::from(*k);
+    let lda = isize::from(*lda);
+    let ldc = isize::from(*ldc);
+    let alpha = *alpha;
+    let beta = *beta;
+
+    let a = |i: isize, l: isize| match *trans {
+        Transpose::No => *a.offset(l * lda + i),
+        Transpose::Yes | Transpose::Conj => *a.offset(i * lda + l),
+    };
+
+    for j in 0..n {
+        let rows = if *uplo == Uplo::Upper { 0..j + 1 } else { j..n };
+
+        for i in rows {
+            let mut temp = T::zero();
+
+            for l in 0..k {
+                temp = temp + a(i, l) * a(j, l);
+            }
+
+            let c = c.offset(j * ldc + i);
+
+            *c = update(alpha, temp, beta, c);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Triangular matrix-vector and matrix-matrix kernels
+
+use cast::From;
+
+use traits::{Field, Scalar};
+use {Diagonal, Side, Transpose, Uplo};
+
+use super::start;
+
+/// `op(A)`, where `A` is a triangular matrix
+struct Op<T> {
+    a: *const T,
+    conj: bool,
+    lda: isize,
+    trans: bool,
+    unit: bool,
+    /// Whether `op(A)`, and not necessarily `A`, is upper triangular
+    upper: bool,
+}
+
+impl<T> Op<T> where T: Scalar {
+    fn new(uplo: &Uplo, trans: &Transpose, diag: &Diagonal, a: *const T, lda: &i32) -> Op<T> {
+        let trans_ = *trans != Transpose::No;
+
+        Op {
+            a: a,
+            conj: *trans == Transpose::Conj,
+            lda: isize::from(*lda),
+            trans: trans_,
+            unit: *diag == Diagonal::Unit,
+            upper: (*uplo == Uplo::Upper) != trans_,
+        }
+    }
+
+    /// Returns the transpose of `op(A)`, the conjugation is preserved
+    fn t(self) -> Op<T> {
+        Op { trans: !self.trans, upper: !self.upper, ..self }
+    }
+
+    /// Returns the element at `(i, j)` of `op(A)`
+    unsafe fn at(&self, i: isize, j: isize) -> T {
+        let a = if self.trans {
+            *self.a.offset(i * self.lda + j)
+        } else {
+            *self.a.offset(j * self.lda + i)
+        };
+
+        if self.conj { a.conj() } else { a }
+    }
+
+    /// x := op(A) * x
+    ///
+    /// NB Each element of `x` is overwritten after its last use, no workspace is needed
+    unsafe fn mv(&self, n: isize, x: *mut T, incx: isize) {
+        let kx = start(n, incx);
+        let x = |i: isize| x.offset(kx + i * incx);
+
+        let diag = |i: isize| if self.unit { *x(i) } else { self.at(i, i) * *x(i) };
+
+        if self.upper {
+            for i in 0..n {
+                let mut temp = diag(i);
+
+                for j in i + 1..n {
+                    temp = temp + self.at(i, j) * *x(j);
+                }
+
+                *x(i) = temp;
+            }
+        } else {
+            for i in (0..n).rev() {
+                let mut temp = diag(i);
+
+                for j in 0..i {
+                    temp = temp + self.at(i, j) * *x(j);
+                }
+
+                *x(i) = temp;
+            }
+        }
+    }
+}
+
+impl<T> Op<T> where T: Field {
+    /// x := op(A)^-1 * x
+    ///
+    /// NB Forward substitution if `op(A)` is lower triangular, backward substitution otherwise
+    unsafe fn sv(&self, n: isize, x: *mut T, incx: isize) {
+        let kx = start(n, incx);
+        let x = |i: isize| x.offset(kx + i * incx);
+
+        let solve = |i: isize, sum: T| {
+            let temp = (*x(i)).difference(sum);
+
+            *x(i) = if self.unit { temp } else { temp.quotient(self.at(i, i)) };
+        };
+
+        if self.upper {
+            for i in (0..n).rev() {
+                let mut sum = T::zero();
+
+                for j in i + 1..n {
+                    sum = sum + self.at(i, j) * *x(j);
+                }
+
+                solve(i, sum);
+            }
+        } else {
+            for i in 0..n {
+                let mut sum = T::zero();
+
+                for j in 0..i {
+                    sum = sum + self.at(i, j) * *x(j);
+                }
+
+                solve(i, sum);
+            }
+        }
+    }
+}
+
+/// B := alpha * B
+///
+/// NB `B` is not read when `alpha == 0`
+unsafe fn scale<T>(m: isize, n: isize, alpha: T, b: *mut T, ldb: isize) where T: Scalar {
+    if alpha == T::one() { return }
+
+    for j in 0..n {
+        for i in 0..m {
+            let b = b.offset(j * ldb + i);
+
+            *b = if alpha == T::zero() { T::zero() } else { alpha * *b };
+        }
+    }
+}
+
+/// B := alpha * op(A) * B, or B := alpha * B * op(A)
+pub unsafe fn trmm<T>(
+    side: &Side,
+    uplo: &Uplo,
+    transa: &Transpose,
+    diag: &Diagonal,
+    m: &i32,
+    n: &i32,
+    alpha: &T,
+    a: *const T,
+    lda: &i32,
+    b: *mut T,
+    ldb: &i32,
+) where
+    T: Scalar,
+{
+    let m = isize::from(*m);
+    let n = isize::from(*n);
+    let ldb = isize::from(*ldb);
+    let alpha = *alpha;
+
+    if m == 0 || n == 0 { return }
+
+    if alpha != T::zero() {
+        let op = Op::new(uplo, transa, diag, a, lda);
+
+        match *side {
+            Side::Left => {
+                for j in 0..n {
+                    op.mv(m, b.offset(j * ldb), 1);
+                }
+            },
+            // NB `B * op(A) = (op(A)' * B')'`, the rows of `B` are the columns of `B'`
+            Side::Right => {
+                let op = op.t();
+
+                for i in 0..m {
+                    op.mv(n, b.offset(i), ldb);
+                }
+            },
+        }
+    }
+
+    scale(m, n, alpha, b, ldb)
+}
+
+/// x := op(A) * x
+pub unsafe fn trmv<T>(
+    uplo: &Uplo,
+    trans: &Transpose,
+    diag: &Diagonal,
+    n: &i32,
+    a: *const T,
+    lda: &i32,
+    x: *mut T,
+    incx: &i32,
+) where
+    T: Scalar,
+{
+    let n = isize::from(*n);
+    let incx = isize::from(*incx);
+
+    Op::new(uplo, trans, diag, a, lda).mv(n, x, incx)
+}
+
+/// B := alpha * op(A)^-1 * B, or B := alpha * B * op(A)^-1
+pub unsafe fn trsm<T>(
+    side: &Side,
+    uplo: &Uplo,
+    transa: &Transpose,
+    diag: &Diagonal,
+    m: &i32,
+    n: &i32,
+    alpha: &T,
+    a: *const T,
+    lda: &i32,
+    b: *mut T,
+    ldb: &i32,
+) where
+    T: Field,
+{
+    let m = isize::from(*m);
+    let n = isize::from(*n);
+    let ldb = isize::from(*ldb);
+    let alpha = *alpha;
+
+    if m == 0 || n == 0 { return }
+
+    scale(m, n, alpha, b, ldb);
+
+    if alpha == T::zero() { return }
+
+    let op = Op::new(uplo, transa, diag, a, lda);
+
+    match *side {
+        Side::Left => {
+            for j in 0..n {
+                op.sv(m, b.offset(j * ldb), 1);
+            }
+        },
+        // NB see `trmm`
+        Side::Right => {
+            let op = op.t();
+
+            for i in 0..m {
+                op.sv(n, b.offset(i), ldb);
+            }
+        },
+    }
+}
+
+/// x := op(A)^-1 * x
+pub unsafe fn trsv<T>(
+    uplo: &Uplo,
+    trans: &Transpose,
+    diag: &Diagonal,
+    n: &i32,
+    a: *const T,
+    lda: &i32,
+    x: *mut T,
+    incx: &i32,
+) where
+    T: Field,
+{
+    let n = isize::from(*n);
+    let incx = isize::from(*incx);
+
+    Op::new(uplo, trans, diag, a, lda).sv(n, x, incx)
+}
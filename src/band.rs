@@ -0,0 +1,97 @@
+use cast::From;
+use extract::Extract;
+use onezero::Zero;
+
+use traits::{Matrix, MatrixDiag, MatrixDiagMut};
+use {BandMat, Diag, Mat, Slice};
+
+impl<T> BandMat<T> {
+    /// Creates a `n x n` band matrix with `kl` sub-diagonals and `ku` super-diagonals, all the
+    /// elements of the band are set to zero
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `n > 2^31 ||`
+    /// - `2 * kl + ku + 1 > 2^31 ||`
+    /// - `n * (2 * kl + ku + 1) > usize::max_value()`
+    pub fn zeros(n: u32, (kl, ku): (u32, u32)) -> BandMat<T> where T: Clone + Zero {
+        let ldab = u32::from(2 * u64::from(kl) + u64::from(ku) + 1).unwrap();
+
+        BandMat {
+            ab: Mat::zeros((ldab, n)),
+            kl: i32::from(kl).unwrap(),
+            ku: i32::from(ku).unwrap(),
+        }
+    }
+
+    /// Returns the number of sub-diagonals
+    pub fn kl(&self) -> u32 {
+        unsafe {
+            u32::from(self.kl).extract()
+        }
+    }
+
+    /// Returns the number of super-diagonals
+    pub fn ku(&self) -> u32 {
+        unsafe {
+            u32::from(self.ku).extract()
+        }
+    }
+}
+
+impl<T> Matrix for BandMat<T> {
+    type Elem = T;
+
+    fn ncols(&self) -> u32 {
+        self.ab.ncols()
+    }
+
+    fn nrows(&self) -> u32 {
+        self.ab.ncols()
+    }
+}
+
+impl<'a, T> Matrix for &'a BandMat<T> {
+    type Elem = T;
+
+    fn ncols(&self) -> u32 {
+        (**self).ncols()
+    }
+
+    fn nrows(&self) -> u32 {
+        (**self).nrows()
+    }
+}
+
+impl<T> MatrixDiag for BandMat<T> {
+    /// Returns the `i`th diagonal of the band, `i` must be in the range `-kl...ku`
+    fn diag(&self, i: i32) -> Diag<T> {
+        unsafe {
+            let (n, ldab) = (self.ab.ncols, self.ab.nrows);
+
+            assert!(i >= -self.kl && i <= self.ku);
+
+            // NB `A[r, r + i]` is stored at `ab[kl + ku - i, r + i]`
+            let row = isize::from(self.kl + self.ku - i);
+
+            if i > 0 {
+                assert!(i < n);
+
+                let data = self.ab.data.offset(isize::from(i) * isize::from(ldab) + row);
+
+                Diag(Slice::new(data, n - i, ldab))
+            } else {
+                // NB the main diagonal of a `0 x 0` matrix is empty, but not out of bounds
+                assert!(i == 0 || -i < n);
+
+                let data = self.ab.data.offset(row);
+
+                Diag(Slice::new(data, n + i, ldab))
+            }
+        }
+    }
+}
+
+impl<T> MatrixDiagMut for BandMat<T> {}
@@ -0,0 +1,104 @@
+//! The BLAS routines used by `ops`
+//!
+//! NB Every element type implements these traits, except `Nrm2` which requires `Normed`, and
+//! `Trsm`/`Trsv` which require `Field`. The routines themselves are provided by the `Backend` of
+//! the element type. The traits are kept as bounds to document which routines each operation uses.
+
+use complex::Complex;
+
+use traits::{Backend, Element, Field, Normed};
+
+pub use {Diagonal, Side, Transpose, Uplo};
+
+/// y := alpha * x + y
+pub trait Axpy: Element {}
+
+/// y := x
+pub trait Copy: Element {}
+
+/// Dot product
+pub trait Dot: Element {}
+
+/// Band matrix-vector multiplication
+pub trait Gbmv: Element {}
+
+/// General matrix-matrix multiplication
+pub trait Gemm: Element {}
+
+/// General matrix-vector multiplication
+pub trait Gemv: Element {}
+
+/// Rank-1 update
+pub trait Ger: Element {}
+
+/// Euclidean norm
+pub trait Nrm2: Normed {}
+
+/// x := alpha * x
+pub trait Scal<A>: Element {
+    /// Calls the scaling routine of `backend` that handles an `alpha` of type `A`
+    unsafe fn scal(backend: &Backend<Self>, n: &i32, alpha: &A, x: *mut Self, incx: &i32);
+}
+
+/// Symmetric matrix-matrix multiplication
+pub trait Symm: Element {}
+
+/// Symmetric matrix-vector multiplication
+pub trait Symv: Element {}
+
+/// Symmetric rank-k update
+pub trait Syrk: Element {}
+
+/// Triangular matrix-matrix multiplication
+pub trait Trmm: Element {}
+
+/// Triangular matrix-vector multiplication
+pub trait Trmv: Element {}
+
+/// Triangular solver with multiple right hand sides
+pub trait Trsm: Field {}
+
+/// Triangular solver
+pub trait Trsv: Field {}
+
+impl<T> Axpy for T where T: Element {}
+impl<T> Copy for T where T: Element {}
+impl<T> Dot for T where T: Element {}
+impl<T> Gbmv for T where T: Element {}
+impl<T> Gemm for T where T: Element {}
+impl<T> Gemv for T where T: Element {}
+impl<T> Ger for T where T: Element {}
+impl<T> Nrm2 for T where T: Normed {}
+impl<T> Symm for T where T: Element {}
+impl<T> Symv for T where T: Element {}
+impl<T> Syrk for T where T: Element {}
+impl<T> Trmm for T where T: Element {}
+impl<T> Trmv for T where T: Element {}
+impl<T> Trsm for T where T: Field {}
+impl<T> Trsv for T where T: Field {}
+
+impl<T> Scal<T> for T where T: Element {
+    unsafe fn scal(backend: &Backend<T>, n: &i32, alpha: &T, x: *mut T, incx: &i32) {
+        backend.scal(n, alpha, x, incx)
+    }
+}
+
+macro_rules! rscal {
+    ($($t:ident),+) => {
+        $(
+            impl Scal<$t> for Complex<$t> {
+                unsafe fn scal(
+                    backend: &Backend<Complex<$t>>,
+                    n: &i32,
+                    alpha: &$t,
+                    x: *mut Complex<$t>,
+                    incx: &i32,
+                ) {
+                    backend.rscal(n, alpha, x, incx)
+                }
+            }
+         )+
+    }
+}
+
+rscal!(f32, f64);
@@ -1,7 +1,7 @@
 use blas::Transpose;
 
 use traits::{Matrix, self};
-use {Chain, SubMat};
+use {Chain, ConjChain, SubMat};
 
 impl<'a, T> Matrix for Chain<'a, T> {
     type Elem = T;
@@ -11,7 +11,7 @@ impl<'a, T> Matrix for Chain<'a, T> {
 
         match trans {
             Transpose::No => mat.nrows(),
-            Transpose::Yes => mat.ncols(),
+            Transpose::Yes | Transpose::Conj => mat.ncols(),
         }
     }
 
@@ -20,20 +20,32 @@ impl<'a, T> Matrix for Chain<'a, T> {
 
         match trans {
             Transpose::No => mat.ncols(),
-            Transpose::Yes => mat.nrows(),
+            Transpose::Yes | Transpose::Conj => mat.nrows(),
         }
     }
 }
 
+impl<'a, T> Matrix for ConjChain<'a, T> {
+    type Elem = T;
+
+    fn nrows(&self) -> u32 {
+        self.0.nrows()
+    }
+
+    fn ncols(&self) -> u32 {
+        self.0.ncols()
+    }
+}
+
 impl<'a, T> traits::Transpose for Chain<'a, T> {
     type Output = Chain<'a, T>;
 
     fn t(self) -> Chain<'a, T> {
         fn t<T>((trans, a): (Transpose, SubMat<T>)) -> (Transpose, SubMat<T>) {
-            match trans {
-                Transpose::No => (Transpose::Yes, a),
-                Transpose::Yes => (Transpose::No, a),
-            }
+            // NB a `Chain` never contains a conjugate transpose, see `ConjChain`
+            debug_assert!(trans != Transpose::Conj);
+
+            if trans == Transpose::No { (Transpose::Yes, a) } else { (Transpose::No, a) }
         }
 
         let was_first = t(self.first);
@@ -0,0 +1,66 @@
+use cast::From;
+use complex::Complex;
+use extract::Extract;
+use lapack::Potrf;
+
+use ops;
+use traits::{Matrix, MatrixDiag, SliceMut};
+use {Cholesky, Error, Mat};
+
+impl<T> Mat<T> {
+    /// Computes the Cholesky factorization of a Hermitian positive definite matrix
+    ///
+    /// NOTE Only the lower triangle of the matrix is referenced. The matrix is taken by value, its
+    /// buffer will be re-used to store the factor.
+    ///
+    /// # Errors
+    ///
+    /// `NotPositiveDefinite` if the matrix is not positive definite
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    pub fn cholesky(mut self) -> Result<Cholesky<T>, Error> where T: Potrf {
+        unsafe {
+            assert_eq!(self.nrows(), self.ncols());
+
+            let info = ops::potrf(self.slice_mut(..));
+
+            if info == 0 {
+                Ok(Cholesky(self))
+            } else {
+                Err(Error::NotPositiveDefinite { order: u32::from(info).extract() })
+            }
+        }
+    }
+}
+
+impl<T> Cholesky<T> {
+    /// Returns the size of the factorized (square) matrix
+    pub fn size(&self) -> (u32, u32) {
+        self.0.size()
+    }
+}
+
+macro_rules! log_det {
+    ($($t:ident),+) => {
+        $(
+            impl Cholesky<$t> {
+                /// Returns the natural logarithm of the determinant of the factorized matrix
+                pub fn log_det(&self) -> $t {
+                    2. * self.0.diag(0).iter().fold(0., |acc, x| acc + x.ln())
+                }
+            }
+
+            impl Cholesky<Complex<$t>> {
+                /// Returns the natural logarithm of the determinant of the factorized matrix
+                pub fn log_det(&self) -> $t {
+                    // NB the diagonal of the factor is real
+                    2. * self.0.diag(0).iter().fold(0., |acc, x| acc + x.re.ln())
+                }
+            }
+         )+
+    }
+}
+
+log_det!(f32, f64);
@@ -0,0 +1,137 @@
+use traits::{ConjTranspose, Conjugate, Matrix, Slice, Transpose};
+use {ConjTransposed, Conjugated, Mat, SubMat, SubMatMut, Transposed};
+
+impl<'a, T> Matrix for Conjugated<SubMat<'a, T>> {
+    type Elem = T;
+
+    fn nrows(&self) -> u32 {
+        self.0.nrows()
+    }
+
+    fn ncols(&self) -> u32 {
+        self.0.ncols()
+    }
+}
+
+impl<'a, T> Matrix for ConjTransposed<SubMat<'a, T>> {
+    type Elem = T;
+
+    fn nrows(&self) -> u32 {
+        self.0.ncols()
+    }
+
+    fn ncols(&self) -> u32 {
+        self.0.nrows()
+    }
+}
+
+// NOTE Core
+impl<'a, T> Conjugate for SubMat<'a, T> {
+    type Output = Conjugated<SubMat<'a, T>>;
+
+    fn conj(self) -> Conjugated<SubMat<'a, T>> {
+        Conjugated(self)
+    }
+}
+
+impl<'a, T> Conjugate for Conjugated<SubMat<'a, T>> {
+    type Output = SubMat<'a, T>;
+
+    fn conj(self) -> SubMat<'a, T> {
+        self.0
+    }
+}
+
+impl<'a, T> Conjugate for ConjTransposed<SubMat<'a, T>> {
+    type Output = Transposed<SubMat<'a, T>>;
+
+    fn conj(self) -> Transposed<SubMat<'a, T>> {
+        Transposed(self.0)
+    }
+}
+
+impl<'a, T> Conjugate for Transposed<SubMat<'a, T>> {
+    type Output = ConjTransposed<SubMat<'a, T>>;
+
+    fn conj(self) -> ConjTransposed<SubMat<'a, T>> {
+        ConjTransposed(self.0)
+    }
+}
+
+// NOTE Core
+impl<'a, T> ConjTranspose for SubMat<'a, T> {
+    type Output = ConjTransposed<SubMat<'a, T>>;
+
+    fn h(self) -> ConjTransposed<SubMat<'a, T>> {
+        ConjTransposed(self)
+    }
+}
+
+impl<'a, T> ConjTranspose for Conjugated<SubMat<'a, T>> {
+    type Output = Transposed<SubMat<'a, T>>;
+
+    fn h(self) -> Transposed<SubMat<'a, T>> {
+        Transposed(self.0)
+    }
+}
+
+impl<'a, T> ConjTranspose for ConjTransposed<SubMat<'a, T>> {
+    type Output = SubMat<'a, T>;
+
+    fn h(self) -> SubMat<'a, T> {
+        self.0
+    }
+}
+
+impl<'a, T> ConjTranspose for Transposed<SubMat<'a, T>> {
+    type Output = Conjugated<SubMat<'a, T>>;
+
+    fn h(self) -> Conjugated<SubMat<'a, T>> {
+        Conjugated(self.0)
+    }
+}
+
+impl<'a, T> Transpose for Conjugated<SubMat<'a, T>> {
+    type Output = ConjTransposed<SubMat<'a, T>>;
+
+    fn t(self) -> ConjTransposed<SubMat<'a, T>> {
+        ConjTransposed(self.0)
+    }
+}
+
+impl<'a, T> Transpose for ConjTransposed<SubMat<'a, T>> {
+    type Output = Conjugated<SubMat<'a, T>>;
+
+    fn t(self) -> Conjugated<SubMat<'a, T>> {
+        Conjugated(self.0)
+    }
+}
+
+macro_rules! forward {
+    ($($ty:ty),+,) => {
+        $(
+            // NOTE Forward
+            impl<'a, 'b, T> Conjugate for $ty {
+                type Output = Conjugated<SubMat<'a, T>>;
+
+                fn conj(self) -> Conjugated<SubMat<'a, T>> {
+                    self.slice(..).conj()
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, T> ConjTranspose for $ty {
+                type Output = ConjTransposed<SubMat<'a, T>>;
+
+                fn h(self) -> ConjTransposed<SubMat<'a, T>> {
+                    self.slice(..).h()
+                }
+            }
+         )+
+    }
+}
+
+forward! {
+    &'a Mat<T>,
+    &'a SubMatMut<'b, T>,
+}
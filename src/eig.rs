@@ -0,0 +1,225 @@
+use std::{cmp, ptr};
+
+use cast::From;
+use complex::Complex;
+use extract::Extract;
+
+use traits::{Lapack, Matrix};
+use {ColVec, Mat, Tor};
+
+/// Dispatches to the real or complex flavor of LAPACK's `geev`
+trait Gev: Sized {
+    /// The type of the real/imaginary part of the eigenvalues
+    type Real;
+
+    /// Computes the eigenvalues, and optionally the left (`jobvl == b'V'`) and right
+    /// (`jobvr == b'V'`) eigenvectors of `A`
+    ///
+    /// The eigenvectors that weren't requested are returned as empty matrices
+    unsafe fn geev(
+        jobvl: u8,
+        jobvr: u8,
+        a: Mat<Self>,
+    ) -> (ColVec<Complex<Self::Real>>, Mat<Complex<Self::Real>>, Mat<Complex<Self::Real>>);
+}
+
+/// Returns the order of the eigenvector matrix requested by `jobv`
+fn order(jobv: u8, n: i32) -> i32 {
+    if jobv == b'V' { n } else { 0 }
+}
+
+macro_rules! gev {
+    ($($t:ident),+) => {
+        $(
+            impl Gev for $t {
+                type Real = $t;
+
+                unsafe fn geev(
+                    jobvl: u8,
+                    jobvr: u8,
+                    a: Mat<$t>,
+                ) -> (ColVec<Complex<$t>>, Mat<Complex<$t>>, Mat<Complex<$t>>) {
+                    /// Packs the eigenvectors returned by `geev` into a complex matrix
+                    ///
+                    /// The eigenvectors associated to a complex conjugate pair of eigenvalues are
+                    /// stored in two consecutive columns, as the real and imaginary parts
+                    fn unpack(v: &Mat<$t>, wi: &[$t]) -> Mat<Complex<$t>> {
+                        Mat::from_fn(v.size(), |(row, col)| {
+                            let i = usize::from(col);
+
+                            if wi[i] == 0. {
+                                Complex { re: v[(row, col)], im: 0. }
+                            } else if wi[i] > 0. {
+                                Complex { re: v[(row, col)], im: v[(row, col + 1)] }
+                            } else {
+                                Complex { re: v[(row, col - 1)], im: -v[(row, col)] }
+                            }
+                        })
+                    }
+
+                    let backend = <$t as Lapack>::lapack_backend();
+                    let ref n = a.nrows;
+                    let n_ = usize::from(*n).extract();
+                    let ref lda = cmp::max(1, *n);
+                    let mut wr = Vec::with_capacity(n_);
+                    let mut wi = Vec::with_capacity(n_);
+                    wr.set_len(n_);
+                    wi.set_len(n_);
+                    let vl = Mat::uninitialized((order(jobvl, *n), order(jobvl, *n)));
+                    let vr = Mat::uninitialized((order(jobvr, *n), order(jobvr, *n)));
+                    let ref ldvl = cmp::max(1, vl.nrows);
+                    let ref ldvr = cmp::max(1, vr.nrows);
+                    let ref mut info = 0;
+
+                    let ref jobvl = jobvl;
+                    let ref jobvr = jobvr;
+                    let a_ = *a.data;
+                    let wr_ = wr.as_mut_ptr();
+                    let wi_ = wi.as_mut_ptr();
+                    let vl_ = *vl.data;
+                    let vr_ = *vr.data;
+
+                    // workspace query
+                    let mut lwork: $t = 0.;
+                    backend.geev(
+                        jobvl, jobvr, n, a_, lda, wr_, wi_, vl_, ldvl, vr_, ldvr, &mut lwork, &-1,
+                        ptr::null_mut(), info,
+                    );
+
+                    assert!(*info == 0);
+
+                    let ref lwork = lwork as i32;
+                    let mut work = Vec::with_capacity(usize::from(*lwork).extract());
+                    let work = work.as_mut_ptr();
+
+                    backend.geev(
+                        jobvl, jobvr, n, a_, lda, wr_, wi_, vl_, ldvl, vr_, ldvr, work, lwork,
+                        ptr::null_mut(), info,
+                    );
+
+                    assert!(*info >= 0);
+                    assert!(*info == 0, "the QR algorithm failed to compute all the eigenvalues");
+
+                    let w = wr.iter().zip(wi.iter()).map(|(&re, &im)| Complex { re: re, im: im });
+
+                    (ColVec(w.collect()), unpack(&vl, &wi), unpack(&vr, &wi))
+                }
+            }
+
+            impl Gev for Complex<$t> {
+                type Real = $t;
+
+                unsafe fn geev(
+                    jobvl: u8,
+                    jobvr: u8,
+                    a: Mat<Complex<$t>>,
+                ) -> (ColVec<Complex<$t>>, Mat<Complex<$t>>, Mat<Complex<$t>>) {
+                    let backend = <Complex<$t> as Lapack>::lapack_backend();
+                    let ref n = a.nrows;
+                    let ref lda = cmp::max(1, *n);
+                    let w = ColVec(Tor::uninitialized(*n));
+                    let vl = Mat::uninitialized((order(jobvl, *n), order(jobvl, *n)));
+                    let vr = Mat::uninitialized((order(jobvr, *n), order(jobvr, *n)));
+                    let ref ldvl = cmp::max(1, vl.nrows);
+                    let ref ldvr = cmp::max(1, vr.nrows);
+                    let mut rwork = Vec::with_capacity(usize::from(2 * *n).extract());
+                    let ref mut info = 0;
+
+                    let ref jobvl = jobvl;
+                    let ref jobvr = jobvr;
+                    let a_ = *a.data;
+                    let w_ = *w.0.data;
+                    let vl_ = *vl.data;
+                    let vr_ = *vr.data;
+                    let rwork = rwork.as_mut_ptr();
+
+                    // workspace query
+                    let mut lwork = Complex { re: 0., im: 0. };
+                    backend.geev(
+                        jobvl, jobvr, n, a_, lda, w_, ptr::null_mut(), vl_, ldvl, vr_, ldvr,
+                        &mut lwork, &-1, rwork, info,
+                    );
+
+                    assert!(*info == 0);
+
+                    let ref lwork = lwork.re as i32;
+                    let mut work = Vec::with_capacity(usize::from(*lwork).extract());
+                    let work = work.as_mut_ptr();
+
+                    backend.geev(
+                        jobvl, jobvr, n, a_, lda, w_, ptr::null_mut(), vl_, ldvl, vr_, ldvr, work,
+                        lwork, rwork, info,
+                    );
+
+                    assert!(*info >= 0);
+                    assert!(*info == 0, "the QR algorithm failed to compute all the eigenvalues");
+
+                    (w, vl, vr)
+                }
+            }
+         )+
+    }
+}
+
+gev!(f32, f64);
+
+macro_rules! eig {
+    ($($t:ty => $real:ty),+,) => {
+        $(
+            impl Mat<$t> {
+                /// Computes the eigenvalues and the right eigenvectors of a square matrix
+                ///
+                /// Returns the (complex) eigenvalues, and a matrix whose columns are the
+                /// corresponding right eigenvectors, i.e. `A * V[:, j] = w[j] * V[:, j]`. Each
+                /// eigenvector is normalized to have euclidean norm equal to one.
+                ///
+                /// NOTE The matrix is taken by value, its buffer is used as workspace
+                ///
+                /// # Panics
+                ///
+                /// If the matrix is not square, or if the algorithm doesn't converge
+                pub fn eig(self) -> (ColVec<Complex<$real>>, Mat<Complex<$real>>) {
+                    unsafe {
+                        assert_eq!(self.nrows(), self.ncols());
+
+                        let (w, _, vr) = Gev::geev(b'N', b'V', self);
+
+                        (w, vr)
+                    }
+                }
+
+                /// Like `eig()`, but also computes the left eigenvectors
+                ///
+                /// Returns `(w, U, V)`, where `U[:, j]' * A = w[j] * U[:, j]'` (`'` denotes the
+                /// conjugate transpose), and `A * V[:, j] = w[j] * V[:, j]`
+                pub fn eig_left_right(
+                    self,
+                ) -> (ColVec<Complex<$real>>, Mat<Complex<$real>>, Mat<Complex<$real>>) {
+                    unsafe {
+                        assert_eq!(self.nrows(), self.ncols());
+
+                        Gev::geev(b'V', b'V', self)
+                    }
+                }
+
+                /// Returns the (complex) eigenvalues of a square matrix
+                ///
+                /// NOTE This is cheaper than `eig()`, because the eigenvectors aren't computed
+                pub fn eigvals(self) -> ColVec<Complex<$real>> {
+                    unsafe {
+                        assert_eq!(self.nrows(), self.ncols());
+
+                        Gev::geev(b'N', b'N', self).0
+                    }
+                }
+            }
+         )+
+    }
+}
+
+eig! {
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => f32,
+    Complex<f64> => f64,
+}
@@ -0,0 +1,299 @@
+use std::ops::Range;
+use std::{cmp, ptr};
+
+use cast::From as _0;
+use complex::Complex;
+use extract::Extract;
+
+use traits::{Lapack, Matrix, Slice};
+use {ColVec, Mat, SubMat, Tor};
+
+/// Dispatches to the real (`sy*`) or complex (`he*`) flavor of LAPACK's eigensolvers
+trait Ev: Sized {
+    /// The type of the eigenvalues
+    type Real;
+
+    /// Computes all the eigenvalues and eigenvectors of `A`
+    ///
+    /// On exit, `A` contains the eigenvectors
+    unsafe fn evd(a: &mut Mat<Self>) -> ColVec<Self::Real>;
+
+    /// Computes the eigenvalues (and eigenvectors) whose indices lie in `il..iu + 1`
+    ///
+    /// NOTE `il` and `iu` are one-based
+    unsafe fn evr(a: Mat<Self>, il: i32, iu: i32) -> (ColVec<Self::Real>, Mat<Self>);
+}
+
+macro_rules! ev {
+    ($($t:ident),+) => {
+        $(
+            impl Ev for $t {
+                type Real = $t;
+
+                unsafe fn evd(a: &mut Mat<$t>) -> ColVec<$t> {
+                    let backend = <$t as Lapack>::lapack_backend();
+                    let ref n = a.nrows;
+                    let ref lda = cmp::max(1, *n);
+                    let w = ColVec(Tor::uninitialized(*n));
+                    let ref mut info = 0;
+
+                    let a = *a.data;
+                    let w_ = *w.0.data;
+
+                    // workspace query
+                    let mut lwork: $t = 0.;
+                    let mut liwork = 0;
+                    backend.heevd(
+                        &b'V', &b'L', n, a, lda, w_, &mut lwork, &-1, ptr::null_mut(), &0,
+                        &mut liwork, &-1, info,
+                    );
+
+                    assert!(*info == 0);
+
+                    let ref lwork = lwork as i32;
+                    let ref liwork = liwork;
+                    let mut work = Vec::with_capacity(usize::from_(*lwork).extract());
+                    let mut iwork = Vec::with_capacity(usize::from_(*liwork).extract());
+                    let work = work.as_mut_ptr();
+                    let iwork = iwork.as_mut_ptr();
+
+                    backend.heevd(
+                        &b'V', &b'L', n, a, lda, w_, work, lwork, ptr::null_mut(), &0, iwork,
+                        liwork, info,
+                    );
+
+                    assert!(*info >= 0);
+                    assert!(*info == 0, "eigendecomposition did not converge");
+
+                    w
+                }
+
+                unsafe fn evr(a: Mat<$t>, il: i32, iu: i32) -> (ColVec<$t>, Mat<$t>) {
+                    let backend = <$t as Lapack>::lapack_backend();
+                    let ref n = a.nrows;
+                    let ref lda = cmp::max(1, *n);
+                    let k = iu - il + 1;
+                    let w = ColVec(Tor::uninitialized(*n));
+                    let z = Mat::uninitialized((*n, k));
+                    let ref ldz = cmp::max(1, *n);
+                    let lisuppz = usize::from_(2 * cmp::max(1, k)).extract();
+                    let mut isuppz = Vec::with_capacity(lisuppz);
+                    let ref mut m = 0;
+                    let ref mut info = 0;
+
+                    let ref il = il;
+                    let ref iu = iu;
+                    let ref zero = 0.;
+                    let a = *a.data;
+                    let w_ = *w.0.data;
+                    let z_ = *z.data;
+                    let isuppz = isuppz.as_mut_ptr();
+
+                    // workspace query
+                    let mut lwork: $t = 0.;
+                    let mut liwork = 0;
+                    backend.heevr(
+                        &b'V', &b'I', &b'L', n, a, lda, zero, zero, il, iu, zero, m, w_, z_, ldz,
+                        isuppz, &mut lwork, &-1, ptr::null_mut(), &0, &mut liwork, &-1, info,
+                    );
+
+                    assert!(*info == 0);
+
+                    let ref lwork = lwork as i32;
+                    let ref liwork = liwork;
+                    let mut work = Vec::with_capacity(usize::from_(*lwork).extract());
+                    let mut iwork = Vec::with_capacity(usize::from_(*liwork).extract());
+                    let work = work.as_mut_ptr();
+                    let iwork = iwork.as_mut_ptr();
+
+                    backend.heevr(
+                        &b'V', &b'I', &b'L', n, a, lda, zero, zero, il, iu, zero, m, w_, z_, ldz,
+                        isuppz, work, lwork, ptr::null_mut(), &0, iwork, liwork, info,
+                    );
+
+                    assert!(*info >= 0);
+                    assert!(*info == 0, "eigendecomposition did not converge");
+
+                    (ColVec::from(w.slice(..u32::from_(*m).extract())), z)
+                }
+            }
+
+            impl Ev for Complex<$t> {
+                type Real = $t;
+
+                unsafe fn evd(a: &mut Mat<Complex<$t>>) -> ColVec<$t> {
+                    let backend = <Complex<$t> as Lapack>::lapack_backend();
+                    let ref n = a.nrows;
+                    let ref lda = cmp::max(1, *n);
+                    let w = ColVec(Tor::uninitialized(*n));
+                    let ref mut info = 0;
+
+                    let a = *a.data;
+                    let w_ = *w.0.data;
+
+                    // workspace query
+                    let mut lwork = Complex { re: 0., im: 0. };
+                    let mut lrwork: $t = 0.;
+                    let mut liwork = 0;
+                    backend.heevd(
+                        &b'V', &b'L', n, a, lda, w_, &mut lwork, &-1, &mut lrwork, &-1,
+                        &mut liwork, &-1, info,
+                    );
+
+                    assert!(*info == 0);
+
+                    let ref lwork = lwork.re as i32;
+                    let ref lrwork = lrwork as i32;
+                    let ref liwork = liwork;
+                    let mut work = Vec::with_capacity(usize::from_(*lwork).extract());
+                    let mut rwork = Vec::with_capacity(usize::from_(*lrwork).extract());
+                    let mut iwork = Vec::with_capacity(usize::from_(*liwork).extract());
+                    let work = work.as_mut_ptr();
+                    let rwork = rwork.as_mut_ptr();
+                    let iwork = iwork.as_mut_ptr();
+
+                    backend.heevd(
+                        &b'V', &b'L', n, a, lda, w_, work, lwork, rwork, lrwork, iwork, liwork,
+                        info,
+                    );
+
+                    assert!(*info >= 0);
+                    assert!(*info == 0, "eigendecomposition did not converge");
+
+                    w
+                }
+
+                unsafe fn evr(
+                    a: Mat<Complex<$t>>,
+                    il: i32,
+                    iu: i32,
+                ) -> (ColVec<$t>, Mat<Complex<$t>>) {
+                    let backend = <Complex<$t> as Lapack>::lapack_backend();
+                    let ref n = a.nrows;
+                    let ref lda = cmp::max(1, *n);
+                    let k = iu - il + 1;
+                    let w = ColVec(Tor::uninitialized(*n));
+                    let z = Mat::uninitialized((*n, k));
+                    let ref ldz = cmp::max(1, *n);
+                    let lisuppz = usize::from_(2 * cmp::max(1, k)).extract();
+                    let mut isuppz = Vec::with_capacity(lisuppz);
+                    let ref mut m = 0;
+                    let ref mut info = 0;
+
+                    let ref il = il;
+                    let ref iu = iu;
+                    let ref zero = 0.;
+                    let a = *a.data;
+                    let w_ = *w.0.data;
+                    let z_ = *z.data;
+                    let isuppz = isuppz.as_mut_ptr();
+
+                    // workspace query
+                    let mut lwork = Complex { re: 0., im: 0. };
+                    let mut lrwork: $t = 0.;
+                    let mut liwork = 0;
+                    backend.heevr(
+                        &b'V', &b'I', &b'L', n, a, lda, zero, zero, il, iu, zero, m, w_, z_, ldz,
+                        isuppz, &mut lwork, &-1, &mut lrwork, &-1, &mut liwork, &-1, info,
+                    );
+
+                    assert!(*info == 0);
+
+                    let ref lwork = lwork.re as i32;
+                    let ref lrwork = lrwork as i32;
+                    let ref liwork = liwork;
+                    let mut work = Vec::with_capacity(usize::from_(*lwork).extract());
+                    let mut rwork = Vec::with_capacity(usize::from_(*lrwork).extract());
+                    let mut iwork = Vec::with_capacity(usize::from_(*liwork).extract());
+                    let work = work.as_mut_ptr();
+                    let rwork = rwork.as_mut_ptr();
+                    let iwork = iwork.as_mut_ptr();
+
+                    backend.heevr(
+                        &b'V', &b'I', &b'L', n, a, lda, zero, zero, il, iu, zero, m, w_, z_, ldz,
+                        isuppz, work, lwork, rwork, lrwork, iwork, liwork, info,
+                    );
+
+                    assert!(*info >= 0);
+                    assert!(*info == 0, "eigendecomposition did not converge");
+
+                    (ColVec::from(w.slice(..u32::from_(*m).extract())), z)
+                }
+            }
+         )+
+    }
+}
+
+ev!(f32, f64);
+
+macro_rules! eigh {
+    ($($t:ty => $real:ty),+,) => {
+        $(
+            impl Mat<$t> {
+                /// Computes the eigenvalues and eigenvectors of a symmetric (or hermitian) matrix
+                ///
+                /// Returns the eigenvalues in ascending order, and a matrix whose columns are the
+                /// corresponding orthonormal eigenvectors
+                ///
+                /// NOTE Only the lower triangle of the matrix is read. The matrix is taken by
+                /// value, its buffer will be re-used to store the eigenvectors
+                ///
+                /// # Panics
+                ///
+                /// If the matrix is not square, or if the algorithm doesn't converge
+                pub fn eigh(mut self) -> (ColVec<$real>, Mat<$t>) {
+                    unsafe {
+                        assert_eq!(self.nrows(), self.ncols());
+
+                        let w = Ev::evd(&mut self);
+
+                        (w, self)
+                    }
+                }
+
+                /// Like `eigh()`, but only computes the eigenvalues (and eigenvectors) whose
+                /// indices, in ascending order, lie in `range`
+                ///
+                /// For example, `A.eigh_range(0..k)` computes the `k` smallest eigenvalues
+                ///
+                /// # Panics
+                ///
+                /// If the matrix is not square, if `range` is empty or out of bounds, or if the
+                /// algorithm doesn't converge
+                pub fn eigh_range(self, range: Range<u32>) -> (ColVec<$real>, Mat<$t>) {
+                    unsafe {
+                        assert_eq!(self.nrows(), self.ncols());
+                        assert!(range.start < range.end);
+                        assert!(range.end <= self.nrows());
+
+                        let il = i32::from_(range.start).unwrap() + 1;
+                        let iu = i32::from_(range.end).unwrap();
+
+                        Ev::evr(self, il, iu)
+                    }
+                }
+            }
+
+            impl<'a> SubMat<'a, $t> {
+                /// Computes the eigenvalues and eigenvectors of a symmetric (or hermitian) matrix
+                ///
+                /// See `Mat::eigh()`. NOTE The view is copied into a new buffer
+                pub fn eigh(self) -> (ColVec<$real>, Mat<$t>) {
+                    Mat::from(self).eigh()
+                }
+
+                /// See `Mat::eigh_range()`. NOTE The view is copied into a new buffer
+                pub fn eigh_range(self, range: Range<u32>) -> (ColVec<$real>, Mat<$t>) {
+                    Mat::from(self).eigh_range(range)
+                }
+            }
+         )+
+    }
+}
+
+eigh! {
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => f32,
+    Complex<f64> => f64,
+}
@@ -0,0 +1,41 @@
+use std::{error, fmt};
+
+use Error;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::DimensionMismatch { lhs, rhs } => {
+                write!(f, "dimension mismatch: {:?} and {:?}", lhs, rhs)
+            },
+            Error::IllegalArgument { position } => {
+                write!(f, "the argument at position {} has an illegal value", position)
+            },
+            Error::NoConvergence { iterations } => {
+                write!(f, "the iterative method didn't converge after {} iterations", iterations)
+            },
+            Error::NotPositiveDefinite { order } => {
+                write!(f, "the leading minor of order {} is not positive definite", order)
+            },
+            Error::NotSquare { size } => {
+                write!(f, "the matrix is not square: {:?}", size)
+            },
+            Error::Singular { pivot } => {
+                write!(f, "the matrix is singular: the pivot {} is exactly zero", pivot)
+            },
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::DimensionMismatch { .. } => "dimension mismatch",
+            Error::IllegalArgument { .. } => "illegal argument",
+            Error::NoConvergence { .. } => "no convergence",
+            Error::NotPositiveDefinite { .. } => "matrix is not positive definite",
+            Error::NotSquare { .. } => "matrix is not square",
+            Error::Singular { .. } => "matrix is singular",
+        }
+    }
+}
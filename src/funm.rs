@@ -0,0 +1,369 @@
+//! Matrix functions: exponential, logarithm and square root
+
+use std::f32;
+use std::f64;
+use std::ops::Mul;
+
+use assign::AddAssign;
+use blas::{Axpy, Gemm};
+use complex::Complex;
+use lapack::{Getrf, Getri, Getrs, Lange};
+use onezero::{One, Zero};
+
+use ops;
+use traits::{Eval, MapInplace, Matrix, Slice, Solve, TryInv};
+use {Error, Mat};
+
+/// Maximum one norm of `A` for which the Padé approximants of degree 3, 5, 7 and 9 are accurate
+/// to double precision (Higham, 2005)
+const THETA: [f64; 4] =
+    [1.495585217958292e-2, 2.539398330063230e-1, 9.504178996162932e-1, 2.097847961257068];
+
+/// Like `THETA`, but for the Padé approximant of degree 13
+const THETA_13: f64 = 5.371920351148152;
+
+/// Coefficients of the Padé approximants of degree 3, 5, 7 and 9 to the exponential
+const B3: [f64; 4] = [120., 60., 12., 1.];
+const B5: [f64; 6] = [30240., 15120., 3360., 420., 30., 1.];
+const B7: [f64; 8] = [17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.];
+const B9: [f64; 10] = [
+    17643225600., 8821612800., 2075673600., 302702400., 30270240., 2162160., 110880., 3960., 90.,
+    1.,
+];
+
+/// Coefficients of the Padé approximant of degree 13 to the exponential
+const B13: [f64; 14] = [
+    64764752532480000., 32382376266240000., 7771770303897600., 1187353796428800.,
+    129060195264000., 10559470521600., 670442572800., 33522128640., 1323241920., 40840800.,
+    960960., 16380., 182., 1.,
+];
+
+/// Nodes and weights of the 8 point Gauss-Legendre quadrature on `[0, 1]`
+const GAUSS_LEGENDRE: [(f64, f64); 8] = [
+    (0.0198550717512319, 0.0506142681451881),
+    (0.1016667612931866, 0.1111905172266872),
+    (0.2372337950418355, 0.1568533229389436),
+    (0.4082826787521751, 0.1813418916891810),
+    (0.5917173212478249, 0.1813418916891810),
+    (0.7627662049581645, 0.1568533229389436),
+    (0.8983332387068134, 0.1111905172266872),
+    (0.9801449282487681, 0.0506142681451881),
+];
+
+/// Maximum number of Denman-Beavers iterations
+const MAX_ITER: u32 = 100;
+
+/// Maximum number of square roots taken by the inverse scaling and squaring method
+const MAX_SQRTM: u32 = 64;
+
+/// The operations on the elements of the matrix required by the matrix functions
+trait Funm: Axpy + Gemm + Getrf + Getri + Getrs + Lange + Mul<Output=Self> + One + Zero {
+    /// The machine epsilon of the real part of the element
+    fn eps() -> f64;
+
+    /// Returns the one norm of `A`
+    fn norm1(a: &Mat<Self>) -> f64;
+
+    /// Converts a real constant into an element
+    fn real(x: f64) -> Self;
+}
+
+macro_rules! funm {
+    ($($t:ident),+) => {
+        $(
+            impl Funm for $t {
+                fn eps() -> f64 {
+                    $t::EPSILON as f64
+                }
+
+                fn norm1(a: &Mat<$t>) -> f64 {
+                    unsafe {
+                        ops::lange(&b'1', a.slice(..)) as f64
+                    }
+                }
+
+                fn real(x: f64) -> $t {
+                    x as $t
+                }
+            }
+
+            impl Funm for Complex<$t> {
+                fn eps() -> f64 {
+                    $t::EPSILON as f64
+                }
+
+                fn norm1(a: &Mat<Complex<$t>>) -> f64 {
+                    unsafe {
+                        ops::lange(&b'1', a.slice(..)) as f64
+                    }
+                }
+
+                fn real(x: f64) -> Complex<$t> {
+                    Complex { re: x as $t, im: 0. }
+                }
+            }
+         )+
+    }
+}
+
+funm!(f32, f64);
+
+/// Returns `alpha * I`
+fn eye<T>(n: u32, alpha: f64) -> Mat<T> where T: Funm {
+    let alpha = T::real(alpha);
+
+    Mat::from_fn((n, n), |(r, c)| if r == c { alpha } else { T::zero() })
+}
+
+/// Returns `c * I + sum(alpha_i * A_i)`
+fn lincomb<T>(n: u32, c: f64, terms: &[(f64, &Mat<T>)]) -> Mat<T> where T: Funm {
+    let mut y = eye(n, c);
+
+    for &(alpha, a) in terms {
+        y.add_assign(a * T::real(alpha));
+    }
+
+    y
+}
+
+/// Returns `A * B`
+fn mul<T>(a: &Mat<T>, b: &Mat<T>) -> Mat<T> where T: Funm {
+    (a * b).eval()
+}
+
+/// Returns `(V - U) \ (V + U)`, the Padé approximant from its odd (`U`) and even (`V`) parts
+fn pade<T>(u: Mat<T>, v: Mat<T>) -> Mat<T> where T: Funm {
+    let mut q = v.clone();
+    q.add_assign(&u * T::real(-1.));
+
+    let mut p = v;
+    p.add_assign(&u);
+
+    q.solve(p)
+}
+
+/// Evaluates the Padé approximant of degree `b.len() - 1` (3, 5, 7 or 9) to `exp(A)`
+fn pade_small<T>(a: &Mat<T>, b: &[f64]) -> Mat<T> where T: Funm {
+    let n = a.nrows();
+
+    // A^2, A^4, ..
+    let a2 = mul(a, a);
+    let mut powers = vec![a2];
+    for _ in 2..b.len() / 2 {
+        let next = mul(powers.last().unwrap(), &powers[0]);
+        powers.push(next);
+    }
+
+    let mut odd = vec![];
+    let mut even = vec![];
+    for (i, p) in powers.iter().enumerate() {
+        even.push((b[2 * i + 2], p));
+        odd.push((b[2 * i + 3], p));
+    }
+
+    let u = mul(a, &lincomb(n, b[1], &odd));
+    let v = lincomb(n, b[0], &even);
+
+    pade(u, v)
+}
+
+/// Evaluates the Padé approximant of degree 13 to `exp(A)`
+fn pade_13<T>(a: &Mat<T>) -> Mat<T> where T: Funm {
+    let ref b = B13;
+    let n = a.nrows();
+
+    let ref a2 = mul(a, a);
+    let ref a4 = mul(a2, a2);
+    let ref a6 = mul(a2, a4);
+
+    let mut u = mul(a6, &lincomb(n, 0., &[(b[13], a6), (b[11], a4), (b[9], a2)]));
+    u.add_assign(&lincomb(n, b[1], &[(b[7], a6), (b[5], a4), (b[3], a2)]));
+    let u = mul(a, &u);
+
+    let mut v = mul(a6, &lincomb(n, 0., &[(b[12], a6), (b[10], a4), (b[8], a2)]));
+    v.add_assign(&lincomb(n, b[0], &[(b[6], a6), (b[4], a4), (b[2], a2)]));
+
+    pade(u, v)
+}
+
+/// Scaling and squaring method (Higham, 2005)
+fn expm<T>(mut a: Mat<T>) -> Mat<T> where T: Funm {
+    if a.nrows() == 0 {
+        return a
+    }
+
+    let norm = T::norm1(&a);
+
+    if norm <= THETA[0] {
+        return pade_small(&a, &B3)
+    } else if norm <= THETA[1] {
+        return pade_small(&a, &B5)
+    } else if norm <= THETA[2] {
+        return pade_small(&a, &B7)
+    } else if norm <= THETA[3] {
+        return pade_small(&a, &B9)
+    }
+
+    // scale `A` by `2^-s` so its norm falls below `THETA_13`
+    let s = (norm / THETA_13).log2().ceil().max(0.) as i32;
+    let alpha = T::real(2f64.powi(-s));
+    a.map_inplace(|x| *x = *x * alpha);
+
+    // and undo the scaling by squaring the result `s` times
+    let mut r = pade_13(&a);
+    for _ in 0..s {
+        r = mul(&r, &r);
+    }
+
+    r
+}
+
+/// Product form of the Denman-Beavers iteration (Higham, 2008)
+fn sqrtm<T>(a: Mat<T>) -> Result<Mat<T>, Error> where T: Funm {
+    let n = a.nrows();
+
+    if n == 0 {
+        return Ok(a)
+    }
+
+    let tol = T::eps() * n as f64;
+    let mut err = f64::INFINITY;
+    let mut m = a.clone();
+    let mut y = a;
+
+    for _ in 0..MAX_ITER {
+        let ref m_inv = try!(m.clone().try_inv());
+
+        // Y <- Y * (I + M^-1) / 2
+        y = mul(&y, &lincomb(n, 0.5, &[(0.5, m_inv)]));
+
+        // M <- (I + (M + M^-1) / 2) / 2
+        m = lincomb(n, 0.5, &[(0.25, &m), (0.25, m_inv)]);
+
+        let mut d = m.clone();
+        d.add_assign(&eye(n, -1.));
+        let next = T::norm1(&d);
+
+        // NB once close to convergence, rounding errors may keep `M` from getting any closer to
+        // the identity
+        if next <= tol || (next >= err && next <= tol.sqrt()) {
+            return Ok(y)
+        }
+
+        err = next;
+    }
+
+    Err(Error::NoConvergence { iterations: MAX_ITER })
+}
+
+/// Inverse scaling and squaring method
+fn logm<T>(mut a: Mat<T>) -> Result<Mat<T>, Error> where T: Funm {
+    let n = a.nrows();
+
+    if n == 0 {
+        return Ok(a)
+    }
+
+    // take square roots until `A` is close to the identity, `log(A) = 2^k * log(A^(1/2^k))`
+    let mut k = 0;
+    let mut x;
+    loop {
+        x = a.clone();
+        x.add_assign(&eye(n, -1.));
+
+        // NB this also bails out if `A` has non-finite elements, its norm is never small enough
+        if T::norm1(&x) <= 0.25 {
+            break
+        } else if k == MAX_SQRTM {
+            return Err(Error::NoConvergence { iterations: k })
+        }
+
+        a = try!(sqrtm(a));
+        k += 1;
+    }
+
+    // log(I + X) = integral(X * (I + t * X)^-1, t = 0..1)
+    let mut l = Mat::zeros((n, n));
+    for &(t, w) in &GAUSS_LEGENDRE {
+        let mut p = eye(n, 1.);
+        p.add_assign(&x * T::real(t));
+
+        l.add_assign(&p.solve(x.clone()) * T::real(w));
+    }
+
+    let alpha = T::real(2f64.powi(k as i32));
+    l.map_inplace(|x| *x = *x * alpha);
+
+    Ok(l)
+}
+
+macro_rules! matrix_functions {
+    ($($t:ty),+) => {
+        $(
+            impl Mat<$t> {
+                /// Computes the exponential of a square matrix
+                ///
+                /// Uses the scaling and squaring method, with Padé approximants of degree up to
+                /// 13
+                ///
+                /// NOTE The matrix is taken by value, its buffer is used as workspace
+                ///
+                /// # Panics
+                ///
+                /// If the matrix is not square
+                pub fn expm(self) -> Mat<$t> {
+                    assert_eq!(self.nrows(), self.ncols());
+
+                    expm(self)
+                }
+
+                /// Computes the principal logarithm of a square matrix
+                ///
+                /// Uses the inverse scaling and squaring method: square roots are taken until
+                /// the matrix is close to the identity, then the logarithm is approximated with
+                /// a Gauss-Legendre quadrature
+                ///
+                /// # Errors
+                ///
+                /// - `NoConvergence` if the matrix doesn't get close enough to the identity after
+                ///   64 square roots, or if one of the square roots doesn't converge
+                /// - `Singular` if one of the iterates of the square root is singular
+                ///
+                /// Either is the case if the matrix has eigenvalues on the closed negative real
+                /// axis
+                ///
+                /// # Panics
+                ///
+                /// If the matrix is not square
+                pub fn logm(self) -> Result<Mat<$t>, Error> {
+                    assert_eq!(self.nrows(), self.ncols());
+
+                    logm(self)
+                }
+
+                /// Computes the principal square root of a square matrix
+                ///
+                /// Uses the product form of the Denman-Beavers iteration
+                ///
+                /// # Errors
+                ///
+                /// - `NoConvergence` if the iteration doesn't converge after 100 steps
+                /// - `Singular` if one of the iterates is singular
+                ///
+                /// Either is the case if the matrix has eigenvalues on the closed negative real
+                /// axis
+                ///
+                /// # Panics
+                ///
+                /// If the matrix is not square
+                pub fn sqrtm(self) -> Result<Mat<$t>, Error> {
+                    assert_eq!(self.nrows(), self.ncols());
+
+                    sqrtm(self)
+                }
+            }
+         )+
+    }
+}
+
+matrix_functions!(f32, f64, Complex<f32>, Complex<f64>);
@@ -0,0 +1,111 @@
+//! The LAPACK routines used by this crate
+//!
+//! NB Unlike the `blas` traits, only the element types that implement `Lapack` implement these
+//! traits, the routines themselves are provided by the `LapackBackend` of the element type.
+
+use complex::Complex;
+
+use traits::Lapack;
+
+/// Band linear system solver
+pub trait Gbsv: Lapack {}
+
+/// Condition number estimate from the LU factorization
+pub trait Gecon: Lapack {}
+
+/// General eigensolver
+pub trait Geev: Lapack {}
+
+/// Least squares solver
+///
+/// NB Unlike the other traits, this one is implemented for each element type, because the
+/// threshold used to determine the rank of the coefficient matrix depends on the element type
+pub trait Gelsd: Lapack {
+    /// Returns the `rcond` that makes `gelsd` use the machine precision as the threshold, i.e.
+    /// `-1`
+    fn rcond() -> Self::Real;
+}
+
+/// QR factorization
+pub trait Geqrf: Lapack {}
+
+/// Singular value decomposition
+pub trait Gesdd: Lapack {}
+
+/// LU factorization
+pub trait Getrf: Lapack {}
+
+/// Inverse from the LU factorization
+pub trait Getri: Lapack {}
+
+/// Linear system solver from the LU factorization
+pub trait Getrs: Lapack {}
+
+/// Tridiagonal linear system solver
+pub trait Gtsv: Lapack {}
+
+/// Symmetric/hermitian eigensolver
+pub trait Heevd: Lapack {}
+
+/// Symmetric/hermitian eigensolver for a subset of the eigenvalues
+pub trait Heevr: Lapack {}
+
+/// Matrix norms
+pub trait Lange: Lapack {}
+
+/// `Q` matrix from the QR factorization
+pub trait Orgqr: Lapack {}
+
+/// Multiplication by the `Q` matrix of the QR factorization
+pub trait Ormqr: Lapack {}
+
+/// Cholesky factorization
+pub trait Potrf: Lapack {}
+
+/// Inverse from the Cholesky factorization
+pub trait Potri: Lapack {}
+
+/// Linear system solver from the Cholesky factorization
+pub trait Potrs: Lapack {}
+
+/// Triangular linear system solver
+pub trait Trtrs: Lapack {}
+
+impl<T> Gbsv for T where T: Lapack {}
+impl<T> Gecon for T where T: Lapack {}
+impl<T> Geev for T where T: Lapack {}
+impl<T> Geqrf for T where T: Lapack {}
+impl<T> Gesdd for T where T: Lapack {}
+impl<T> Getrf for T where T: Lapack {}
+impl<T> Getri for T where T: Lapack {}
+impl<T> Getrs for T where T: Lapack {}
+impl<T> Gtsv for T where T: Lapack {}
+impl<T> Heevd for T where T: Lapack {}
+impl<T> Heevr for T where T: Lapack {}
+impl<T> Lange for T where T: Lapack {}
+impl<T> Orgqr for T where T: Lapack {}
+impl<T> Ormqr for T where T: Lapack {}
+impl<T> Potrf for T where T: Lapack {}
+impl<T> Potri for T where T: Lapack {}
+impl<T> Potrs for T where T: Lapack {}
+impl<T> Trtrs for T where T: Lapack {}
+
+macro_rules! gelsd {
+    ($($t:ident),+) => {
+        $(
+            impl Gelsd for $t {
+                fn rcond() -> $t {
+                    -1.
+                }
+            }
+
+            impl Gelsd for Complex<$t> {
+                fn rcond() -> $t {
+                    -1.
+                }
+            }
+         )+
+    }
+}
+
+gelsd!(f32, f64);
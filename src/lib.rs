@@ -10,6 +10,29 @@
 //! git = "https://github.com/japaric/linalg.rs"
 //! ```
 //!
+//! The `blas` and `lapack` features, both enabled by default, link to the C BLAS and LAPACK
+//! libraries. To build without any C library, enable the `pure-rust` feature, which overrides
+//! `blas` and `lapack`. The BLAS routines will be replaced with native Rust kernels, and the
+//! functionality that depends on LAPACK will be unavailable, that is:
+//!
+//! - the Cholesky, LU and QR factorizations, and `lstsq`
+//! - `inv`, `try_inv`, `det` and `slogdet`
+//! - `solve` and `try_solve` of general and banded systems (triangular solves are available)
+//! - `svd`, `eig` and `eigh`
+//! - `expm`, `logm` and `sqrtm`
+//! - `MatrixNorm`
+//! - the `Ffi` backend, and `set_lapack_backend`
+//!
+//! ``` ignore
+//! [dependencies.linalg]
+//! features = ["pure-rust"]
+//! git = "https://github.com/japaric/linalg.rs"
+//! ```
+//!
+//! Besides `f32`, `f64`, `c64` and `c128`, matrices of integers (e.g. `Mat<i64>`) and of
+//! user-defined element types can be added, subtracted, scaled and multiplied. These operations
+//! use the native Rust kernels, see the `Element` trait and the `element!` macro.
+//!
 //! All the functionality is available via the [prelude] module, glob import it:
 //!
 //! [prelude]: prelude/index.html
@@ -141,6 +164,78 @@
 //! let B = A.inv();
 //! ```
 //!
+//! - Determinant
+//!
+//! ``` ignore
+//! // Python
+//! d = numpy.linalg.det(A)
+//! sign, logdet = numpy.linalg.slogdet(A)
+//!
+//! // Rust
+//! let d = A.det();
+//! let (sign, logdet) = A.slogdet();
+//! ```
+//!
+//! - Linear systems
+//!
+//! ``` ignore
+//! // Python
+//! x = numpy.linalg.solve(A, b)
+//!
+//! // Rust
+//! let x = A.solve(b);
+//! ```
+//!
+//! - Least squares
+//!
+//! ``` ignore
+//! // Python
+//! x = numpy.linalg.lstsq(A, b)[0]
+//!
+//! // Rust
+//! let x = A.lstsq(b);
+//! ```
+//!
+//! - Singular value decomposition
+//!
+//! ``` ignore
+//! // Python
+//! U, s, Vt = numpy.linalg.svd(A)
+//!
+//! // Rust
+//! let (U, s, Vt) = A.svd();
+//! ```
+//!
+//! - Symmetric (hermitian) eigendecomposition
+//!
+//! ``` ignore
+//! // Python
+//! w, V = numpy.linalg.eigh(A)
+//!
+//! // Rust
+//! let (w, V) = A.eigh();
+//! ```
+//!
+//! - General eigendecomposition
+//!
+//! ``` ignore
+//! // Python
+//! w, V = numpy.linalg.eig(A)
+//!
+//! // Rust
+//! let (w, V) = A.eig();
+//! ```
+//!
+//! - Matrix exponential, logarithm and square root
+//!
+//! ``` ignore
+//! // Python
+//! E, L, S = scipy.linalg.expm(A), scipy.linalg.logm(A), scipy.linalg.sqrtm(A)
+//!
+//! // Rust
+//! let (E, L, S) = (A.clone().expm(), try!(A.clone().logm()), try!(A.sqrtm()));
+//! ```
+//!
 //! # Overview of the API
 //!
 //! - There are two types of structures provided by this crate: "owned structures", and "views".
@@ -154,6 +249,51 @@
 //!
 //! - There are several conversions between views and from views to owned structures available via
 //! the `From`/`Into` traits.
+//! - Owned buffers can be moved in and out of the owned structures without copying their
+//! elements: `Mat::from_vec`, `Mat::into_vec`, `Mat::reshape`, `Mat::into_col` and
+//! `ColVec::into_mat`.
+//! - Element-wise operations, `map()`, `map_inplace()`, `zip_with()`, `zip3_with()` and `fold()`,
+//! visit the elements in column major order, and walk all their operands in the same order even
+//! if some of them are transposed or strided.
+//!
+//! ``` ignore
+//! let C = A.zip_with(&B.t(), |a, b| a.max(*b));
+//! let sum = C.fold(0., |acc, c| acc + c);
+//! ```
+//!
+//! - Reductions along an axis: `sum_cols()`, `mean_rows()`, `max_cols()`, `argmax_rows()`, etc.
+//! The `*_cols` variants reduce each column and return a `RowVec`, the `*_rows` variants reduce
+//! each row and return a `ColVec`. Sums and means are computed with a matrix-vector product
+//! against a vector of ones.
+//!
+//! ``` ignore
+//! let totals = A.sum_cols();
+//! let predictions = scores.argmax_rows();
+//! ```
+//!
+//! - Row (column) vectors can be added to, or subtracted from, every row (column) of a matrix via
+//! `broadcast_rows()` (`broadcast_cols()`). These operations are rank-1 updates, and don't
+//! allocate more than a vector of ones.
+//!
+//! ``` ignore
+//! // center the data
+//! let ref mean = A.mean_cols();
+//! A.sub_assign(mean.broadcast_rows());
+//! ```
+//!
+//! - The outer product of a column vector and a row vector, `&x * y.t()`, is lazy. Evaluating it
+//! allocates a new matrix, whereas adding it to (or subtracting it from) an existing matrix is a
+//! single rank-1 update that doesn't allocate. Both operands must be borrowed: the transpose of an
+//! owned `ColVec` is an owned `RowVec`, which can't be part of a lazy product, so bind `y` by
+//! reference (or use `y.slice(..).t()`).
+//!
+//! ``` ignore
+//! let ref x = ColVec::ones(m);
+//! let ref y = ColVec::ones(n);
+//!
+//! let P = (x * y.t()).eval();
+//! A.add_assign(alpha * x * y.t());
+//! ```
 //!
 //! # Notes about operators
 //!
@@ -230,8 +370,100 @@
 //! - Transposing a matrix is "free", no allocations, copies or operations are performed . Do note
 //! that the `t()` method takes the caller by value.
 //!
+//! - `Mat` stores its elements in column-major order, `RowMat` in row-major order. Row-major data
+//! (e.g. from C/C++ code) can be moved into a `RowMat`, or viewed in place with
+//! `SubMat::reshape_row_major` (or its `SubMatMut` variant). Both layouts can be indexed, sliced
+//! and multiplied together, the BLAS calls get the right transpose flags.
+//!
+//! ``` ignore
+//! // `a: Vec<f64>` and `b: &[f64]` hold a `m x k` and a `k x n` matrix in row-major order
+//! let A = RowMat::from_vec((m, k), a);
+//! let B = SubMat::reshape_row_major(b, (k, n));
+//! let mut C = RowMat::zeros((m, n));
+//!
+//! C.set(&A * B);
+//!
+//! let c: Vec<f64> = C.into_vec();  // row-major
+//! ```
+//!
 //! - The `inv()` method computes the inverse of an owned (square) matrix and takes ownership of
 //! the caller. The caller's buffer will be re-used to store the inverse.
+//!
+//! - The `solve()` method solves the linear system `A * X = B` using the LU factorization of `A`,
+//! this is faster and more accurate than computing `A.inv() * B`. Both `A` and `B` are taken by
+//! value: an owned `A` is overwritten with its factorization, an owned `B` is overwritten with the
+//! solution. Views are copied into new buffers.
+//!
+//! - When solving several systems that share the same coefficient matrix, factorize the matrix
+//! once with the `lu()` method, and use the factorization to solve each system.
+//!
+//! ``` ignore
+//! let lu = A.lu();
+//!
+//! for b in rhss {
+//!     let x = lu.solve(b);
+//!
+//!     ..
+//! }
+//! ```
+//!
+//! - Triangular views, `A.lower()` and `A.upper()` (and their `unit_*` variants that assume a
+//! diagonal of ones), multiply and solve without touching the other half of `A`. The right hand
+//! side is overwritten in place, e.g. `L.solve(alpha * B)` computes `alpha * L^-1 * B` in the
+//! buffer of an owned `B`.
+//!
+//! ``` ignore
+//! let y = (A.upper() * &x).eval();
+//! let X = A.lower().solve(2. * B);
+//! ```
+//!
+//! - Symmetric views, `A.sym(Uplo::Upper)`, only read one triangular part of `A` and multiply via
+//! the symmetric BLAS routines. Products of a matrix by its own transpose, `A.t() * A` and
+//! `A * A.t()`, are evaluated with a single rank-k update that computes half of the (symmetric)
+//! result.
+//!
+//! ``` ignore
+//! let C = (A.sym(Uplo::Upper) * &B).eval();
+//! let G = (A.t() * &A).eval();
+//! ```
+//!
+//! - Band matrices, `BandMat`, only store the diagonals of their band, which are accessible via
+//! `diag()` and `diag_mut()`. Products by a column vector use the banded BLAS routine, and
+//! `solve()` uses the banded (or tridiagonal when `kl == ku == 1`) LAPACK solvers.
+//!
+//! ``` ignore
+//! let mut A = BandMat::zeros(n, (1, 2));
+//! A.diag_mut(0).set(4.);
+//! A.diag_mut(-1).set(1.);
+//!
+//! let y = (&A * &x).eval();
+//! let x = A.solve(y);
+//! ```
+//!
+//! - Sparse matrices, `SparseMat`, store only their non-zero elements in the CSC or CSR format.
+//! They are built from `(row, col, value)` triplets or from a dense matrix, and multiply dense
+//! column vectors and matrices. Transposing a sparse matrix switches its format, no data is moved.
+//!
+//! ``` ignore
+//! let S = SparseMat::from_triplets((m, n), SparseFormat::Csr, &[(0, 1, 2.), (3, 2, -1.)]);
+//!
+//! y.set(&S * &x);
+//! C.add_assign(2. * &S * &B);
+//! let D = Mat::from(&S.t());
+//! ```
+//!
+//! - Operations panic when the dimensions of their operands don't agree, or when a matrix is
+//! singular. Use the `try_*` variants (`try_add_assign()`, `try_eval()`, `try_inv()`,
+//! `try_mul()`, `try_set()`, `try_solve()`) to get an `Error` instead.
+//!
+//! ``` ignore
+//! match A.try_mul(B).and_then(|AB| AB.try_eval()).and_then(|C| C.try_inv()) {
+//!     Ok(C_inv) => ..,
+//!     Err(Error::DimensionMismatch { lhs, rhs }) => ..,
+//!     Err(Error::Singular { pivot }) => ..,
+//!     Err(e) => ..,
+//! }
+//! ```
 
 #![deny(missing_docs)]
 #![deny(warnings)]
@@ -244,26 +476,55 @@
 #![feature(unsafe_no_drop_flag)]
 
 extern crate assign;
-extern crate blas;
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+extern crate blas as blas_sys;
 extern crate cast;
 extern crate complex;
 extern crate core;
 extern crate extract;
-extern crate lapack;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+extern crate lapack as lapack_sys;
 extern crate onezero;
 
+mod backend;
+mod band;
+mod blas;
 mod chain;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod cholesky;
 mod cols;
+mod conj;
 mod debug;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod eig;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod eigh;
+mod error;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod funm;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod lapack;
 mod linear;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod lu;
+mod map;
 mod mat;
 mod ops;
 mod product;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod qr;
+mod row_mat;
 mod rows;
 mod scaled;
+mod sparse;
+mod strided_mat;
 mod stripes;
 mod submat_mut;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod svd;
+mod sym;
 mod tor;
+mod tri;
 
 pub mod prelude;
 pub mod strided;
@@ -275,13 +536,72 @@ use core::nonzero::NonZero;
 use std::marker::PhantomData;
 use std::ops::{Range, RangeFull};
 use std::ptr::Unique;
+use std::rc::Rc;
 use std::{mem, slice};
 
-use blas::Transpose;
 use cast::From as _0;
 use extract::Extract;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use lapack::Lange;
+
+use traits::{Backend, Element, Matrix};
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use traits::{Lapack, LapackBackend};
+
+/// Replaces the backend that the current thread uses for elements of type `T`, returns the
+/// previous backend
+///
+/// Every thread starts with the default backend: `Ffi`, or `Native` if the `blas` feature is
+/// disabled or the `pure-rust` feature is enabled. The backend only provides the BLAS routines,
+/// see `set_lapack_backend` for the LAPACK ones.
+///
+/// ``` ignore
+/// // Counts the calls to `gemm`, and forwards all the calls to `Ffi`
+/// struct Counting(Rc<Cell<usize>>);
+///
+/// impl Backend<f64> for Counting {
+///     unsafe fn gemm(&self, ..) {
+///         self.0.set(self.0.get() + 1);
+///         Ffi.gemm(..)
+///     }
+///
+///     ..
+/// }
+///
+/// let count = Rc::new(Cell::new(0));
+/// set_backend::<f64>(Box::new(Counting(count.clone())));
+/// ```
+pub fn set_backend<T>(backend: Box<Backend<T>>) -> Rc<Box<Backend<T>>> where T: Element {
+    T::set_backend(backend)
+}
 
-use traits::Matrix;
+/// Replaces the LAPACK backend that the current thread uses for elements of type `T`, returns the
+/// previous LAPACK backend
+///
+/// Every thread starts with `Ffi` as the LAPACK backend.
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub fn set_lapack_backend<T>(backend: Box<LapackBackend<T>>) -> Rc<Box<LapackBackend<T>>> where
+    T: Lapack,
+{
+    T::set_lapack_backend(backend)
+}
+
+/// An owned square band matrix, only the elements of its `kl` sub-diagonals and `ku`
+/// super-diagonals are stored
+// NB Uses the band storage of LAPACK: `A[i, j]` is stored at `ab[kl + ku + i - j, j]`. The first
+// `kl` rows of `ab` are never read by the products, they are workspace for the fill-in of the LU
+// factorization that `gbsv` performs in place.
+pub struct BandMat<T> {
+    ab: Mat<T>,
+    kl: i32,
+    ku: i32,
+}
+
+/// A vector repeated along the rows (`Broadcast<Row>`) or along the columns (`Broadcast<Col>`) of
+/// a matrix
+// NB `V` can only be `Col` or `Row`
+#[derive(Clone, Copy)]
+pub struct Broadcast<V>(V);
 
 /// A reserved chunk of memory
 pub struct Buffer<T>(Vec<T>);
@@ -304,6 +624,7 @@ impl<T> Buffer<T> {
 }
 
 /// Lazy matrix chain multiplication
+// NB The factors of a `Chain` are never conjugate transposes, see `ConjChain`
 pub struct Chain<'a, T> {
     first: (Transpose, SubMat<'a, T>),
     second: (Transpose, SubMat<'a, T>),
@@ -316,6 +637,30 @@ impl<'a, T> Chain<'a, T> {
     }
 }
 
+/// The Cholesky factorization of a Hermitian positive definite matrix: `A = L * L'`
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub struct Cholesky<T>(Mat<T>);
+
+/// Lazy matrix chain multiplication where at least one of the factors is a conjugate transpose
+///
+/// Unlike `Chain`, this product can't be transposed or multiplied by a row vector: `(A^H)^T` is
+/// the conjugate of `A`, which BLAS can't use as an operand.
+pub struct ConjChain<'a, T>(Chain<'a, T>);
+
+/// A lazily conjugated matrix
+///
+/// NOTE BLAS can't use a conjugated (but not transposed) matrix as an operand, so this view
+/// doesn't take part in products. Use `Mat::from(A.conj())` to evaluate it into an owned matrix
+/// first.
+// NB `M` can only be `SubMat`
+#[derive(Clone, Copy)]
+pub struct Conjugated<M>(M);
+
+/// A lazily conjugated and transposed matrix, i.e. the Hermitian transpose
+// NB `M` can only be `SubMat`
+#[derive(Clone, Copy)]
+pub struct ConjTransposed<M>(M);
+
 /// Immutable view into the column of a matrix
 pub struct Col<'a, T>(Slice<'a, T>);
 
@@ -338,6 +683,57 @@ pub struct Diag<'a, T>(Slice<'a, T>);
 /// A mutable "view" into the diagonal of a matrix
 pub struct DiagMut<'a, T>(Diag<'a, T>);
 
+/// Diagonal option of the BLAS triangular routines
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Diagonal {
+    /// Use the diagonal elements of the matrix
+    NonUnit,
+    /// Assume that the diagonal elements are ones, they are never read
+    Unit,
+}
+
+/// The default backend: calls into the system BLAS and LAPACK libraries
+#[cfg(all(any(feature = "blas", feature = "lapack"), not(feature = "pure-rust")))]
+#[derive(Clone, Copy, Debug)]
+pub struct Ffi;
+
+/// Errors that can arise while performing linear algebra operations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// The dimensions of the operands don't agree
+    DimensionMismatch {
+        /// The size of the left hand side operand
+        lhs: (u32, u32),
+        /// The size of the right hand side operand
+        rhs: (u32, u32),
+    },
+    /// A LAPACK routine was called with an illegal argument
+    IllegalArgument {
+        /// The (one-based) position of the illegal argument
+        position: u32,
+    },
+    /// An iterative method didn't converge
+    NoConvergence {
+        /// The number of iterations that were performed
+        iterations: u32,
+    },
+    /// The matrix is not positive definite
+    NotPositiveDefinite {
+        /// The order of the leading minor that is not positive definite
+        order: u32,
+    },
+    /// The matrix is not square
+    NotSquare {
+        /// The size of the matrix
+        size: (u32, u32),
+    },
+    /// The matrix is singular
+    Singular {
+        /// The (zero-based) index of the pivot that is exactly zero
+        pivot: u32,
+    },
+}
+
 /// An immutable iterator over a matrix in horizontal stripes
 pub struct HStripes<'a, T> {
     mat: SubMat<'a, T>,
@@ -347,6 +743,21 @@ pub struct HStripes<'a, T> {
 /// A "mutable" iterator over a matrix in horizontal stripes
 pub struct HStripesMut<'a, T>(HStripes<'a, T>);
 
+/// An immutable view into the lower triangular part of a square matrix, the elements above the
+/// diagonal are never read
+// NB `M` can only be `SubMat`
+#[derive(Clone, Copy)]
+pub struct LowerTri<M>(M, Diagonal);
+
+/// The LU factorization of a square matrix: `A = P * L * U`
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub struct Lu<T> where T: Lange {
+    anorm: T::Real,
+    info: i32,
+    ipiv: Vec<i32>,
+    lu: Mat<T>,
+}
+
 /// An owned matrix
 // NB `nrows` and `ncols` are guaranteed to be non-negative
 #[unsafe_no_drop_flag]
@@ -389,6 +800,31 @@ impl<T> Mat<T> {
 
 }
 
+/// A backend that uses BLAS kernels written in Rust
+///
+/// This is the default backend of the integer types, and of every element type when the `blas`
+/// feature is disabled or the `pure-rust` feature is enabled. It doesn't provide the LAPACK
+/// routines.
+#[derive(Clone, Copy, Debug)]
+pub struct Native;
+
+/// The kind of matrix norm
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NormKind {
+    /// Frobenius norm: the square root of the sum of the squared absolute values of the elements
+    Frobenius,
+    /// Infinity norm: the maximum absolute row sum
+    Inf,
+    /// The largest absolute value of the elements (NOTE this is not a matrix norm)
+    MaxAbs,
+    /// Nuclear norm: the sum of the singular values
+    Nuclear,
+    /// One norm: the maximum absolute column sum
+    One,
+    /// Spectral norm: the largest singular value
+    Spectral,
+}
+
 /// A pool of uninitialized matrices
 pub struct Pool<'a, T>(Option<&'a mut [T]>) where T: 'a;
 
@@ -441,10 +877,26 @@ impl<'a, T> Pool<'a, T> {
 //
 // - Col-like: `Product<Chain, Col>`, `Product<Transposed<SubMat>, Col>`, `Product<SubMat, Col>`
 // - Row-like: `Product<Row, Chain>`, `Product<Row, Transposed<SubMat>>`, `Product<Row, SubMat>`
+// - Outer: `Product<Col, Row>`
 //
-// -> 6 types
+// -> 7 types
 pub struct Product<L, R>(L, R);
 
+/// The QR factorization of a matrix: `A = Q * R`
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub struct Qr<T> {
+    qr: Mat<T>,
+    tau: Vec<T>,
+}
+
+/// An owned matrix that stores its elements in row major order
+///
+/// A row-major `m x n` matrix has the same memory layout as the transpose of a column-major
+/// `n x m` matrix, so this is a `Transposed<Mat>`: it takes part in the same operations
+/// (products, `set()`, `add_assign()`, `solve()`, ...) and the BLAS calls get the right transpose
+/// flags.
+pub type RowMat<T> = Transposed<Mat<T>>;
+
 /// Immutable view into the row of a matrix
 pub struct Row<'a, T>(Slice<'a, T>);
 
@@ -466,11 +918,96 @@ pub struct RowsMut<'a, T>(Rows<'a, T>);
 #[derive(Clone, Copy, Debug)]
 pub struct Scaled<M>(M::Elem, M) where M: Matrix;
 
+/// Side option of the BLAS triangular routines
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    /// The triangular matrix multiplies from the left: `op(A) * B`
+    Left,
+    /// The triangular matrix multiplies from the right: `B * op(A)`
+    Right,
+}
+
+/// Storage format of a sparse matrix
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SparseFormat {
+    /// Compressed sparse column: the non-zero elements are stored column by column
+    Csc,
+    /// Compressed sparse row: the non-zero elements are stored row by row
+    Csr,
+}
+
+/// An owned sparse matrix, only its non-zero elements are stored
+// NB The "major" dimension is the column dimension in the CSC format and the row dimension in the
+// CSR format. `indptr` has one element per major index plus one, and the minor indices and the
+// values of the `i`th major index are `indices[indptr[i]..indptr[i + 1]]` and
+// `data[indptr[i]..indptr[i + 1]]`. The minor indices of each major index are sorted and unique.
+//
+// A CSR matrix has the same representation as the CSC form of its transpose, that's why
+// transposing only swaps `format`, `nrows` and `ncols`.
+pub struct SparseMat<T> {
+    data: Vec<T>,
+    format: SparseFormat,
+    indices: Vec<u32>,
+    indptr: Vec<usize>,
+    ncols: u32,
+    nrows: u32,
+}
+
+/// Immutable view into a matrix whose elements are not contiguous within a column, e.g. the real
+/// (or imaginary) parts of a complex matrix
+// NB `stride` is the distance between two consecutive columns, and `step` is the distance between
+// two consecutive elements of a column. `ncols`, `nrows` and `stride` are guaranteed to be
+// non-negative, `step` is guaranteed to be positive
+pub struct StridedMat<'a, T> {
+    _marker: PhantomData<fn() -> &'a T>,
+    data: NonZero<*mut T>,
+    ncols: i32,
+    nrows: i32,
+    step: i32,
+    stride: i32,
+}
+
+/// An immutable view into a symmetric matrix, only the elements of its `Uplo` triangular part are
+/// read
+// NB `M` can only be `SubMat`
+#[derive(Clone, Copy)]
+pub struct Symmetric<M>(M, Uplo);
+
+/// Transposition option of the BLAS routines
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transpose {
+    /// Use the matrix as it is
+    No,
+    /// Use the transpose of the matrix
+    Yes,
+    /// Use the conjugate transpose of the matrix
+    Conj,
+}
+
 /// A lazily transposed matrix
+///
+/// This is also how row-major matrices are represented: a row-major `m x n` matrix has the same
+/// memory layout as the transpose of a column-major `n x m` matrix. See `RowMat` and
+/// `SubMat::reshape_row_major`.
 // NB `M` can only be `Mat`, `SubMat`, or `SubMatMut`
 #[derive(Clone, Copy)]
 pub struct Transposed<M>(M);
 
+/// Triangle option of the BLAS triangular routines
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Uplo {
+    /// Use the lower triangular part of the matrix
+    Lower,
+    /// Use the upper triangular part of the matrix
+    Upper,
+}
+
+/// An immutable view into the upper triangular part of a square matrix, the elements below the
+/// diagonal are never read
+// NB `M` can only be `SubMat`
+#[derive(Clone, Copy)]
+pub struct UpperTri<M>(M, Diagonal);
+
 /// An immutable iterator over a matrix in vertical stripes
 pub struct VStripes<'a, T> {
     mat: SubMat<'a, T>,
@@ -527,6 +1064,22 @@ impl<'a, T> SubMat<'a, T> {
         }
     }
 
+    /// Reshapes a slice that holds a matrix in row-major order as an immutable (sub)matrix
+    ///
+    /// The returned view is the transpose of a column-major `ncols x nrows` matrix, no data is
+    /// moved.
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows * ncols != slice.len() ||`
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31 ||`
+    pub fn reshape_row_major(slice: &[T], (nrows, ncols): (u32, u32)) -> Transposed<SubMat<T>> {
+        Transposed(SubMat::reshape(slice, (ncols, nrows)))
+    }
+
     fn as_slice(&self) -> Option<&[T]> {
         unsafe {
             if self.nrows == self.stride {
@@ -639,6 +1192,25 @@ impl<'a, T> SubMatMut<'a, T> {
         }
     }
 
+    /// Reshapes a slice that holds a matrix in row-major order as a mutable (sub)matrix
+    ///
+    /// The returned "view" is the transpose of a column-major `ncols x nrows` matrix, no data is
+    /// moved.
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows * ncols != slice.len() ||`
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31 ||`
+    pub fn reshape_row_major(
+        slice: &mut [T],
+        (nrows, ncols): (u32, u32),
+    ) -> Transposed<SubMatMut<T>> {
+        Transposed(SubMatMut::reshape(slice, (ncols, nrows)))
+    }
+
     fn as_slice_mut(&mut self) -> Option<&mut [T]> {
         self.0.as_slice().map(|s| unsafe {
             slice::from_raw_parts_mut(s.as_ptr() as *mut _, s.len())
@@ -669,9 +1241,17 @@ trait Forward: Sized {
     }
 }
 
+impl<'a, T> Forward for &'a BandMat<T> {}
+impl<V> Forward for Broadcast<V> {}
 impl<'a, T> Forward for Chain<'a, T> {}
+impl<'a, T> Forward for ConjChain<'a, T> {}
+impl<M> Forward for ConjTransposed<M> {}
+impl<M> Forward for LowerTri<M> {}
 impl<L, R> Forward for Product<L, R> {}
 impl<M> Forward for Scaled<M> where M: Matrix {}
+impl<'a, T> Forward for &'a SparseMat<T> {}
+impl<M> Forward for Symmetric<M> {}
+impl<M> Forward for UpperTri<M> {}
 
 macro_rules! copy {
     ($($ty:ident),+) => {
@@ -687,7 +1267,7 @@ macro_rules! copy {
     };
 }
 
-copy!(Col, Diag, Row, Slice, SubMat);
+copy!(Col, Diag, Row, Slice, StridedMat, SubMat);
 
 macro_rules! send {
     ($($ty:ident),+) => {
@@ -697,7 +1277,7 @@ macro_rules! send {
     };
 }
 
-send!(Col, Row, SubMat);
+send!(Col, Row, StridedMat, SubMat);
 
 macro_rules! send_mut {
     ($($ty:ident),+) => {
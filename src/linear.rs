@@ -8,8 +8,8 @@ use cast::From as _0;
 use onezero::{One, Zero};
 
 use strided;
-use traits::{Matrix, Slice, SliceMut, Transpose};
-use {Col, ColMut, ColVec, Diag, DiagMut, Row, RowMut, RowVec, Tor};
+use traits::{Matrix, MatrixCol, MatrixColMut, Slice, SliceMut, Transpose};
+use {Broadcast, Col, ColMut, ColVec, Diag, DiagMut, Mat, Row, RowMut, RowVec, Tor};
 
 impl<'a, T> Col<'a, T> {
     /// Returns a slice that contains the whole vector
@@ -17,6 +17,12 @@ impl<'a, T> Col<'a, T> {
         self.0.as_slice()
     }
 
+    /// Repeats the vector along the columns of a matrix, `A.add_assign(x.broadcast_cols())`
+    /// adds `x` to each column of `A`
+    pub fn broadcast_cols(&self) -> Broadcast<Col<'a, T>> {
+        Broadcast(*self)
+    }
+
     /// Returns an "immutable iterator" over the column
     pub fn iter(&self) -> strided::Iter<'a, T> {
         self.0.iter()
@@ -38,6 +44,12 @@ impl<'a, T> ColMut<'a, T> {
         (self.0).0.as_slice_mut()
     }
 
+    /// Repeats the vector along the columns of a matrix, `A.add_assign(x.broadcast_cols())`
+    /// adds `x` to each column of `A`
+    pub fn broadcast_cols(&self) -> Broadcast<Col<T>> {
+        Broadcast(self.0)
+    }
+
     /// Returns an "immutable iterator" over the column
     pub fn iter(&self) -> strided::Iter<T> {
         self.0.iter()
@@ -90,6 +102,22 @@ impl<T> ColVec<T> {
         self.0.as_slice_mut()
     }
 
+    /// Repeats the vector along the columns of a matrix, `A.add_assign(x.broadcast_cols())`
+    /// adds `x` to each column of `A`
+    pub fn broadcast_cols(&self) -> Broadcast<Col<T>> {
+        Broadcast(self.slice(..))
+    }
+
+    /// Converts the vector into a matrix with dimensions `(nrows, ncols)` whose columns are
+    /// consecutive chunks of the vector, the allocation is re-used
+    ///
+    /// # Panics
+    ///
+    /// If `nrows * ncols != self.nrows()`
+    pub fn into_mat(self, (nrows, ncols): (u32, u32)) -> Mat<T> {
+        Mat::from_vec((nrows, ncols), self.0.into_vec())
+    }
+
     /// Returns an "immutable iterator" over the vector
     pub fn iter(&self) -> slice::Iter<T> {
         self.0.iter()
@@ -135,6 +163,12 @@ impl<'a, T> Row<'a, T> {
         self.0.as_slice()
     }
 
+    /// Repeats the vector along the rows of a matrix, `A.add_assign(x.broadcast_rows())` adds `x`
+    /// to each row of `A`
+    pub fn broadcast_rows(&self) -> Broadcast<Row<'a, T>> {
+        Broadcast(*self)
+    }
+
     /// Returns an "immutable iterator" over the row
     pub fn iter(&self) -> strided::Iter<'a, T> {
         self.0.iter()
@@ -156,6 +190,12 @@ impl<'a, T> RowMut<'a, T> {
         (self.0).0.as_slice_mut()
     }
 
+    /// Repeats the vector along the rows of a matrix, `A.add_assign(x.broadcast_rows())` adds `x`
+    /// to each row of `A`
+    pub fn broadcast_rows(&self) -> Broadcast<Row<T>> {
+        Broadcast(self.0)
+    }
+
     /// Returns an "immutable iterator" over the row
     pub fn iter(&self) -> strided::Iter<T> {
         self.0.iter()
@@ -186,6 +226,12 @@ impl<T> RowVec<T> {
         self.0.as_slice_mut()
     }
 
+    /// Repeats the vector along the rows of a matrix, `A.add_assign(x.broadcast_rows())` adds `x`
+    /// to each row of `A`
+    pub fn broadcast_rows(&self) -> Broadcast<Row<T>> {
+        Broadcast(self.slice(..))
+    }
+
     /// Creates a row vector of size `length` filled with ones
     ///
     /// # Panics
@@ -424,6 +470,58 @@ impl<T> Matrix for RowVec<T> {
     }
 }
 
+// NB A column vector is a matrix with a single column, a row vector is a matrix whose columns
+// have a single element
+impl<'a, T> MatrixCol for Col<'a, T> {
+    fn col(&self, i: u32) -> Col<T> {
+        assert_eq!(i, 0);
+
+        *self
+    }
+}
+
+impl<'a, T> MatrixCol for ColMut<'a, T> {
+    fn col(&self, i: u32) -> Col<T> {
+        assert_eq!(i, 0);
+
+        self.slice(..)
+    }
+}
+
+impl<'a, T> MatrixColMut for ColMut<'a, T> {}
+
+impl<T> MatrixCol for ColVec<T> {
+    fn col(&self, i: u32) -> Col<T> {
+        assert_eq!(i, 0);
+
+        self.slice(..)
+    }
+}
+
+impl<T> MatrixColMut for ColVec<T> {}
+
+impl<'a, T> MatrixCol for Row<'a, T> {
+    fn col(&self, i: u32) -> Col<T> {
+        Col(self.0.slice(i..i + 1))
+    }
+}
+
+impl<'a, T> MatrixCol for RowMut<'a, T> {
+    fn col(&self, i: u32) -> Col<T> {
+        Col((self.0).0.slice(i..i + 1))
+    }
+}
+
+impl<'a, T> MatrixColMut for RowMut<'a, T> {}
+
+impl<T> MatrixCol for RowVec<T> {
+    fn col(&self, i: u32) -> Col<T> {
+        Col(self.slice(..).0.slice(i..i + 1))
+    }
+}
+
+impl<T> MatrixColMut for RowVec<T> {}
+
 macro_rules! slice {
     ($ty_owned:ident, $ty:ident, $ty_mut:ident) => {
         impl<'a, T> Slice<'a, RangeFull> for $ty_owned<T> {
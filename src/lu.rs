@@ -0,0 +1,142 @@
+use std::ops::{Mul, Neg};
+use std::{f32, f64};
+
+use complex::Complex;
+use lapack::{Gecon, Getrf, Lange};
+use onezero::{One, Zero};
+
+use ops;
+use traits::{Matrix, MatrixDiag, Slice, SliceMut};
+use {Lu, Mat};
+
+impl<T> Mat<T> {
+    /// Computes the LU factorization of a square matrix
+    ///
+    /// NOTE The matrix is taken by value, its buffer will be re-used to store the factors
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    pub fn lu(mut self) -> Lu<T> where T: Getrf + Lange {
+        unsafe {
+            assert_eq!(self.nrows(), self.ncols());
+
+            let anorm = ops::lange(&b'1', self.slice(..));
+            let (ipiv, info) = ops::getrf(self.slice_mut(..));
+
+            assert!(info >= 0);
+
+            Lu {
+                anorm: anorm,
+                info: info,
+                ipiv: ipiv,
+                lu: self,
+            }
+        }
+    }
+}
+
+impl<T> Lu<T> where T: Lange {
+    /// Returns the determinant of the factorized matrix
+    pub fn det(&self) -> T where T: Copy + Mul<Output=T> + Neg<Output=T> + One {
+        let mut det = T::one();
+
+        // NB `ipiv` uses 1-based indexing
+        for (i, (&p, &u)) in (1..).zip(self.ipiv.iter().zip(self.lu.diag(0).iter())) {
+            det = if p == i { det * u } else { -(det * u) };
+        }
+
+        det
+    }
+
+    /// Returns `true` if the factorized matrix is singular
+    ///
+    /// NOTE A singular factorization can be used to compute the determinant, but it can't be used
+    /// to solve linear systems or to compute the inverse
+    pub fn is_singular(&self) -> bool {
+        self.info > 0
+    }
+
+    /// Returns the size of the factorized (square) matrix
+    pub fn size(&self) -> (u32, u32) {
+        self.lu.size()
+    }
+
+    /// Returns an estimate of the reciprocal condition number (in the 1-norm) of the factorized
+    /// matrix
+    pub fn rcond(&self) -> T::Real where T: Gecon, T::Real: Zero {
+        unsafe {
+            if self.is_singular() {
+                return Zero::zero()
+            }
+
+            ops::gecon(&b'1', self.lu.slice(..), &self.anorm)
+        }
+    }
+}
+
+macro_rules! slogdet {
+    ($($t:ident),+) => {
+        $(
+            impl Lu<$t> {
+                /// Returns the sign and the natural logarithm of the absolute value of the
+                /// determinant of the factorized matrix
+                ///
+                /// NOTE This is more robust than `det()` against overflow and underflow. If the
+                /// matrix is singular, `(0, -inf)` is returned
+                pub fn slogdet(&self) -> ($t, $t) {
+                    if self.is_singular() {
+                        return (0., $t::NEG_INFINITY)
+                    }
+
+                    let mut sign = 1.;
+                    let mut logdet = 0.;
+
+                    // NB `ipiv` uses 1-based indexing
+                    for (i, (&p, &u)) in (1..).zip(self.ipiv.iter().zip(self.lu.diag(0).iter())) {
+                        if (p == i) != (u > 0.) {
+                            sign = -sign;
+                        }
+
+                        logdet += u.abs().ln();
+                    }
+
+                    (sign, logdet)
+                }
+            }
+
+            impl Lu<Complex<$t>> {
+                /// Returns the sign (a complex number with modulus one) and the natural logarithm
+                /// of the absolute value of the determinant of the factorized matrix
+                ///
+                /// NOTE This is more robust than `det()` against overflow and underflow. If the
+                /// matrix is singular, `(0, -inf)` is returned
+                pub fn slogdet(&self) -> (Complex<$t>, $t) {
+                    if self.is_singular() {
+                        return (Complex { re: 0., im: 0. }, $t::NEG_INFINITY)
+                    }
+
+                    let mut sign = Complex { re: 1., im: 0. };
+                    let mut logdet = 0.;
+
+                    // NB `ipiv` uses 1-based indexing
+                    for (i, (&p, &u)) in (1..).zip(self.ipiv.iter().zip(self.lu.diag(0).iter())) {
+                        let abs = u.re.hypot(u.im);
+
+                        sign = sign * Complex { re: u.re / abs, im: u.im / abs };
+
+                        if p != i {
+                            sign = Complex { re: -sign.re, im: -sign.im };
+                        }
+
+                        logdet += abs.ln();
+                    }
+
+                    (sign, logdet)
+                }
+            }
+         )+
+    }
+}
+
+slogdet!(f32, f64);
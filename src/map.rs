@@ -0,0 +1,160 @@
+//! Element-wise operations
+
+use cast::From as _0;
+
+use traits::{Fold, Map, MapInplace, Matrix, MatrixCol, MatrixColMut};
+use {Col, ColMut, ColVec, Mat, Row, RowMut, RowVec, SubMat, SubMatMut, Transposed};
+
+/// Owned output of the element-wise operations
+trait FromVec<T> {
+    /// Creates a matrix with dimensions `size` from its elements in column major order
+    fn from_vec(size: (u32, u32), elems: Vec<T>) -> Self;
+}
+
+impl<T> FromVec<T> for ColVec<T> {
+    fn from_vec(_: (u32, u32), elems: Vec<T>) -> ColVec<T> {
+        ColVec::from(elems)
+    }
+}
+
+impl<T> FromVec<T> for Mat<T> {
+    fn from_vec(size: (u32, u32), elems: Vec<T>) -> Mat<T> {
+        Mat::from_vec(size, elems)
+    }
+}
+
+impl<T> FromVec<T> for RowVec<T> {
+    fn from_vec(_: (u32, u32), elems: Vec<T>) -> RowVec<T> {
+        RowVec::from(elems)
+    }
+}
+
+impl<M> Fold for M where M: MatrixCol {
+    fn fold<B, F>(&self, init: B, mut f: F) -> B where F: FnMut(B, &M::Elem) -> B {
+        let mut acc = init;
+
+        for j in 0..self.ncols() {
+            for a in self.col(j).iter() {
+                acc = f(acc, a);
+            }
+        }
+
+        acc
+    }
+}
+
+impl<M> MapInplace for M where M: MatrixColMut {
+    fn map_inplace<F>(&mut self, mut f: F) where F: FnMut(&mut M::Elem) {
+        for j in 0..self.ncols() {
+            for a in self.col_mut(j).iter_mut() {
+                f(a)
+            }
+        }
+    }
+}
+
+/// Returns the number of elements of `a`
+fn len<A>(a: &A) -> usize where A: Matrix {
+    usize::from_(a.nrows()) * usize::from_(a.ncols())
+}
+
+/// Returns `[f(&A[0, 0]), f(&A[1, 0]), ..]`
+fn map<A, F, U>(a: &A, mut f: F) -> Vec<U> where A: MatrixCol, F: FnMut(&A::Elem) -> U {
+    let mut v = Vec::with_capacity(len(a));
+
+    for j in 0..a.ncols() {
+        for a in a.col(j).iter() {
+            v.push(f(a))
+        }
+    }
+
+    v
+}
+
+/// Returns `[f(&A[0, 0], &B[0, 0]), f(&A[1, 0], &B[1, 0]), ..]`
+fn zip<A, B, F, U>(a: &A, b: &B, mut f: F) -> Vec<U> where
+    A: MatrixCol,
+    B: MatrixCol,
+    F: FnMut(&A::Elem, &B::Elem) -> U,
+{
+    assert_eq!(a.size(), b.size());
+
+    let mut v = Vec::with_capacity(len(a));
+
+    for j in 0..a.ncols() {
+        let (a, b) = (a.col(j), b.col(j));
+
+        for (a, b) in a.iter().zip(b.iter()) {
+            v.push(f(a, b))
+        }
+    }
+
+    v
+}
+
+/// Returns `[f(&A[0, 0], &B[0, 0], &C[0, 0]), f(&A[1, 0], &B[1, 0], &C[1, 0]), ..]`
+fn zip3<A, B, C, F, U>(a: &A, b: &B, c: &C, mut f: F) -> Vec<U> where
+    A: MatrixCol,
+    B: MatrixCol,
+    C: MatrixCol,
+    F: FnMut(&A::Elem, &B::Elem, &C::Elem) -> U,
+{
+    assert_eq!(a.size(), b.size());
+    assert_eq!(a.size(), c.size());
+
+    let mut v = Vec::with_capacity(len(a));
+
+    for j in 0..a.ncols() {
+        let (a, b, c) = (a.col(j), b.col(j), c.col(j));
+
+        for ((a, b), c) in a.iter().zip(b.iter()).zip(c.iter()) {
+            v.push(f(a, b, c))
+        }
+    }
+
+    v
+}
+
+macro_rules! map {
+    ($($ty:ty => $output:ident),+,) => {
+        $(
+            impl<'a, T, U> Map<U> for $ty {
+                type Output = $output<U>;
+
+                fn map<F>(&self, f: F) -> $output<U> where F: FnMut(&T) -> U {
+                    FromVec::from_vec(self.size(), map(self, f))
+                }
+
+                fn zip_with<B, F>(&self, b: &B, f: F) -> $output<U> where
+                    B: MatrixCol,
+                    F: FnMut(&T, &B::Elem) -> U,
+                {
+                    FromVec::from_vec(self.size(), zip(self, b, f))
+                }
+
+                fn zip3_with<B, C, F>(&self, b: &B, c: &C, f: F) -> $output<U> where
+                    B: MatrixCol,
+                    C: MatrixCol,
+                    F: FnMut(&T, &B::Elem, &C::Elem) -> U,
+                {
+                    FromVec::from_vec(self.size(), zip3(self, b, c, f))
+                }
+            }
+         )+
+    }
+}
+
+map! {
+    Col<'a, T> => ColVec,
+    ColMut<'a, T> => ColVec,
+    ColVec<T> => ColVec,
+    Mat<T> => Mat,
+    Row<'a, T> => RowVec,
+    RowMut<'a, T> => RowVec,
+    RowVec<T> => RowVec,
+    SubMat<'a, T> => Mat,
+    SubMatMut<'a, T> => Mat,
+    Transposed<Mat<T>> => Mat,
+    Transposed<SubMat<'a, T>> => Mat,
+    Transposed<SubMatMut<'a, T>> => Mat,
+}
@@ -14,8 +14,8 @@ use traits::{
     VSplit, VSplitMut,
 };
 use {
-    Col, ColMut, Cols, Diag, HStripes, Mat, Row, RowMut, Rows, Transposed, VStripes, SubMat,
-    SubMatMut,
+    Col, ColMut, ColVec, Cols, Diag, HStripes, Mat, Row, RowMut, Rows, Transposed, VStripes,
+    SubMat, SubMatMut,
 };
 
 impl<T> Mat<T> {
@@ -111,6 +111,29 @@ impl<T> Mat<T> {
         }
     }
 
+    /// Creates an owned matrix with dimensions `(nrows, ncols)` from a vector that holds its
+    /// elements in column major order
+    ///
+    /// The allocation of `elems` is re-used, no elements are copied (unless `elems` has spare
+    /// capacity, in which case the allocation is shrunk to fit first).
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows * ncols != elems.len() ||`
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31`
+    pub fn from_vec((nrows, ncols): (u32, u32), elems: Vec<T>) -> Mat<T> {
+        unsafe {
+            assert_eq!(Some(elems.len()), usize::from(nrows).checked_mul(usize::from(ncols)));
+
+            let (nrows, ncols) = (i32::from(nrows).unwrap(), i32::from(ncols).unwrap());
+
+            Mat::from_raw_parts(elems.into_boxed_slice(), (nrows, ncols))
+        }
+    }
+
     /// Creates an owned matrix with dimensions `(nrows, ncols)` filled with ones
     ///
     /// # Panics
@@ -137,6 +160,42 @@ impl<T> Mat<T> {
         Mat::from_elem((nrows, ncols), T::zero())
     }
 
+    /// Converts the matrix into a column vector that contains its columns stacked one after the
+    /// other, the allocation is re-used
+    ///
+    /// # Panics
+    ///
+    /// If `nrows * ncols > 2^31`
+    pub fn into_col(self) -> ColVec<T> {
+        ColVec::new(self.into_vec().into_boxed_slice())
+    }
+
+    /// Converts the matrix into a vector that holds its elements in column major order, the
+    /// allocation is re-used
+    pub fn into_vec(self) -> Vec<T> {
+        unsafe {
+            let len = self.len();
+            let v = Vec::from_raw_parts(*self.data, len, len);
+            mem::forget(self);
+
+            v
+        }
+    }
+
+    /// Changes the dimensions of the matrix to `(nrows, ncols)`, the elements keep their column
+    /// major order and the allocation is re-used
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows * ncols != self.nrows() * self.ncols() ||`
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31`
+    pub fn reshape(self, (nrows, ncols): (u32, u32)) -> Mat<T> {
+        Mat::from_vec((nrows, ncols), self.into_vec())
+    }
+
     fn len(&self) -> usize {
         unsafe {
             usize::from(self.nrows).extract() * usize::from(self.ncols).extract()
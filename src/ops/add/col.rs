@@ -29,6 +29,8 @@ impl<'a, 'b, T> Add<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Scaled<ColVec
     type Output = ColVec<T>;
 
     fn add(self, rhs: Scaled<Product<Chain<T>, Col<T>>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulCol::*;
 
@@ -39,7 +41,7 @@ impl<'a, 'b, T> Add<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Scaled<ColVec
             let Scaled(beta, rhs) = rhs;
             let ref beta = beta;
 
-            let a_mul_b = rhs.reduce();
+            let a_mul_b = rhs.reduce(backend);
 
             let (ref transa, a, x) = match a_mul_b {
                 M_C(ref lhs, x) => {
@@ -53,7 +55,7 @@ impl<'a, 'b, T> Add<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Scaled<ColVec
                 }
             };
 
-            ops::gemv(transa, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, transa, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -67,6 +69,8 @@ Add<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for Scaled<ColVec<T>
     type Output = ColVec<T>;
 
     fn add(self, rhs: Scaled<Product<Transposed<SubMat<T>>, Col<T>>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -75,7 +79,7 @@ Add<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for Scaled<ColVec<T>
             let Scaled(beta, mut y) = self;
             let ref beta = beta;
 
-            ops::gemv(transa, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, transa, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -88,6 +92,8 @@ impl<'a, 'b, T> Add<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for Scaled<ColVe
     type Output = ColVec<T>;
 
     fn add(self, rhs: Scaled<Product<SubMat<T>, Col<T>>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -96,7 +102,7 @@ impl<'a, 'b, T> Add<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for Scaled<ColVe
             let Scaled(beta, mut y) = self;
             let ref beta = beta;
 
-            ops::gemv(transa, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, transa, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -26,6 +26,8 @@ impl<'a, T> Add<Scaled<Chain<'a, T>>> for Scaled<Mat<T>> where T: Gemm + One + Z
     type Output = Mat<T>;
 
     fn add(self, rhs: Scaled<Chain<T>>) -> Mat<T> {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulMat::*;
 
@@ -36,7 +38,7 @@ impl<'a, T> Add<Scaled<Chain<'a, T>>> for Scaled<Mat<T>> where T: Gemm + One + Z
             let Scaled(alpha, chain) = rhs;
             let ref alpha = alpha;
 
-            let a_mul_b = chain.reduce();
+            let a_mul_b = chain.reduce(backend);
 
             let (ref transa, ref transb, a, b) = match a_mul_b {
                 M_M(ref lhs, ref rhs) => {
@@ -53,7 +55,7 @@ impl<'a, T> Add<Scaled<Chain<'a, T>>> for Scaled<Mat<T>> where T: Gemm + One + Z
                 }
             };
 
-            ops::gemm(transa, transb, alpha, a, b, beta, c.slice_mut(..));
+            ops::gemm(backend, transa, transb, alpha, a, b, beta, c.slice_mut(..));
 
             c
         }
@@ -5,27 +5,29 @@ use onezero::{One, Zero};
 use Forward;
 use ops::Reduce;
 use ops;
-use traits::{Matrix, Slice, SliceMut};
-use {Chain, Col, ColMut, ColVec, Product, Scaled, Transposed, SubMat};
+use traits::{Matrix, Scalar, Slice, SliceMut};
+use {Chain, Col, ColMut, ColVec, Product, Scaled, SparseMat, Transposed, SubMat};
 
 // Combinations:
 //
 // LHS: ColMut, ColVec
 // RHS: &T, T, Col, &ColMut, &ColVec, Product<Chain, Col>, Product<Transposed<SubMat>, Col>,
-//      Product<SubMat, Col>, Scaled<Col>, Scaled<Product<Chain, Col>>,
-//      Scaled<Product<Transposed<SubMat>, Col>>, Scaled<Product<SubMat, Col>>
+//      Product<&SparseMat, Col>, Product<SubMat, Col>, Scaled<Col>, Scaled<Product<Chain, Col>>,
+//      Scaled<Product<&SparseMat, Col>>, Scaled<Product<Transposed<SubMat>, Col>>,
+//      Scaled<Product<SubMat, Col>>
 //
-// -> 24 implementations
+// -> 28 implementations
 
-// 5 impls
+// 6 impls
 // Core implementations
 impl<'a, 'b, T> AddAssign<&'a T> for ColMut<'b, T> where T: Axpy + One {
     fn add_assign(&mut self, rhs: &T) {
+        let ref backend = T::backend();
         let ColMut(Col(ref mut y)) = *self;
         let x = rhs;
         let ref alpha = T::one();
 
-        ops::axpy_strided_scalar(alpha, x, y)
+        ops::axpy_strided_scalar(backend, alpha, x, y)
     }
 }
 
@@ -33,6 +35,8 @@ impl<'a, 'b, 'c, T> AddAssign<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Col
     T: Gemm + Gemv + One + Zero,
 {
     fn add_assign(&mut self, rhs: Scaled<Product<Chain<T>, Col<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulCol::*;
 
@@ -41,7 +45,7 @@ impl<'a, 'b, 'c, T> AddAssign<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Col
             let Scaled(alpha, rhs) = rhs;
             let ref alpha = alpha;
 
-            let a_mul_b = rhs.reduce();
+            let a_mul_b = rhs.reduce(backend);
 
             let (ref transa, a, x) = match a_mul_b {
                 M_C(ref lhs, x) => {
@@ -58,7 +62,7 @@ impl<'a, 'b, 'c, T> AddAssign<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Col
             let y = self.slice_mut(..);
             let ref beta = T::one();
 
-            ops::gemv(transa, alpha, a, beta, x, y)
+            ops::gemv(backend, transa, alpha, a, beta, x, y)
         }
     }
 }
@@ -68,6 +72,8 @@ AddAssign<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for ColMut<'c,
     T: Gemv + One,
 {
     fn add_assign(&mut self, rhs: Scaled<Product<Transposed<SubMat<T>>, Col<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -76,15 +82,30 @@ AddAssign<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for ColMut<'c,
             let ref beta = T::one();
             let y = ColMut(self.0);
 
-            ops::gemv(trans, alpha, a, beta, x, y);
+            ops::gemv(backend, trans, alpha, a, beta, x, y);
         }
     }
 }
 
+impl<'a, 'b, 'c, T>
+AddAssign<Scaled<Product<&'a SparseMat<T>, Col<'b, T>>>> for ColMut<'c, T> where
+    T: Scalar,
+{
+    fn add_assign(&mut self, rhs: Scaled<Product<&SparseMat<T>, Col<T>>>) {
+        assert_eq_size!(self, rhs);
+
+        let Scaled(ref alpha, Product(a, x)) = rhs;
+
+        ops::spmv(alpha, a, &T::one(), x, self.slice_mut(..))
+    }
+}
+
 impl<'a, 'b, 'c, T> AddAssign<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for ColMut<'c, T> where
     T: Gemv + One,
 {
     fn add_assign(&mut self, rhs: Scaled<Product<SubMat<T>, Col<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -93,22 +114,24 @@ impl<'a, 'b, 'c, T> AddAssign<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for Co
             let ref beta = T::one();
             let y = ColMut(self.0);
 
-            ops::gemv(trans, alpha, a, beta, x, y);
+            ops::gemv(backend, trans, alpha, a, beta, x, y);
         }
     }
 }
 
 impl<'a, 'b, T> AddAssign<Scaled<Col<'a, T>>> for ColMut<'b, T> where T: Axpy {
     fn add_assign(&mut self, rhs: Scaled<Col<T>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
-            ops::axpy_strided_strided(&rhs.0, &(rhs.1).0, &mut (self.0).0)
+            ops::axpy_strided_strided(backend, &rhs.0, &(rhs.1).0, &mut (self.0).0)
         }
     }
 }
 
-// 4 impls
+// 5 impls
 // Secondary implementations
 impl<'a, 'b, T> AddAssign<Col<'a, T>> for ColMut<'b, T> where T: Axpy + One {
     fn add_assign(&mut self, rhs: Col<T>) {
@@ -133,6 +156,14 @@ AddAssign<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>> for ColMut<'c, T> wher
     }
 }
 
+impl<'a, 'b, 'c, T> AddAssign<Product<&'a SparseMat<T>, Col<'b, T>>> for ColMut<'c, T> where
+    T: Scalar,
+{
+    fn add_assign(&mut self, rhs: Product<&SparseMat<T>, Col<T>>) {
+        self.add_assign(Scaled(T::one(), rhs))
+    }
+}
+
 impl<'a, 'b, 'c, T> AddAssign<Product<SubMat<'a, T>, Col<'b, T>>> for ColMut<'c, T> where
     T: Gemv + One,
 {
@@ -165,16 +196,18 @@ impl<'a, T> AddAssign<T> for ColMut<'a, T> where T: Axpy + One {
     }
 }
 
-// 12 impls
+// 14 impls
 forward!(ColVec<T> {
     Col<'a, T> { Axpy, One },
     &'a ColMut<'b, T> { Axpy, One },
     &'a ColVec<T> { Axpy, One },
     Product<Chain<'a, T>, Col<'b, T>> { Gemm, Gemv, One, Zero },
+    Product<&'a SparseMat<T>, Col<'b, T>> { Scalar },
     Product<Transposed<SubMat<'a, T>>, Col<'b, T>> { Gemv, One },
     Product<SubMat<'a, T>, Col<'b, T>> { Gemv, One },
     Scaled<Col<'a, T>> { Axpy },
     Scaled<Product<Chain<'a, T>, Col<'b, T>>> { Gemm, Gemv, One, Zero },
+    Scaled<Product<&'a SparseMat<T>, Col<'b, T>>> { Scalar },
     Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>> { Gemv, One },
     Scaled<Product<SubMat<'a, T>, Col<'b, T>>> { Gemv, One },
 });
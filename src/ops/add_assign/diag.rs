@@ -15,11 +15,12 @@ use {DiagMut, Diag};
 // Core implementations
 impl<'a, 'b, T> AddAssign<&'a T> for DiagMut<'b, T> where T: Axpy + One {
     fn add_assign(&mut self, rhs: &T) {
+        let ref backend = T::backend();
         let DiagMut(Diag(ref mut y)) = *self;
         let x = rhs;
         let ref alpha = T::one();
 
-        ops::axpy_strided_scalar(alpha, x, y)
+        ops::axpy_strided_scalar(backend, alpha, x, y)
     }
 }
 
@@ -1,14 +1,17 @@
 use assign::AddAssign;
-use blas::{Axpy, Gemm, Transpose};
+use blas::{Axpy, Gemm, Ger, Transpose};
 use onezero::{One, Zero};
 
 use Forward;
 use ops::{Reduce, self};
 use traits::{
-    Matrix, MatrixCols, MatrixColsMut, MatrixRows, MatrixRowsMut, Slice, SliceMut,
+    Matrix, MatrixCols, MatrixColsMut, MatrixRows, MatrixRowsMut, Scalar, Slice, SliceMut,
 };
 use traits::Transpose as _0;
-use {Chain, ColMut, Col, Mat, RowMut, Row, Scaled, Transposed, SubMat, SubMatMut};
+use {
+    Broadcast, Chain, ColMut, Col, ColVec, Mat, Product, RowMut, Row, RowVec, Scaled, SparseMat,
+    Transposed, SubMat, SubMatMut,
+};
 
 // Combinations:
 //
@@ -17,32 +20,87 @@ use {Chain, ColMut, Col, Mat, RowMut, Row, Scaled, Transposed, SubMat, SubMatMut
 // &Transposed<Mat>, Transposed<SubMat>, &Transposed<SubMatMut>, SubMat, &SubMatMut
 //
 // -> 48 implementations
+//
+// LHS: Mat, Transposed<Mat>, Transposed<SubMatMut>, SubMatMut
+// RHS: Broadcast<Col>, Broadcast<Row>, Product<Col, Row>, Scaled<Product<Col, Row>>
+//
+// -> 16 implementations
+//
+// LHS: Mat, SubMatMut
+// RHS: Product<&SparseMat, SubMat>, Scaled<Product<&SparseMat, SubMat>>
+//
+// -> 4 implementations
 
-// 4 impls
+// 8 impls
 // Core implementations
 impl<'a, 'b, T> AddAssign<&'a T> for SubMatMut<'b, T> where T: Axpy + One {
     fn add_assign(&mut self, rhs: &T) {
+        let ref backend = T::backend();
         let ref alpha = T::one();
         let x = rhs;
 
         if let Some(y) = self.as_slice_mut() {
-            return ops::axpy_slice_scalar(alpha, rhs, y)
+            return ops::axpy_slice_scalar(backend, alpha, rhs, y)
         }
 
         if self.nrows() < self.ncols() {
             for RowMut(Row(ref mut y)) in self.rows_mut() {
-                ops::axpy_strided_scalar(alpha, x, y)
+                ops::axpy_strided_scalar(backend, alpha, x, y)
             }
         } else {
             for ColMut(Col(ref mut y)) in self.cols_mut() {
-                ops::axpy_strided_scalar(alpha, x, y)
+                ops::axpy_strided_scalar(backend, alpha, x, y)
+            }
+        }
+    }
+}
+
+impl<'a, 'b, T> AddAssign<Broadcast<Col<'a, T>>> for SubMatMut<'b, T> where T: Ger + One {
+    fn add_assign(&mut self, rhs: Broadcast<Col<T>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            let Broadcast(x) = rhs;
+
+            assert_eq!(self.nrows(), x.nrows());
+
+            // NB vectors can't be empty
+            if self.ncols() == 0 {
+                return
+            }
+
+            let ref ones = RowVec::ones(self.ncols());
+
+            ops::ger(backend, &T::one(), x, ones.slice(..), self.slice_mut(..))
+        }
+    }
+}
+
+impl<'a, 'b, T> AddAssign<Broadcast<Row<'a, T>>> for SubMatMut<'b, T> where T: Ger + One {
+    fn add_assign(&mut self, rhs: Broadcast<Row<T>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            let Broadcast(y) = rhs;
+
+            assert_eq!(self.ncols(), y.ncols());
+
+            // NB vectors can't be empty
+            if self.nrows() == 0 {
+                return
             }
+
+            let ref ones = ColVec::ones(self.nrows());
+
+            ops::ger(backend, &T::one(), ones.slice(..), y, self.slice_mut(..))
         }
     }
 }
 
 impl<'a, 'b, T> AddAssign<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where T: Gemm + One + Zero {
     fn add_assign(&mut self, rhs: Scaled<Chain<T>>) {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulMat::*;
 
@@ -53,7 +111,7 @@ impl<'a, 'b, T> AddAssign<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where T: Ge
             let ref beta = T::one();
             let c = SubMatMut(self.0);
 
-            let a_mul_b = chain.reduce();
+            let a_mul_b = chain.reduce(backend);
 
             let (ref transa, ref transb, a, b) = match a_mul_b {
                 M_M(ref lhs, ref rhs) => {
@@ -70,13 +128,45 @@ impl<'a, 'b, T> AddAssign<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where T: Ge
                 },
             };
 
-            ops::gemm(transa, transb, alpha, a, b, beta, c);
+            ops::gemm(backend, transa, transb, alpha, a, b, beta, c);
         }
     }
 }
 
+impl<'a, 'b, 'c, T>
+AddAssign<Scaled<Product<Col<'a, T>, Row<'b, T>>>> for SubMatMut<'c, T> where
+    T: Ger,
+{
+    fn add_assign(&mut self, rhs: Scaled<Product<Col<T>, Row<T>>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            assert_eq_size!(self, rhs);
+
+            let Scaled(ref alpha, Product(x, y)) = rhs;
+
+            ops::ger(backend, alpha, x, y, self.slice_mut(..))
+        }
+    }
+}
+
+impl<'a, 'b, 'c, T>
+AddAssign<Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>>> for SubMatMut<'c, T> where
+    T: Scalar,
+{
+    fn add_assign(&mut self, rhs: Scaled<Product<&SparseMat<T>, SubMat<T>>>) {
+        assert_eq_size!(self, rhs);
+
+        let Scaled(ref alpha, Product(a, b)) = rhs;
+
+        ops::spmm(alpha, a, &T::one(), b, self.slice_mut(..))
+    }
+}
+
 impl<'a, 'b, T> AddAssign<Scaled<Transposed<SubMat<'a, T>>>> for SubMatMut<'b, T> where T: Axpy {
     fn add_assign(&mut self, rhs: Scaled<Transposed<SubMat<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -84,11 +174,11 @@ impl<'a, 'b, T> AddAssign<Scaled<Transposed<SubMat<'a, T>>>> for SubMatMut<'b, T
 
             if self.nrows() < self.ncols() {
                 for (RowMut(Row(ref mut y)), Row(ref x)) in self.rows_mut().zip(rhs.rows()) {
-                    ops::axpy_strided_strided(alpha, x, y)
+                    ops::axpy_strided_strided(backend, alpha, x, y)
                 }
             } else {
                 for (ColMut(Col(ref mut y)), Col(ref x)) in self.cols_mut().zip(rhs.cols()) {
-                    ops::axpy_strided_strided(alpha, x, y)
+                    ops::axpy_strided_strided(backend, alpha, x, y)
                 }
             }
         }
@@ -97,29 +187,31 @@ impl<'a, 'b, T> AddAssign<Scaled<Transposed<SubMat<'a, T>>>> for SubMatMut<'b, T
 
 impl<'a, 'b, T> AddAssign<Scaled<SubMat<'a, T>>> for SubMatMut<'b, T> where T: Axpy {
     fn add_assign(&mut self, rhs: Scaled<SubMat<T>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
             let Scaled(ref alpha, rhs) = rhs;
 
             if let (Some(y), Some(x)) = (self.as_slice_mut(), rhs.as_slice()) {
-                return ops::axpy_slice_slice(alpha, x, y)
+                return ops::axpy_slice_slice(backend, alpha, x, y)
             }
 
             if self.nrows() < self.ncols() {
                 for (RowMut(Row(ref mut y)), Row(ref x)) in self.rows_mut().zip(rhs.rows()) {
-                    ops::axpy_strided_strided(alpha, x, y)
+                    ops::axpy_strided_strided(backend, alpha, x, y)
                 }
             } else {
                 for (ColMut(Col(ref mut y)), Col(ref x)) in self.cols_mut().zip(rhs.cols()) {
-                    ops::axpy_strided_strided(alpha, x, y)
+                    ops::axpy_strided_strided(backend, alpha, x, y)
                 }
             }
         }
     }
 }
 
-// 10 impls
+// 16 impls
 // Secondary implementations
 impl<'a, 'b, T> AddAssign<&'a T> for Transposed<SubMatMut<'b, T>> where T: Axpy + One {
     fn add_assign(&mut self, rhs: &T) {
@@ -127,6 +219,22 @@ impl<'a, 'b, T> AddAssign<&'a T> for Transposed<SubMatMut<'b, T>> where T: Axpy
     }
 }
 
+impl<'a, 'b, T> AddAssign<Broadcast<Col<'a, T>>> for Transposed<SubMatMut<'b, T>> where
+    T: Ger + One,
+{
+    fn add_assign(&mut self, rhs: Broadcast<Col<T>>) {
+        self.0.add_assign(Broadcast(Row((rhs.0).0)))
+    }
+}
+
+impl<'a, 'b, T> AddAssign<Broadcast<Row<'a, T>>> for Transposed<SubMatMut<'b, T>> where
+    T: Ger + One,
+{
+    fn add_assign(&mut self, rhs: Broadcast<Row<T>>) {
+        self.0.add_assign(Broadcast(Col((rhs.0).0)))
+    }
+}
+
 impl<'a, 'b, T> AddAssign<Chain<'a, T>> for Transposed<SubMatMut<'b, T>> where
     T: Gemm + One + Zero,
 {
@@ -135,6 +243,15 @@ impl<'a, 'b, T> AddAssign<Chain<'a, T>> for Transposed<SubMatMut<'b, T>> where
     }
 }
 
+impl<'a, 'b, 'c, T>
+AddAssign<Product<Col<'a, T>, Row<'b, T>>> for Transposed<SubMatMut<'c, T>> where
+    T: Ger + One,
+{
+    fn add_assign(&mut self, rhs: Product<Col<T>, Row<T>>) {
+        self.add_assign(Scaled(T::one(), rhs))
+    }
+}
+
 impl<'a, 'b, T> AddAssign<Scaled<Chain<'a, T>>> for Transposed<SubMatMut<'b, T>> where
     T: Gemm + One + Zero,
 {
@@ -143,6 +260,15 @@ impl<'a, 'b, T> AddAssign<Scaled<Chain<'a, T>>> for Transposed<SubMatMut<'b, T>>
     }
 }
 
+impl<'a, 'b, 'c, T>
+AddAssign<Scaled<Product<Col<'a, T>, Row<'b, T>>>> for Transposed<SubMatMut<'c, T>> where
+    T: Ger,
+{
+    fn add_assign(&mut self, rhs: Scaled<Product<Col<T>, Row<T>>>) {
+        self.0.add_assign(rhs.t())
+    }
+}
+
 impl<'a, 'b, T> AddAssign<Scaled<Transposed<SubMat<'a, T>>>> for Transposed<SubMatMut<'b, T>> where
     T: Axpy,
 {
@@ -173,6 +299,22 @@ impl<'a, 'b, T> AddAssign<SubMat<'a, T>> for Transposed<SubMatMut<'b, T>> where
     }
 }
 
+impl<'a, 'b, 'c, T> AddAssign<Product<Col<'a, T>, Row<'b, T>>> for SubMatMut<'c, T> where
+    T: Ger + One,
+{
+    fn add_assign(&mut self, rhs: Product<Col<T>, Row<T>>) {
+        self.add_assign(Scaled(T::one(), rhs))
+    }
+}
+
+impl<'a, 'b, 'c, T> AddAssign<Product<&'a SparseMat<T>, SubMat<'b, T>>> for SubMatMut<'c, T> where
+    T: Scalar,
+{
+    fn add_assign(&mut self, rhs: Product<&SparseMat<T>, SubMat<T>>) {
+        self.add_assign(Scaled(T::one(), rhs))
+    }
+}
+
 impl<'a, 'b, T> AddAssign<Chain<'a, T>> for SubMatMut<'b, T> where T: Gemm + One + Zero {
     fn add_assign(&mut self, rhs: Chain<T>) {
         self.add_assign(Scaled(T::one(), rhs))
@@ -203,11 +345,17 @@ macro_rules! forward {
     };
 }
 
-// 12 impls
+// 18 impls
 forward!(Mat<T> {
+    Broadcast<Col<'a, T>> { Ger, One },
+    Broadcast<Row<'a, T>> { Ger, One },
     Chain<'a, T> { Gemm, One, Zero },
     &'a Mat<T> { Axpy, One },
+    Product<Col<'a, T>, Row<'b, T>> { Ger, One },
+    Product<&'a SparseMat<T>, SubMat<'b, T>> { Scalar },
     Scaled<Chain<'a, T>> { Gemm, One, Zero },
+    Scaled<Product<Col<'a, T>, Row<'b, T>>> { Ger },
+    Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>> { Scalar },
     Scaled<Transposed<SubMat<'a, T>>> { Axpy },
     Scaled<SubMat<'a, T>> { Axpy },
     &'a Transposed<Mat<T>> { Axpy, One },
@@ -229,11 +377,15 @@ impl<T> AddAssign<T> for Mat<T> where T: Axpy + One {
     }
 }
 
-// 12 impls
+// 16 impls
 forward!(Transposed<Mat<T>> {
+    Broadcast<Col<'a, T>> { Ger, One },
+    Broadcast<Row<'a, T>> { Ger, One },
     Chain<'a, T> { Gemm, One, Zero },
     &'a Mat<T> { Axpy, One },
+    Product<Col<'a, T>, Row<'b, T>> { Ger, One },
     Scaled<Chain<'a, T>> { Gemm, One, Zero },
+    Scaled<Product<Col<'a, T>, Row<'b, T>>> { Ger },
     Scaled<Transposed<SubMat<'a, T>>> { Axpy },
     Scaled<SubMat<'a, T>> { Axpy },
     &'a Transposed<Mat<T>> { Axpy, One },
@@ -0,0 +1,272 @@
+use blas::Transpose;
+use cast::From;
+use extract::Extract;
+use onezero::{One, Zero};
+
+use ops;
+use traits::{AxisReduce, Field, Matrix, MatrixCols, Scalar, Slice, SliceMut};
+use {ColVec, Mat, RowVec, SubMat, SubMatMut, Tor, Transposed};
+
+// Combinations:
+//
+// Mat, SubMat, SubMatMut, Transposed<M>
+//
+// -> 4 implementations
+
+/// Returns `op(A) * ones`, i.e. the sums of the rows (`trans == No`) or of the columns of `A`
+fn sum<T>(trans: &Transpose, a: SubMat<T>) -> ColVec<T> where T: Scalar {
+    let ref backend = T::backend();
+
+    unsafe {
+        let (m, n) = match *trans {
+            Transpose::No => (a.nrows(), a.ncols()),
+            Transpose::Yes | Transpose::Conj => (a.ncols(), a.nrows()),
+        };
+
+        // NB BLAS rejects the leading dimension of empty matrices, and `gemv` rejects an empty `x`
+        if m == 0 || n == 0 {
+            return (0..m).map(|_| T::zero()).collect()
+        }
+
+        let ref ones = ColVec::ones(n);
+        let mut y = ColVec(Tor::uninitialized(i32::from(m).extract()));
+
+        ops::gemv(backend, trans, &T::one(), a, &T::zero(), ones.slice(..), y.slice_mut(..));
+
+        y
+    }
+}
+
+/// Returns the means of the rows (`trans == No`) or of the columns of `A`
+fn mean<T>(trans: &Transpose, a: SubMat<T>) -> ColVec<T> where T: Field {
+    let n = match *trans {
+        Transpose::No => a.ncols(),
+        Transpose::Yes | Transpose::Conj => a.nrows(),
+    };
+    let n = (0..n).fold(T::zero(), |n, _| n + T::one());
+
+    let mut y = sum(trans, a);
+
+    for y in y.iter_mut() {
+        *y = y.quotient(n);
+    }
+
+    y
+}
+
+/// Returns the position and the value of the "best" element of each column of `A`
+fn best_cols<T, F>(a: SubMat<T>, better: F) -> Vec<(u32, T)> where
+    T: Copy + PartialOrd,
+    F: Fn(T, T) -> bool,
+{
+    assert!(a.nrows() != 0);
+
+    a.cols().map(|col| {
+        let mut elems = (0..).zip(col.iter());
+        let (mut pos, &first) = elems.next().unwrap();
+        let mut best = first;
+
+        for (i, &e) in elems {
+            if better(e, best) {
+                pos = i;
+                best = e;
+            }
+        }
+
+        (pos, best)
+    }).collect()
+}
+
+/// Returns the position and the value of the "best" element of each row of `A`
+fn best_rows<T, F>(a: SubMat<T>, better: F) -> Vec<(u32, T)> where
+    T: Copy + PartialOrd,
+    F: Fn(T, T) -> bool,
+{
+    assert!(a.ncols() != 0);
+
+    // NB the matrix is walked column by column, which is cache friendly
+    let mut cols = (0..).zip(a.cols());
+    let (_, first) = cols.next().unwrap();
+    let mut best = first.iter().map(|&e| (0, e)).collect::<Vec<_>>();
+
+    for (j, col) in cols {
+        for (best, &e) in best.iter_mut().zip(col.iter()) {
+            if better(e, best.1) {
+                *best = (j, e);
+            }
+        }
+    }
+
+    best
+}
+
+/// NB A NaN `best` is replaced by any element, and a NaN `e` never replaces `best`
+fn greater<T>(e: T, best: T) -> bool where T: PartialOrd {
+    e > best || best != best
+}
+
+/// NB See `greater`
+fn less<T>(e: T, best: T) -> bool where T: PartialOrd {
+    e < best || best != best
+}
+
+// NOTE Core
+impl<'a, T> AxisReduce for SubMat<'a, T> {
+    fn argmax_cols(&self) -> RowVec<u32> where T: Copy + PartialOrd {
+        best_cols(*self, greater).into_iter().map(|(i, _)| i).collect()
+    }
+
+    fn argmax_rows(&self) -> ColVec<u32> where T: Copy + PartialOrd {
+        best_rows(*self, greater).into_iter().map(|(i, _)| i).collect()
+    }
+
+    fn argmin_cols(&self) -> RowVec<u32> where T: Copy + PartialOrd {
+        best_cols(*self, less).into_iter().map(|(i, _)| i).collect()
+    }
+
+    fn argmin_rows(&self) -> ColVec<u32> where T: Copy + PartialOrd {
+        best_rows(*self, less).into_iter().map(|(i, _)| i).collect()
+    }
+
+    fn max_cols(&self) -> RowVec<T> where T: Copy + PartialOrd {
+        best_cols(*self, greater).into_iter().map(|(_, e)| e).collect()
+    }
+
+    fn max_rows(&self) -> ColVec<T> where T: Copy + PartialOrd {
+        best_rows(*self, greater).into_iter().map(|(_, e)| e).collect()
+    }
+
+    fn mean_cols(&self) -> RowVec<T> where T: Field {
+        RowVec(mean(&Transpose::Yes, *self).0)
+    }
+
+    fn mean_rows(&self) -> ColVec<T> where T: Field {
+        mean(&Transpose::No, *self)
+    }
+
+    fn min_cols(&self) -> RowVec<T> where T: Copy + PartialOrd {
+        best_cols(*self, less).into_iter().map(|(_, e)| e).collect()
+    }
+
+    fn min_rows(&self) -> ColVec<T> where T: Copy + PartialOrd {
+        best_rows(*self, less).into_iter().map(|(_, e)| e).collect()
+    }
+
+    fn sum_cols(&self) -> RowVec<T> where T: Scalar {
+        RowVec(sum(&Transpose::Yes, *self).0)
+    }
+
+    fn sum_rows(&self) -> ColVec<T> where T: Scalar {
+        sum(&Transpose::No, *self)
+    }
+}
+
+// NOTE Secondary
+impl<M> AxisReduce for Transposed<M> where M: AxisReduce {
+    fn argmax_cols(&self) -> RowVec<u32> where M::Elem: Copy + PartialOrd {
+        RowVec(self.0.argmax_rows().0)
+    }
+
+    fn argmax_rows(&self) -> ColVec<u32> where M::Elem: Copy + PartialOrd {
+        ColVec(self.0.argmax_cols().0)
+    }
+
+    fn argmin_cols(&self) -> RowVec<u32> where M::Elem: Copy + PartialOrd {
+        RowVec(self.0.argmin_rows().0)
+    }
+
+    fn argmin_rows(&self) -> ColVec<u32> where M::Elem: Copy + PartialOrd {
+        ColVec(self.0.argmin_cols().0)
+    }
+
+    fn max_cols(&self) -> RowVec<M::Elem> where M::Elem: Copy + PartialOrd {
+        RowVec(self.0.max_rows().0)
+    }
+
+    fn max_rows(&self) -> ColVec<M::Elem> where M::Elem: Copy + PartialOrd {
+        ColVec(self.0.max_cols().0)
+    }
+
+    fn mean_cols(&self) -> RowVec<M::Elem> where M::Elem: Field {
+        RowVec(self.0.mean_rows().0)
+    }
+
+    fn mean_rows(&self) -> ColVec<M::Elem> where M::Elem: Field {
+        ColVec(self.0.mean_cols().0)
+    }
+
+    fn min_cols(&self) -> RowVec<M::Elem> where M::Elem: Copy + PartialOrd {
+        RowVec(self.0.min_rows().0)
+    }
+
+    fn min_rows(&self) -> ColVec<M::Elem> where M::Elem: Copy + PartialOrd {
+        ColVec(self.0.min_cols().0)
+    }
+
+    fn sum_cols(&self) -> RowVec<M::Elem> where M::Elem: Scalar {
+        RowVec(self.0.sum_rows().0)
+    }
+
+    fn sum_rows(&self) -> ColVec<M::Elem> where M::Elem: Scalar {
+        ColVec(self.0.sum_cols().0)
+    }
+}
+
+macro_rules! forward {
+    ($($ty:ty),+) => {
+        $(
+            // NOTE Forward
+            impl<'a, T> AxisReduce for $ty {
+                fn argmax_cols(&self) -> RowVec<u32> where T: Copy + PartialOrd {
+                    self.slice(..).argmax_cols()
+                }
+
+                fn argmax_rows(&self) -> ColVec<u32> where T: Copy + PartialOrd {
+                    self.slice(..).argmax_rows()
+                }
+
+                fn argmin_cols(&self) -> RowVec<u32> where T: Copy + PartialOrd {
+                    self.slice(..).argmin_cols()
+                }
+
+                fn argmin_rows(&self) -> ColVec<u32> where T: Copy + PartialOrd {
+                    self.slice(..).argmin_rows()
+                }
+
+                fn max_cols(&self) -> RowVec<T> where T: Copy + PartialOrd {
+                    self.slice(..).max_cols()
+                }
+
+                fn max_rows(&self) -> ColVec<T> where T: Copy + PartialOrd {
+                    self.slice(..).max_rows()
+                }
+
+                fn mean_cols(&self) -> RowVec<T> where T: Field {
+                    self.slice(..).mean_cols()
+                }
+
+                fn mean_rows(&self) -> ColVec<T> where T: Field {
+                    self.slice(..).mean_rows()
+                }
+
+                fn min_cols(&self) -> RowVec<T> where T: Copy + PartialOrd {
+                    self.slice(..).min_cols()
+                }
+
+                fn min_rows(&self) -> ColVec<T> where T: Copy + PartialOrd {
+                    self.slice(..).min_rows()
+                }
+
+                fn sum_cols(&self) -> RowVec<T> where T: Scalar {
+                    self.slice(..).sum_cols()
+                }
+
+                fn sum_rows(&self) -> ColVec<T> where T: Scalar {
+                    self.slice(..).sum_rows()
+                }
+            }
+         )+
+    }
+}
+
+forward!(Mat<T>, SubMatMut<'a, T>);
@@ -0,0 +1,155 @@
+use std::ops::Mul;
+
+use assign::AddAssign;
+use blas::Transpose;
+
+use traits::{Eval, Matrix, Set, TryAddAssign, TryEval, TryMul, TrySet};
+use {
+    BandMat, Chain, Col, ColMut, ColVec, ConjChain, ConjTransposed, Error, LowerTri, Mat, Product,
+    Row, RowMut, RowVec, Scaled, SparseMat, Symmetric, Transposed, SubMat, SubMatMut, UpperTri,
+};
+
+/// Validates the dimensions of an operand
+pub trait Check {
+    /// Returns the size of the operand, or an error if the operand is a lazy operation whose
+    /// operands have dimensions that don't agree
+    fn check(&self) -> Result<(u32, u32), Error>;
+}
+
+/// Returns the size of `A * B`, or an error if the inner dimensions don't agree
+fn product(lhs: (u32, u32), rhs: (u32, u32)) -> Result<(u32, u32), Error> {
+    if lhs.1 == rhs.0 && lhs.1 != 0 {
+        Ok((lhs.0, rhs.1))
+    } else {
+        Err(Error::DimensionMismatch { lhs: lhs, rhs: rhs })
+    }
+}
+
+/// Returns an error if the sizes of the operands are not the same
+fn same_size(lhs: (u32, u32), rhs: (u32, u32)) -> Result<(), Error> {
+    if lhs == rhs {
+        Ok(())
+    } else {
+        Err(Error::DimensionMismatch { lhs: lhs, rhs: rhs })
+    }
+}
+
+impl<'a, T> Check for Chain<'a, T> {
+    fn check(&self) -> Result<(u32, u32), Error> {
+        fn size<T>(&(trans, a): &(Transpose, SubMat<T>)) -> (u32, u32) {
+            match trans {
+                Transpose::No => (a.nrows(), a.ncols()),
+                Transpose::Yes | Transpose::Conj => (a.ncols(), a.nrows()),
+            }
+        }
+
+        let mut size_ = size(&self.first);
+
+        for factor in Some(&self.second).into_iter().chain(self.tail.iter()) {
+            size_ = try!(product(size_, size(factor)));
+        }
+
+        Ok(size_)
+    }
+}
+
+impl<'a, T> Check for ConjChain<'a, T> {
+    fn check(&self) -> Result<(u32, u32), Error> {
+        self.0.check()
+    }
+}
+
+impl<L, R> Check for Product<L, R> where L: Check, R: Check {
+    fn check(&self) -> Result<(u32, u32), Error> {
+        product(try!(self.0.check()), try!(self.1.check()))
+    }
+}
+
+impl<M> Check for Scaled<M> where M: Check + Matrix {
+    fn check(&self) -> Result<(u32, u32), Error> {
+        self.1.check()
+    }
+}
+
+impl<M> Check for ConjTransposed<M> where M: Check {
+    fn check(&self) -> Result<(u32, u32), Error> {
+        self.0.check().map(|(nrows, ncols)| (ncols, nrows))
+    }
+}
+
+impl<M> Check for Transposed<M> where M: Check {
+    fn check(&self) -> Result<(u32, u32), Error> {
+        self.0.check().map(|(nrows, ncols)| (ncols, nrows))
+    }
+}
+
+impl<'a, M> Check for &'a M where M: Check {
+    fn check(&self) -> Result<(u32, u32), Error> {
+        (**self).check()
+    }
+}
+
+macro_rules! check {
+    ($($ty:ty),+,) => {
+        $(
+            impl<'a, T> Check for $ty {
+                fn check(&self) -> Result<(u32, u32), Error> {
+                    Ok(self.size())
+                }
+            }
+         )+
+    }
+}
+
+check! {
+    BandMat<T>,
+    Col<'a, T>,
+    ColMut<'a, T>,
+    ColVec<T>,
+    LowerTri<SubMat<'a, T>>,
+    Mat<T>,
+    Row<'a, T>,
+    RowMut<'a, T>,
+    RowVec<T>,
+    SparseMat<T>,
+    SubMat<'a, T>,
+    SubMatMut<'a, T>,
+    Symmetric<SubMat<'a, T>>,
+    UpperTri<SubMat<'a, T>>,
+}
+
+impl<L, R> TryAddAssign<R> for L where L: AddAssign<R> + Check, R: Check {
+    fn try_add_assign(&mut self, rhs: R) -> Result<(), Error> {
+        try!(same_size(try!(self.check()), try!(rhs.check())));
+
+        self.add_assign(rhs);
+
+        Ok(())
+    }
+}
+
+impl<E> TryEval for E where E: Check + Eval {
+    fn try_eval(self) -> Result<E::Output, Error> {
+        try!(self.check());
+
+        Ok(self.eval())
+    }
+}
+
+impl<L, R> TryMul<R> for L where L: Check + Mul<R>, R: Check {
+    fn try_mul(self, rhs: R) -> Result<L::Output, Error> {
+        try!(product(try!(self.check()), try!(rhs.check())));
+
+        Ok(self * rhs)
+    }
+}
+
+impl<L, R> TrySet<R> for L where L: Check + Set<R>, R: Check {
+    fn try_set(&mut self, rhs: R) -> Result<(), Error> {
+        try!(same_size(try!(self.check()), try!(rhs.check())));
+
+        self.set(rhs);
+
+        Ok(())
+    }
+}
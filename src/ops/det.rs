@@ -0,0 +1,82 @@
+use complex::Complex;
+
+use traits::{Det, Slice};
+use {Mat, Transposed, SubMat, SubMatMut};
+
+// Combinations:
+//
+// Mat, SubMat, SubMatMut, Transposed<M>
+//
+// -> 4 implementations per element type
+
+// NOTE The LU factorization overwrites the matrix, so the matrix is copied into a new buffer
+macro_rules! det {
+    ($($t:ty => $real:ty),+,) => {
+        $(
+            // NOTE Core
+            impl<'a> Det for SubMat<'a, $t> {
+                type Output = $t;
+                type Real = $real;
+
+                fn det(&self) -> $t {
+                    Mat::from(*self).lu().det()
+                }
+
+                fn slogdet(&self) -> ($t, $real) {
+                    Mat::from(*self).lu().slogdet()
+                }
+            }
+
+            // NOTE Forward
+            impl Det for Mat<$t> {
+                type Output = $t;
+                type Real = $real;
+
+                fn det(&self) -> $t {
+                    self.slice(..).det()
+                }
+
+                fn slogdet(&self) -> ($t, $real) {
+                    self.slice(..).slogdet()
+                }
+            }
+
+            // NOTE Forward
+            impl<'a> Det for SubMatMut<'a, $t> {
+                type Output = $t;
+                type Real = $real;
+
+                fn det(&self) -> $t {
+                    self.slice(..).det()
+                }
+
+                fn slogdet(&self) -> ($t, $real) {
+                    self.slice(..).slogdet()
+                }
+            }
+         )+
+    }
+}
+
+// 12 impls
+det! {
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => f32,
+    Complex<f64> => f64,
+}
+
+// NOTE Forward
+// Remember that det(A^t) === det(A)
+impl<M> Det for Transposed<M> where M: Det {
+    type Output = M::Output;
+    type Real = M::Real;
+
+    fn det(&self) -> M::Output {
+        self.0.det()
+    }
+
+    fn slogdet(&self) -> (M::Output, M::Real) {
+        self.0.slogdet()
+    }
+}
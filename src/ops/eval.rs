@@ -1,10 +1,18 @@
-use blas::{Gemm, Gemv, Transpose};
+use assign::MulAssign;
+use blas::{
+    Copy, Gbmv, Gemm, Gemv, Ger, Scal, Symm, Symv, Syrk, Transpose, Trmm, Trmv, Uplo,
+};
+use cast::From as _0;
 use onezero::{One, Zero};
 
+use ops::reduce;
 use ops::{Reduce, self};
 use traits::Transpose as _0;
-use traits::{Eval, Matrix, Slice, SliceMut};
-use {Chain, Col, ColVec, Mat, Product, Row, RowVec, Scaled, Tor, Transposed, SubMat};
+use traits::{Eval, Matrix, Scalar, Slice, SliceMut};
+use {
+    BandMat, Chain, Col, ColVec, ConjChain, ConjTransposed, LowerTri, Mat, Product, Row, RowVec,
+    Scaled, SparseMat, Symmetric, Tor, Transposed, SubMat, UpperTri,
+};
 
 /// alpha * op(A) * x
 unsafe fn gemv<T>(
@@ -15,30 +23,45 @@ unsafe fn gemv<T>(
 ) -> ColVec<T> where
     T: Gemv + Zero,
 {
+    let ref backend = T::backend();
     let mut y = ColVec(Tor::uninitialized(match *trans {
         Transpose::No => a.nrows,
-        Transpose::Yes => a.ncols,
+        Transpose::Yes | Transpose::Conj => a.ncols,
     }));
 
-    ops::gemv(trans, alpha, a, &T::zero(), x, y.slice_mut(..));
+    ops::gemv(backend, trans, alpha, a, &T::zero(), x, y.slice_mut(..));
 
     y
 }
 
 // Core implementations
 impl<'a, T> Eval for Scaled<Chain<'a, T>> where
-    T: Gemm + One + Zero,
+    T: Copy + Gemm + One + Syrk + Zero,
 {
     type Output = Mat<T>;
 
     fn eval(self) -> Mat<T> {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulMat::*;
 
             let Scaled(alpha, chain) = self;
             let ref alpha = alpha;
 
-            let a_mul_b = chain.reduce();
+            if let Some((ref trans, a)) = reduce::gram(&chain) {
+                let n = match *trans {
+                    Transpose::No => a.nrows,
+                    Transpose::Yes | Transpose::Conj => a.ncols,
+                };
+                let mut c = Mat::uninitialized((n, n));
+
+                ops::syrk(backend, trans, alpha, a, c.slice_mut(..));
+
+                return c
+            }
+
+            let a_mul_b = chain.reduce(backend);
 
             let (ref transa, ref transb, a, b) = match a_mul_b {
                 M_M(ref lhs, ref rhs) => {
@@ -55,7 +78,7 @@ impl<'a, T> Eval for Scaled<Chain<'a, T>> where
                 },
             };
 
-            ops::submat_mul_submat(transa, transb, alpha, a, b)
+            ops::submat_mul_submat(backend, transa, transb, alpha, a, b)
         }
     }
 }
@@ -66,13 +89,15 @@ impl<'a, 'b, T> Eval for Scaled<Product<Chain<'a, T>, Col<'b, T>>> where
     type Output = ColVec<T>;
 
     fn eval(self) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulCol::*;
 
             let Scaled(alpha, product) = self;
             let ref alpha = alpha;
 
-            let a_mul_b = product.reduce();
+            let a_mul_b = product.reduce(backend);
 
             let (ref transa, a, x) = match a_mul_b {
                 M_C(ref lhs, x) => {
@@ -86,7 +111,48 @@ impl<'a, 'b, T> Eval for Scaled<Product<Chain<'a, T>, Col<'b, T>>> where
                 }
             };
 
-            ops::submat_mul_col(transa, alpha, a, x)
+            ops::submat_mul_col(backend, transa, alpha, a, x)
+        }
+    }
+}
+
+// NB The evaluation of a `Chain` already handles conjugate transposes, `ConjChain` only keeps
+// them away from the operations that don't (transposition and row vector products)
+impl<'a, T> Eval for Scaled<ConjChain<'a, T>> where
+    T: Copy + Gemm + One + Syrk + Zero,
+{
+    type Output = Mat<T>;
+
+    fn eval(self) -> Mat<T> {
+        let Scaled(alpha, ConjChain(chain)) = self;
+
+        Scaled(alpha, chain).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Scaled<Product<ConjChain<'a, T>, Col<'b, T>>> where
+    T: Gemm + Gemv + One + Zero,
+{
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        let Scaled(alpha, Product(ConjChain(chain), x)) = self;
+
+        Scaled(alpha, Product(chain, x)).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Scaled<Product<ConjTransposed<SubMat<'a, T>>, Col<'b, T>>> where
+    T: Gemv + Zero,
+{
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        unsafe {
+            let Scaled(ref alpha, Product(ConjTransposed(a), x)) = self;
+            let ref trans = Transpose::Conj;
+
+            gemv(trans, alpha, a, x)
         }
     }
 }
@@ -120,7 +186,7 @@ impl<'a, 'b, T> Eval for Scaled<Product<SubMat<'a, T>, Col<'b, T>>> where T: Gem
 }
 
 // Secondary implementations
-impl<'a, T> Eval for Chain<'a, T> where T: Gemm + One + Zero {
+impl<'a, T> Eval for Chain<'a, T> where T: Copy + Gemm + One + Syrk + Zero {
     type Output = Mat<T>;
 
     fn eval(self) -> Mat<T> {
@@ -136,6 +202,34 @@ impl<'a, 'b, T> Eval for Product<Chain<'a, T>, Col<'b, T>> where T: Gemm + Gemv
     }
 }
 
+impl<'a, T> Eval for ConjChain<'a, T> where T: Copy + Gemm + One + Syrk + Zero {
+    type Output = Mat<T>;
+
+    fn eval(self) -> Mat<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Product<ConjChain<'a, T>, Col<'b, T>> where
+    T: Gemm + Gemv + One + Zero,
+{
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Product<ConjTransposed<SubMat<'a, T>>, Col<'b, T>> where
+    T: Gemv + One + Zero,
+{
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
+
 impl<'a, 'b, T> Eval for Product<Transposed<SubMat<'a, T>>, Col<'b, T>> where
     T: Gemv + One + Zero,
 {
@@ -209,3 +303,235 @@ impl<'a, 'b, T> Eval for Scaled<Product<Row<'a, T>, SubMat<'b, T>>> where
         self.t().eval().t()
     }
 }
+
+// NB `op(A) * x` and `op(A) * B` overwrite (a copy of) their right hand side
+macro_rules! tri {
+    ($($tri:ident => $uplo:expr),+) => {
+        $(
+            // Core implementations
+            impl<'a, 'b, T> Eval for Product<$tri<SubMat<'a, T>>, Col<'b, T>> where
+                T: Copy + Trmv,
+            {
+                type Output = ColVec<T>;
+
+                fn eval(self) -> ColVec<T> {
+                    let ref backend = T::backend();
+
+                    unsafe {
+                        let Product($tri(a, ref diag), x) = self;
+                        let ref trans = Transpose::No;
+                        let mut y = ColVec::from(x);
+
+                        ops::trmv(backend, $uplo, trans, diag, a, y.slice_mut(..));
+
+                        y
+                    }
+                }
+            }
+
+            impl<'a, 'b, T> Eval for Scaled<Product<$tri<SubMat<'a, T>>, SubMat<'b, T>>> where
+                T: Copy + Trmm,
+            {
+                type Output = Mat<T>;
+
+                fn eval(self) -> Mat<T> {
+                    let ref backend = T::backend();
+
+                    unsafe {
+                        let Scaled(ref alpha, Product($tri(a, ref diag), b)) = self;
+                        let ref transa = Transpose::No;
+                        let mut c = Mat::from(b);
+
+                        ops::trmm(backend, $uplo, transa, diag, alpha, a, c.slice_mut(..));
+
+                        c
+                    }
+                }
+            }
+
+            // Secondary implementations
+            impl<'a, 'b, T> Eval for Product<$tri<SubMat<'a, T>>, SubMat<'b, T>> where
+                T: Copy + One + Trmm,
+            {
+                type Output = Mat<T>;
+
+                fn eval(self) -> Mat<T> {
+                    Scaled(T::one(), self).eval()
+                }
+            }
+
+            impl<'a, 'b, T> Eval for Scaled<Product<$tri<SubMat<'a, T>>, Col<'b, T>>> where
+                T: Copy + Scal<T> + Trmv,
+            {
+                type Output = ColVec<T>;
+
+                fn eval(self) -> ColVec<T> {
+                    let Scaled(alpha, product) = self;
+                    let mut y = product.eval();
+
+                    y.mul_assign(alpha);
+
+                    y
+                }
+            }
+         )+
+    }
+}
+
+tri!(LowerTri => &Uplo::Lower, UpperTri => &Uplo::Upper);
+
+// Core implementations
+impl<'a, 'b, T> Eval for Scaled<Product<&'a BandMat<T>, Col<'b, T>>> where T: Gbmv + Zero {
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        let ref backend = T::backend();
+
+        unsafe {
+            let Scaled(ref alpha, Product(a, x)) = self;
+            let mut y = ColVec(Tor::uninitialized(a.ab.ncols));
+
+            ops::gbmv(backend, alpha, a, &T::zero(), x, y.slice_mut(..));
+
+            y
+        }
+    }
+}
+
+impl<'a, 'b, T> Eval for Scaled<Product<Col<'a, T>, Row<'b, T>>> where T: Ger + Zero {
+    type Output = Mat<T>;
+
+    fn eval(self) -> Mat<T> {
+        let ref backend = T::backend();
+
+        unsafe {
+            let Scaled(ref alpha, Product(x, y)) = self;
+            let mut a = Mat::zeros((x.nrows(), y.ncols()));
+
+            ops::ger(backend, alpha, x, y, a.slice_mut(..));
+
+            a
+        }
+    }
+}
+
+impl<'a, 'b, T> Eval for Scaled<Product<&'a SparseMat<T>, Col<'b, T>>> where T: Scalar {
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        unsafe {
+            let Scaled(ref alpha, Product(a, x)) = self;
+            let mut y = ColVec(Tor::uninitialized(i32::from_(a.nrows).unwrap()));
+
+            ops::spmv(alpha, a, &T::zero(), x, y.slice_mut(..));
+
+            y
+        }
+    }
+}
+
+impl<'a, 'b, T> Eval for Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>> where T: Scalar {
+    type Output = Mat<T>;
+
+    fn eval(self) -> Mat<T> {
+        unsafe {
+            let Scaled(ref alpha, Product(a, b)) = self;
+            let mut c = Mat::uninitialized((i32::from_(a.nrows).unwrap(), b.ncols));
+
+            ops::spmm(alpha, a, &T::zero(), b, c.slice_mut(..));
+
+            c
+        }
+    }
+}
+
+impl<'a, 'b, T> Eval for Scaled<Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>> where
+    T: Symv + Zero,
+{
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        let ref backend = T::backend();
+
+        unsafe {
+            let Scaled(ref alpha, Product(Symmetric(a, ref uplo), x)) = self;
+            let mut y = ColVec(Tor::uninitialized(a.nrows));
+
+            ops::symv(backend, uplo, alpha, a, &T::zero(), x, y.slice_mut(..));
+
+            y
+        }
+    }
+}
+
+impl<'a, 'b, T> Eval for Scaled<Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>> where
+    T: Symm + Zero,
+{
+    type Output = Mat<T>;
+
+    fn eval(self) -> Mat<T> {
+        let ref backend = T::backend();
+
+        unsafe {
+            let Scaled(ref alpha, Product(Symmetric(a, ref uplo), b)) = self;
+            let mut c = Mat::uninitialized((a.nrows, b.ncols));
+
+            ops::symm(backend, uplo, alpha, a, b, &T::zero(), c.slice_mut(..));
+
+            c
+        }
+    }
+}
+
+// Secondary implementations
+impl<'a, 'b, T> Eval for Product<&'a BandMat<T>, Col<'b, T>> where T: Gbmv + One + Zero {
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Product<Col<'a, T>, Row<'b, T>> where T: Ger + One + Zero {
+    type Output = Mat<T>;
+
+    fn eval(self) -> Mat<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Product<&'a SparseMat<T>, Col<'b, T>> where T: Scalar {
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Product<&'a SparseMat<T>, SubMat<'b, T>> where T: Scalar {
+    type Output = Mat<T>;
+
+    fn eval(self) -> Mat<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Product<Symmetric<SubMat<'a, T>>, Col<'b, T>> where
+    T: One + Symv + Zero,
+{
+    type Output = ColVec<T>;
+
+    fn eval(self) -> ColVec<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
+
+impl<'a, 'b, T> Eval for Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>> where
+    T: One + Symm + Zero,
+{
+    type Output = Mat<T>;
+
+    fn eval(self) -> Mat<T> {
+        Scaled(T::one(), self).eval()
+    }
+}
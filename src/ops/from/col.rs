@@ -9,7 +9,7 @@ use {Col, ColMut, ColVec, Slice, Tor};
 impl<'a, T> From<Col<'a, T>> for ColVec<T> where T: Copy {
     fn from(input: Col<T>) -> ColVec<T> {
         unsafe {
-            ColVec(from::strided_to_tor(&input.0))
+            ColVec(from::strided_to_tor(&T::backend(), &input.0))
         }
     }
 }
@@ -1,16 +1,22 @@
 use blas::Copy;
+use onezero::Zero;
 
 use ops::{from, self};
-use traits::{Matrix, MatrixCols, MatrixColsMut, MatrixRows, MatrixRowsMut, Slice};
-use {ColMut, Col, Mat, RowMut, Row, Transposed, SubMat, SubMatMut};
+use traits::{Matrix, MatrixCols, MatrixColsMut, MatrixRows, MatrixRowsMut, Scalar, Slice};
+use {
+    ColMut, Col, ConjTransposed, Conjugated, Mat, RowMut, Row, SparseFormat, SparseMat,
+    StridedMat, Transposed, SubMat, SubMatMut,
+};
 
 // NOTE Core
 impl<'a, T> From<SubMat<'a, T>> for Mat<T> where T: Copy {
     fn from(input: SubMat<T>) -> Mat<T> {
+        let ref backend = T::backend();
+
         unsafe {
             if let Some(slice) = input.as_slice() {
                 Mat {
-                    data: from::slice(slice),
+                    data: from::slice(backend, slice),
                     ncols: input.ncols,
                     nrows: input.nrows,
                 }
@@ -19,11 +25,11 @@ impl<'a, T> From<SubMat<'a, T>> for Mat<T> where T: Copy {
 
                 if input.nrows < input.ncols {
                     for (Row(ref x), RowMut(Row(ref mut y))) in input.rows().zip(m.rows_mut()) {
-                        ops::copy_strided(x, y)
+                        ops::copy_strided(backend, x, y)
                     }
                 } else {
                     for (Col(ref x), ColMut(Col(ref mut y))) in input.cols().zip(m.cols_mut()) {
-                        ops::copy_strided(x, y)
+                        ops::copy_strided(backend, x, y)
                     }
                 }
 
@@ -36,16 +42,18 @@ impl<'a, T> From<SubMat<'a, T>> for Mat<T> where T: Copy {
 // NOTE Core
 impl<'a, T> From<Transposed<SubMat<'a, T>>> for Mat<T> where T: Copy {
     fn from(input: Transposed<SubMat<T>>) -> Mat<T> {
+        let ref backend = T::backend();
+
         unsafe {
             let mut m = Mat::uninitialized((input.0.ncols, input.0.nrows));
 
             if input.nrows() < input.ncols() {
                 for (Row(ref x), RowMut(Row(ref mut y))) in input.rows().zip(m.rows_mut()) {
-                    ops::copy_strided(x, y)
+                    ops::copy_strided(backend, x, y)
                 }
             } else {
                 for (Col(ref x), ColMut(Col(ref mut y))) in input.cols().zip(m.cols_mut()) {
-                    ops::copy_strided(x, y)
+                    ops::copy_strided(backend, x, y)
                 }
             }
 
@@ -54,6 +62,51 @@ impl<'a, T> From<Transposed<SubMat<'a, T>>> for Mat<T> where T: Copy {
     }
 }
 
+// NOTE Core
+impl<'a, T> From<Conjugated<SubMat<'a, T>>> for Mat<T> where T: Scalar {
+    fn from(input: Conjugated<SubMat<T>>) -> Mat<T> {
+        let a = input.0;
+
+        Mat::from_fn(a.size(), |(r, c)| a[(r, c)].conj())
+    }
+}
+
+// NOTE Core
+impl<'a, T> From<ConjTransposed<SubMat<'a, T>>> for Mat<T> where T: Scalar {
+    fn from(input: ConjTransposed<SubMat<T>>) -> Mat<T> {
+        let a = input.0;
+
+        Mat::from_fn(input.size(), |(r, c)| a[(c, r)].conj())
+    }
+}
+
+// NOTE Core
+impl<'a, T> From<&'a SparseMat<T>> for Mat<T> where T: Copy + Zero {
+    fn from(input: &SparseMat<T>) -> Mat<T> {
+        let mut m = Mat::zeros(input.size());
+
+        for (major, w) in (0..).zip(input.indptr.windows(2)) {
+            for p in w[0]..w[1] {
+                let minor = input.indices[p];
+
+                m[match input.format {
+                    SparseFormat::Csc => (minor, major),
+                    SparseFormat::Csr => (major, minor),
+                }] = input.data[p];
+            }
+        }
+
+        m
+    }
+}
+
+// NOTE Core
+impl<'a, T> From<StridedMat<'a, T>> for Mat<T> where T: Copy {
+    fn from(input: StridedMat<T>) -> Mat<T> {
+        Mat::from_fn(input.size(), |i| input[i])
+    }
+}
+
 macro_rules! forward {
     ($($src:ty),+,) => {
         $(
@@ -8,7 +8,7 @@ use {Row, RowMut, RowVec, Slice, Tor};
 impl<'a, T> From<Row<'a, T>> for RowVec<T> where T: Copy {
     fn from(input: Row<T>) -> RowVec<T> {
         unsafe {
-            RowVec(from::strided_to_tor(&input.0))
+            RowVec(from::strided_to_tor(&T::backend(), &input.0))
         }
     }
 }
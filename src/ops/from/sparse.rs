@@ -0,0 +1,51 @@
+use onezero::Zero;
+
+use traits::{Matrix, MatrixCols, Slice};
+use {Mat, SparseFormat, SparseMat, SubMat, SubMatMut};
+
+// NOTE Core
+/// Stores the non-zero elements of `input` in the CSC format
+impl<'a, T> From<SubMat<'a, T>> for SparseMat<T> where T: Copy + PartialEq + Zero {
+    fn from(input: SubMat<T>) -> SparseMat<T> {
+        let mut data = vec![];
+        let mut indices = vec![];
+        let mut indptr = vec![0];
+
+        for col in input.cols() {
+            for (row, &x) in (0..).zip(col.iter()) {
+                if x != T::zero() {
+                    data.push(x);
+                    indices.push(row);
+                }
+            }
+
+            indptr.push(data.len());
+        }
+
+        SparseMat {
+            data: data,
+            format: SparseFormat::Csc,
+            indices: indices,
+            indptr: indptr,
+            ncols: input.ncols(),
+            nrows: input.nrows(),
+        }
+    }
+}
+
+macro_rules! forward {
+    ($($src:ty),+,) => {
+        $(
+            impl<'a, 'b, T> From<$src> for SparseMat<T> where T: Copy + PartialEq + Zero {
+                fn from(input: $src) -> SparseMat<T> {
+                    SparseMat::from(input.slice(..))
+                }
+            }
+         )+
+    }
+}
+
+forward! {
+    &'a Mat<T>,
+    &'a SubMatMut<'b, T>,
+}
@@ -1,50 +1,72 @@
 use blas::Gemm;
-use cast::From;
-use extract::Extract;
-use lapack::{Getrf, Getri};
+use lapack::{Getrf, Getri, Lange, Potri};
 use onezero::{One, Zero};
 
-use traits::{Eval, Matrix, MatrixInverse, SliceMut};
-use {Chain, Mat, Scaled, SubMatMut, Transposed};
+use ops::{Conj, self};
+use traits::{Eval, Matrix, MatrixInverse, SliceMut, TryEval, TryInv};
+use {Chain, Cholesky, Error, Lu, Mat, Scaled, SubMatMut, Transposed};
 
-unsafe fn inv<T>(m: SubMatMut<T>) where T: Getri + Getrf {
+unsafe fn inv<T>(mut m: SubMatMut<T>) -> Result<(), Error> where T: Getri + Getrf {
     debug_assert_eq!(m.nrows(), m.ncols());
 
-    let getri = T::getri();
-    let getrf = T::getrf();
+    let (ipiv, info) = ops::getrf(m.slice_mut(..));
 
-    let ref n = m.0.nrows;
-    let mut ipiv = Vec::with_capacity(usize::from(*n).extract());
-    let ipiv = ipiv.as_mut_ptr();
+    try!(ops::check_info(info));
 
-    let a = *m.0.data;
-    let ref lda = m.0.stride;
-    let ref mut info = 0;
+    ops::getri(m, &ipiv);
 
-    getrf(n, n, a, lda, ipiv, info);
+    Ok(())
+}
 
-    assert!(*info == 0);
+// NOTE Core
+impl<T> MatrixInverse for Mat<T> where T: Getri + Getrf {
+    type Output = Mat<T>;
 
-    let lwork = n;
-    let mut work = Vec::with_capacity(usize::from(*lwork).extract());
-    let work = work.as_mut_ptr();
+    fn inv(mut self) -> Mat<T> {
+        unsafe {
+            assert_eq!(self.nrows(), self.ncols());
 
-    getri(n, a, lda, ipiv, work, lwork, info);
+            inv(self.slice_mut(..)).unwrap();
 
-    assert!(*info == 0);
+            self
+        }
+    }
 }
 
 // NOTE Core
-impl<T> MatrixInverse for Mat<T> where T: Getri + Getrf {
+impl<T> MatrixInverse for Cholesky<T> where T: Conj + Copy + Potri {
+    type Output = Mat<T>;
+
+    fn inv(self) -> Mat<T> {
+        unsafe {
+            let mut m = self.0;
+
+            ops::potri(m.slice_mut(..));
+
+            // `potri` only computes the lower triangle, the upper one must be filled in
+            let n = m.nrows();
+            for col in 0..n {
+                for row in col+1..n {
+                    m[(col, row)] = m[(row, col)].conj();
+                }
+            }
+
+            m
+        }
+    }
+}
+
+// NOTE Core
+impl<T> MatrixInverse for Lu<T> where T: Getri + Lange {
     type Output = Mat<T>;
 
     fn inv(mut self) -> Mat<T> {
         unsafe {
-            assert_eq!(self.nrows(), self.ncols());
+            assert!(self.info == 0);
 
-            inv(self.slice_mut(..));
+            ops::getri(self.lu.slice_mut(..), &self.ipiv);
 
-            self
+            self.lu
         }
     }
 }
@@ -67,7 +89,7 @@ impl<'a, T> MatrixInverse for Transposed<Mat<T>> where T: Getrf + Getri {
 
     fn inv(mut self) -> Transposed<Mat<T>> {
         unsafe {
-            inv(self.0.slice_mut(..));
+            inv(self.0.slice_mut(..)).unwrap();
 
             self
         }
@@ -82,3 +104,60 @@ impl<'a, T> MatrixInverse for Chain<'a, T> where T: Gemm + Getrf + Getri + One +
         Scaled(T::one(), self).inv()
     }
 }
+
+// NOTE Core
+impl<T> TryInv for Mat<T> where T: Getri + Getrf {
+    fn try_inv(mut self) -> Result<Mat<T>, Error> {
+        unsafe {
+            if self.nrows() != self.ncols() {
+                return Err(Error::NotSquare { size: self.size() })
+            }
+
+            try!(inv(self.slice_mut(..)));
+
+            Ok(self)
+        }
+    }
+}
+
+// NOTE Core
+// A Cholesky factorization always corresponds to an invertible matrix
+impl<T> TryInv for Cholesky<T> where T: Conj + Copy + Potri {
+    fn try_inv(self) -> Result<Mat<T>, Error> {
+        Ok(self.inv())
+    }
+}
+
+// NOTE Core
+impl<T> TryInv for Lu<T> where T: Getri + Lange {
+    fn try_inv(mut self) -> Result<Mat<T>, Error> {
+        unsafe {
+            try!(ops::check_info(self.info));
+
+            ops::getri(self.lu.slice_mut(..), &self.ipiv);
+
+            Ok(self.lu)
+        }
+    }
+}
+
+// NOTE Secondary
+impl<'a, T> TryInv for Scaled<Chain<'a, T>> where T: Gemm + Getrf + Getri + One + Zero {
+    fn try_inv(self) -> Result<Mat<T>, Error> {
+        try!(self.try_eval()).try_inv()
+    }
+}
+
+// NOTE Secondary
+impl<'a, T> TryInv for Transposed<Mat<T>> where T: Getrf + Getri {
+    fn try_inv(self) -> Result<Transposed<Mat<T>>, Error> {
+        self.0.try_inv().map(Transposed)
+    }
+}
+
+// NOTE Forward
+impl<'a, T> TryInv for Chain<'a, T> where T: Gemm + Getrf + Getri + One + Zero {
+    fn try_inv(self) -> Result<Mat<T>, Error> {
+        Scaled(T::one(), self).try_inv()
+    }
+}
@@ -0,0 +1,248 @@
+use std::{cmp, ptr};
+
+use blas::Copy;
+use cast::From as _0;
+use lapack::Gelsd;
+
+use ops;
+use traits::{Lstsq, Matrix, Set, Slice, SliceMut};
+use {Col, ColMut, ColVec, Mat, SubMat, SubMatMut, Tor};
+
+// Combinations:
+//
+// LHS: Mat, &Mat, SubMat, &SubMatMut
+// RHS: Col, &ColMut, ColVec, &ColVec, Mat, &Mat, SubMat, &SubMatMut
+//
+// -> 32 implementations
+
+// 4 impls
+// NOTE Core
+impl<'a, T> Lstsq<Col<'a, T>> for Mat<T> where T: Copy + Gelsd {
+    type Output = ColVec<T>;
+
+    fn lstsq(mut self, b: Col<'a, T>) -> ColVec<T> {
+        unsafe {
+            let (m, n) = self.size();
+
+            assert_eq!(m, b.nrows());
+
+            // NB `gelsd` needs `max(m, n)` rows to store both the right hand side and the solution
+            let k = cmp::max(self.nrows, self.ncols);
+            let mut x = ColVec(Tor::uninitialized(k));
+
+            x.slice_mut(..m).set(b);
+
+            {
+                let x = SubMatMut::reshape(x.as_slice_mut(), (cmp::max(m, n), 1));
+
+                ops::gelsd(self.slice_mut(..), x);
+            }
+
+            if m > n {
+                ColVec::from(x.slice(..n))
+            } else {
+                x
+            }
+        }
+    }
+}
+
+// NOTE Core
+impl<'a, T> Lstsq<SubMat<'a, T>> for Mat<T> where T: Copy + Gelsd {
+    type Output = Mat<T>;
+
+    fn lstsq(mut self, b: SubMat<'a, T>) -> Mat<T> {
+        unsafe {
+            let (m, n) = self.size();
+
+            assert_eq!(m, b.nrows());
+
+            let k = cmp::max(self.nrows, self.ncols);
+            let mut x = Mat::uninitialized((k, b.ncols));
+
+            x.slice_mut((..m, ..)).set(b);
+
+            ops::gelsd(self.slice_mut(..), x.slice_mut(..));
+
+            if m > n {
+                Mat::from(x.slice((..n, ..)))
+            } else {
+                x
+            }
+        }
+    }
+}
+
+// NOTE Core
+impl<T> Lstsq<ColVec<T>> for Mat<T> where T: Copy + Gelsd {
+    type Output = ColVec<T>;
+
+    fn lstsq(mut self, mut b: ColVec<T>) -> ColVec<T> {
+        unsafe {
+            let (m, n) = self.size();
+
+            assert_eq!(m, b.nrows());
+
+            // NB `b` is too short to hold the solution of an underdetermined system
+            if m < n {
+                return self.lstsq(b.slice(..))
+            }
+
+            {
+                let b = SubMatMut::reshape(b.as_slice_mut(), (m, 1));
+
+                ops::gelsd(self.slice_mut(..), b);
+            }
+
+            if m > n {
+                let mut v = b.0.into_vec();
+                v.truncate(usize::from_(n));
+
+                ColVec::from(v)
+            } else {
+                b
+            }
+        }
+    }
+}
+
+// NOTE Core
+impl<T> Lstsq<Mat<T>> for Mat<T> where T: Copy + Gelsd {
+    type Output = Mat<T>;
+
+    fn lstsq(mut self, mut b: Mat<T>) -> Mat<T> {
+        unsafe {
+            let (m, n) = self.size();
+
+            assert_eq!(m, b.nrows());
+
+            // NB `b` is too short to hold the solution of an underdetermined system
+            if m < n {
+                return self.lstsq(b.slice(..))
+            }
+
+            ops::gelsd(self.slice_mut(..), b.slice_mut(..));
+
+            if m > n {
+                // move the first `n` rows of each column to the front of the buffer
+                let nrhs = b.ncols();
+                let (m_, n_) = (usize::from_(m), usize::from_(n));
+                let mut v = b.into_vec();
+
+                for j in 1..usize::from_(nrhs) {
+                    let src = v.as_ptr().offset((j * m_) as isize);
+                    let dst = v.as_mut_ptr().offset((j * n_) as isize);
+
+                    ptr::copy(src, dst, n_);
+                }
+
+                v.truncate(n_ * usize::from_(nrhs));
+
+                Mat::from_vec((n, nrhs), v)
+            } else {
+                b
+            }
+        }
+    }
+}
+
+macro_rules! rhs {
+    ($($lhs:ty),+,) => {
+        $(
+            // NOTE Forward
+            impl<'a, 'b, 'c, 'd, T> Lstsq<&'c ColMut<'d, T>> for $lhs where T: Copy + Gelsd {
+                type Output = ColVec<T>;
+
+                fn lstsq(self, b: &'c ColMut<'d, T>) -> ColVec<T> {
+                    self.lstsq(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, T> Lstsq<&'c ColVec<T>> for $lhs where T: Copy + Gelsd {
+                type Output = ColVec<T>;
+
+                fn lstsq(self, b: &'c ColVec<T>) -> ColVec<T> {
+                    self.lstsq(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, T> Lstsq<&'c Mat<T>> for $lhs where T: Copy + Gelsd {
+                type Output = Mat<T>;
+
+                fn lstsq(self, b: &'c Mat<T>) -> Mat<T> {
+                    self.lstsq(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, 'd, T> Lstsq<&'c SubMatMut<'d, T>> for $lhs where T: Copy + Gelsd {
+                type Output = Mat<T>;
+
+                fn lstsq(self, b: &'c SubMatMut<'d, T>) -> Mat<T> {
+                    self.lstsq(b.slice(..))
+                }
+            }
+         )+
+    }
+}
+
+// 4 impls
+rhs! {
+    Mat<T>,
+}
+
+// `gelsd` overwrites the coefficient matrix, so views must be copied first
+macro_rules! lhs {
+    ($($lhs:ty),+,) => {
+        $(
+            // NOTE Secondary
+            impl<'a, 'b, 'c, T> Lstsq<Col<'c, T>> for $lhs where T: Copy + Gelsd {
+                type Output = ColVec<T>;
+
+                fn lstsq(self, b: Col<'c, T>) -> ColVec<T> {
+                    Mat::from(self.slice(..)).lstsq(b)
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, 'c, T> Lstsq<ColVec<T>> for $lhs where T: Copy + Gelsd {
+                type Output = ColVec<T>;
+
+                fn lstsq(self, b: ColVec<T>) -> ColVec<T> {
+                    Mat::from(self.slice(..)).lstsq(b)
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, 'c, T> Lstsq<Mat<T>> for $lhs where T: Copy + Gelsd {
+                type Output = Mat<T>;
+
+                fn lstsq(self, b: Mat<T>) -> Mat<T> {
+                    Mat::from(self.slice(..)).lstsq(b)
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, 'c, T> Lstsq<SubMat<'c, T>> for $lhs where T: Copy + Gelsd {
+                type Output = Mat<T>;
+
+                fn lstsq(self, b: SubMat<'c, T>) -> Mat<T> {
+                    Mat::from(self.slice(..)).lstsq(b)
+                }
+            }
+         )+
+
+        rhs! {
+            $($lhs),+,
+        }
+    }
+}
+
+// 24 impls
+lhs! {
+    &'a Mat<T>,
+    SubMat<'a, T>,
+    &'a SubMatMut<'b, T>,
+}
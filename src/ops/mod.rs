@@ -1,11 +1,28 @@
-use blas::{Axpy, Copy, Gemm, Gemv, Scal, Transpose};
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use std::cmp;
+
+use blas::{
+    Axpy, Copy, Diagonal, Gbmv, Gemm, Gemv, Ger, Scal, Side, Symm, Symv, Syrk, Transpose, Trmm,
+    Trmv, Trsm, Trsv, Uplo,
+};
 use cast::From;
+use complex::Complex;
 use extract::Extract;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use lapack::{
+    Gbsv, Gecon, Gelsd, Geqrf, Getrf, Getri, Getrs, Gtsv, Lange, Orgqr, Ormqr, Potrf, Potri,
+    Potrs, Trtrs,
+};
 use onezero::Zero;
 
 use traits::Transpose as _0;
-use traits::{Matrix, SliceMut};
-use {Col, ColMut, ColVec, Mat, Row, RowVec, Slice, SubMat, SubMatMut, Tor};
+use traits::{Backend, Element, Matrix, MatrixCol, MatrixColMut, Scalar, SliceMut};
+use {
+    BandMat, Col, ColMut, ColVec, Mat, Row, RowVec, Slice, SparseFormat, SparseMat, SubMat,
+    SubMatMut, Tor,
+};
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use Error;
 
 macro_rules! assert_eq_inner_dimensions {
     ($lhs:expr, $rhs:expr) => {
@@ -21,11 +38,18 @@ macro_rules! assert_eq_size {
 
 mod add;
 mod add_assign;
+mod axis;
+mod check;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod det;
 mod div_assign;
 mod eq;
 mod eval;
 mod from;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
 mod inv;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod lstsq;
 mod mcop;
 mod mul;
 mod mul_assign;
@@ -34,19 +58,47 @@ mod product;
 mod reduce;
 mod scaled;
 mod set;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod solve;
+mod solve_tri;
 mod sub;
 mod sub_assign;
 
-trait Reduce {
+/// Complex conjugate
+pub trait Conj {
+    /// Returns the complex conjugate of `self`
+    fn conj(self) -> Self;
+}
+
+macro_rules! conj {
+    ($($t:ty),+) => {
+        $(
+            impl Conj for $t {
+                fn conj(self) -> $t {
+                    self
+                }
+            }
+
+            impl Conj for Complex<$t> {
+                fn conj(self) -> Complex<$t> {
+                    Complex { re: self.re, im: -self.im }
+                }
+            }
+         )+
+    }
+}
+
+conj!(f32, f64);
+
+trait Reduce<T> where T: Element {
     type Output;
 
-    fn reduce(self) -> Self::Output;
+    fn reduce(self, backend: &Backend<T>) -> Self::Output;
 }
 
 /// y := alpha * x + y
-fn axpy_slice_scalar<T>(alpha: &T, x: &T, y: &mut [T]) where T: Axpy {
+fn axpy_slice_scalar<T>(backend: &Backend<T>, alpha: &T, x: &T, y: &mut [T]) where T: Axpy {
     unsafe {
-        let axpy = T::axpy();
         let incx = &0;
         let incy = &1;
 
@@ -57,21 +109,27 @@ fn axpy_slice_scalar<T>(alpha: &T, x: &T, y: &mut [T]) where T: Axpy {
         let offset = isize::from(i32::max_value());
 
         while n >= max {
-            axpy(&i32::max_value(), alpha, x, incx, y, incy);
+            backend.axpy(&i32::max_value(), alpha, x, incx, y, incy);
 
             y = y.offset(offset);
             n -= max;
         }
 
-        axpy(&i32::from(n).extract(), alpha, x, incx, y, incy)
+        backend.axpy(&i32::from(n).extract(), alpha, x, incx, y, incy)
     }
 }
 
 /// y := alpha * x + y
-unsafe fn axpy_slice_slice<T>(alpha: &T, x: &[T], y: &mut [T]) where T: Axpy {
+unsafe fn axpy_slice_slice<T>(
+    backend: &Backend<T>,
+    alpha: &T,
+    x: &[T],
+    y: &mut [T],
+) where
+    T: Axpy,
+{
     debug_assert_eq!(y.len(), x.len());
 
-    let axpy = T::axpy();
     let x = x.as_ptr();
     let incx = &1;
     let incy = &1;
@@ -83,34 +141,39 @@ unsafe fn axpy_slice_slice<T>(alpha: &T, x: &[T], y: &mut [T]) where T: Axpy {
     let offset = isize::from(i32::max_value());
 
     while n >= max {
-        axpy(&i32::max_value(), alpha, x, incx, y, incy);
+        backend.axpy(&i32::max_value(), alpha, x, incx, y, incy);
 
         y = y.offset(offset);
         n -= max;
     }
 
-    axpy(&i32::from(n).extract(), alpha, x, incx, y, incy)
+    backend.axpy(&i32::from(n).extract(), alpha, x, incx, y, incy)
 }
 
 /// y := alpha * x + y
-fn axpy_strided_scalar<T>(alpha: &T, x: &T, y: &mut Slice<T>) where T: Axpy {
+fn axpy_strided_scalar<T>(backend: &Backend<T>, alpha: &T, x: &T, y: &mut Slice<T>) where T: Axpy {
     unsafe {
-        let axpy = T::axpy();
         let n = &y.len;
         let incx = &0;
         let incy = &*y.stride;
 
         let y = *y.data;
 
-        axpy(n, alpha, x, incx, y, incy)
+        backend.axpy(n, alpha, x, incx, y, incy)
     }
 }
 
 /// y := alpha * x + y
-unsafe fn axpy_strided_strided<T>(alpha: &T, x: &Slice<T>, y: &mut Slice<T>) where T: Axpy {
+unsafe fn axpy_strided_strided<T>(
+    backend: &Backend<T>,
+    alpha: &T,
+    x: &Slice<T>,
+    y: &mut Slice<T>,
+) where
+    T: Axpy,
+{
     debug_assert_eq!(x.len, y.len);
 
-    let axpy = T::axpy();
     let n = &y.len;
     let incx = &*x.stride;
     let incy = &*y.stride;
@@ -118,28 +181,32 @@ unsafe fn axpy_strided_strided<T>(alpha: &T, x: &Slice<T>, y: &mut Slice<T>) whe
     let y = *y.data;
     let x = *x.data;
 
-    axpy(n, alpha, x, incx, y, incy)
+    backend.axpy(n, alpha, x, incx, y, incy)
 }
 
 /// y := x
-unsafe fn copy_strided<T>(input: &Slice<T>, output: &mut Slice<T>) where T: Copy {
+unsafe fn copy_strided<T>(
+    backend: &Backend<T>,
+    input: &Slice<T>,
+    output: &mut Slice<T>,
+) where
+    T: Copy,
+{
     debug_assert_eq!(input.len, output.len);
 
-    let copy = T::copy();
     let n = &input.len;
     let x = *input.data;
     let incx = &*input.stride;
     let y = *output.data;
     let incy = &*output.stride;
 
-    copy(n, x, incx, y, incy)
+    backend.copy(n, x, incx, y, incy)
 }
 
 /// y := x
-unsafe fn copy_slice<T>(input: &[T], output: &mut [T]) where T: Copy {
+unsafe fn copy_slice<T>(backend: &Backend<T>, input: &[T], output: &mut [T]) where T: Copy {
     debug_assert_eq!(input.len(), output.len());
 
-    let copy = T::copy();
     let mut n = input.len();
     let mut x = input.as_ptr();
     let incx = &1;
@@ -150,18 +217,51 @@ unsafe fn copy_slice<T>(input: &[T], output: &mut [T]) where T: Copy {
     let offset = isize::from(i32::max_value());
 
     while n >= max {
-        copy(&i32::max_value(), x, incx, y, incy);
+        backend.copy(&i32::max_value(), x, incx, y, incy);
 
         x = x.offset(offset);
         y = y.offset(offset);
         n -= max;
     }
 
-    copy(&i32::from(n).extract(), x, incx, y, incy);
+    backend.copy(&i32::from(n).extract(), x, incx, y, incy);
+}
+
+/// y := alpha * A * x + beta * y, where `A` is a band matrix
+unsafe fn gbmv<T>(
+    backend: &Backend<T>,
+    alpha: &T,
+    a: &BandMat<T>,
+    beta: &T,
+    x: Col<T>,
+    y: ColMut<T>,
+) where
+    T: Gbmv,
+{
+    debug_assert!(a.ncols() == x.nrows() && a.nrows() == y.nrows() && x.nrows() != 0);
+
+    let x = x.0;
+    let y = (y.0).0;
+
+    let ref trans = Transpose::No;
+    let n = &a.ab.ncols;
+    let kl = &a.kl;
+    let ku = &a.ku;
+    let lda = &a.ab.nrows;
+    let incx = &*x.stride;
+    let incy = &*y.stride;
+
+    // NB skip the `kl` rows of workspace used by `gbsv`
+    let a = a.ab.data.offset(isize::from(*kl));
+    let x = *x.data;
+    let y = *y.data;
+
+    backend.gbmv(trans, n, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy);
 }
 
 /// y := alpha * op(A) * x + beta * y
 unsafe fn gemv<T>(
+    backend: &Backend<T>,
     trans: &Transpose,
     alpha: &T,
     a: SubMat<T>,
@@ -175,7 +275,7 @@ unsafe fn gemv<T>(
         Transpose::No => {
             a.ncols() == x.nrows() && a.nrows() == y.nrows()
         },
-        Transpose::Yes => {
+        Transpose::Yes | Transpose::Conj => {
             a.nrows() == x.nrows() && a.ncols() == y.nrows()
         },
     } && x.nrows() != 0);
@@ -183,7 +283,6 @@ unsafe fn gemv<T>(
     let x = x.0;
     let y = (y.0).0;
 
-    let gemv = T::gemv();
     let m = &a.nrows;
     let n = &a.ncols;
     let lda = &a.stride;
@@ -194,11 +293,12 @@ unsafe fn gemv<T>(
     let x = *x.data;
     let y = *y.data;
 
-    gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy);
+    backend.gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy);
 }
 
 /// C := alpha * op(A) * op(B) + beta * C
 unsafe fn gemm<T>(
+    backend: &Backend<T>,
     transa: &Transpose,
     transb: &Transpose,
     alpha: &T,
@@ -218,19 +318,19 @@ unsafe fn gemm<T>(
                 a.nrows == c.nrows &&
                 b.ncols == c.ncols
         },
-        (Transpose::No, Transpose::Yes) => {
+        (Transpose::No, _) => {
             a.ncols == b.ncols &&
                 a.ncols != 0 &&
                 a.nrows == c.nrows &&
                 b.nrows == c.ncols
         },
-        (Transpose::Yes, Transpose::No) => {
+        (_, Transpose::No) => {
             a.nrows == b.nrows &&
                 a.nrows != 0 &&
                 a.ncols == c.nrows &&
                 b.ncols == c.ncols
         },
-        (Transpose::Yes, Transpose::Yes) => {
+        (_, _) => {
             a.nrows == b.ncols &&
                 a.nrows != 0 &&
                 a.ncols == c.nrows &&
@@ -238,14 +338,13 @@ unsafe fn gemm<T>(
         },
     });
 
-    let gemm = T::gemm();
     let (ref m, ref k) = match *transa {
         Transpose::No => (a.nrows, a.ncols),
-        Transpose::Yes => (a.ncols, a.nrows),
+        Transpose::Yes | Transpose::Conj => (a.ncols, a.nrows),
     };
     let ref n = match *transb {
         Transpose::No => b.ncols,
-        Transpose::Yes => b.nrows,
+        Transpose::Yes | Transpose::Conj => b.nrows,
     };
     let lda = &a.stride;
     let ldb = &b.stride;
@@ -255,13 +354,468 @@ unsafe fn gemm<T>(
     let b = *b.data;
     let c = *c.data;
 
-    gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+    backend.gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+/// A := alpha * x * y + A
+unsafe fn ger<T>(
+    backend: &Backend<T>,
+    alpha: &T,
+    x: Col<T>,
+    y: Row<T>,
+    a: SubMatMut<T>,
+) where
+    T: Ger,
+{
+    let x = x.0;
+    let y = y.0;
+    let a = a.0;
+
+    debug_assert!(a.nrows == x.len && a.ncols == y.len);
+
+    // NB BLAS rejects the leading dimension of empty matrices
+    if a.nrows == 0 || a.ncols == 0 {
+        return
+    }
+
+    let m = &a.nrows;
+    let n = &a.ncols;
+    let lda = &a.stride;
+    let incx = &*x.stride;
+    let incy = &*y.stride;
+
+    let a = *a.data;
+    let x = *x.data;
+    let y = *y.data;
+
+    backend.ger(m, n, alpha, x, incx, y, incy, a, lda);
+}
+
+/// Solves the least squares problem `min ||A * X - B||`, using the singular value decomposition
+/// of `A`
+///
+/// `B` must have `max(m, n)` rows, where `(m, n) = A.size()`. On exit, the first `n` rows of `B`
+/// contain the minimum norm solution, which is also well defined when `A` is rank deficient. `A`
+/// gets overwritten. The singular values below `eps * s[0]`, where `eps` is the machine precision,
+/// are treated as zero.
+///
+/// # Panics
+///
+/// If the singular value decomposition of `A` didn't converge
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn gelsd<T>(a: SubMatMut<T>, b: SubMatMut<T>) where T: Gelsd {
+    /// LAPACK's `smlsiz`: the maximum size of the subproblems at the bottom of the divide and
+    /// conquer tree
+    const SMLSIZ: i32 = 25;
+
+    let a = a.0;
+    let b = b.0;
+
+    debug_assert_eq!(b.nrows, cmp::max(a.nrows, a.ncols));
+
+    let backend = T::lapack_backend();
+    let ref m = a.nrows;
+    let ref n = a.ncols;
+    let ref nrhs = b.ncols;
+    let ref lda = cmp::max(1, a.stride);
+    let ref ldb = cmp::max(1, b.stride);
+    let ref rcond = T::rcond();
+    let ref mut rank = 0;
+    let ref mut info = 0;
+
+    // NB an upper bound of LAPACK's `nlvl = max(0, int(log2(mn / (smlsiz + 1))) + 1)`
+    let mn = cmp::min(*m, *n);
+    let mut nlvl = 1;
+    let mut k = mn;
+    while k > SMLSIZ {
+        k /= 2;
+        nlvl += 1;
+    }
+
+    // NB the minimum workspaces documented by LAPACK, the real and complex flavors each take the
+    // largest bound of either flavor
+    let smlsq = (SMLSIZ + 1) * (SMLSIZ + 1);
+    let wlalsd = 9 * mn + 2 * mn * SMLSIZ + 8 * mn * nlvl + mn * *nrhs + smlsq;
+    let ref lwork = 3 * mn + cmp::max(cmp::max(*m, *n), cmp::max(*nrhs, wlalsd));
+    let lrwork = 10 * mn + 2 * mn * SMLSIZ + 8 * mn * nlvl + 3 * SMLSIZ * *nrhs +
+        cmp::max(smlsq, mn * (1 + *nrhs) + 2 * *nrhs);
+    let liwork = cmp::max(1, 3 * mn * nlvl + 11 * mn);
+
+    let mut s = Vec::with_capacity(usize::from(mn).extract());
+    let mut work = Vec::with_capacity(usize::from(*lwork).extract());
+    let mut rwork = Vec::with_capacity(usize::from(lrwork).extract());
+    let mut iwork = Vec::with_capacity(usize::from(liwork).extract());
+
+    let s = s.as_mut_ptr();
+    let work = work.as_mut_ptr();
+    let rwork = rwork.as_mut_ptr();
+    let iwork = iwork.as_mut_ptr();
+
+    let a = *a.data;
+    let b = *b.data;
+
+    backend.gelsd(m, n, nrhs, a, lda, b, ldb, s, rcond, rank, work, lwork, rwork, iwork, info);
+
+    assert!(*info >= 0);
+    assert!(*info == 0, "SVD did not converge");
+}
+
+/// A := Q * R
+///
+/// Returns the scalar factors of the elementary reflectors that form `Q`
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn geqrf<T>(a: SubMatMut<T>) -> Vec<T> where T: Geqrf {
+    let a = a.0;
+
+    let backend = T::lapack_backend();
+    let ref m = a.nrows;
+    let ref n = a.ncols;
+    let ref lda = a.stride;
+    let k = usize::from(cmp::min(*m, *n)).extract();
+    let mut tau = Vec::with_capacity(k);
+    tau.set_len(k);
+    let ref lwork = cmp::max(1, *n);
+    let mut work = Vec::with_capacity(usize::from(*lwork).extract());
+    let ref mut info = 0;
+
+    backend.geqrf(m, n, *a.data, lda, tau.as_mut_ptr(), work.as_mut_ptr(), lwork, info);
+
+    assert!(*info == 0);
+
+    tau
+}
+
+/// A := Q, where the columns of `A` contain the elementary reflectors returned by `geqrf`
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn orgqr<T>(a: SubMatMut<T>, tau: &[T]) where T: Orgqr {
+    let a = a.0;
+
+    debug_assert!(a.nrows >= a.ncols);
+    debug_assert_eq!(usize::from(a.ncols).extract(), tau.len());
+
+    let backend = T::lapack_backend();
+    let ref m = a.nrows;
+    let ref n = a.ncols;
+    let ref k = a.ncols;
+    let ref lda = a.stride;
+    let ref lwork = if *n == 0 { 1 } else { *n };
+    let mut work = Vec::with_capacity(usize::from(*lwork).extract());
+    let ref mut info = 0;
+
+    backend.orgqr(m, n, k, *a.data, lda, tau.as_ptr(), work.as_mut_ptr(), lwork, info);
+
+    assert!(*info == 0);
+}
+
+/// C := op(Q) * C, where the columns of `A` contain the elementary reflectors returned by `geqrf`
+///
+/// Where `trans` is one of LAPACK's transpose specifiers: `b'N'`, `b'T'` (real) or `b'C'`
+/// (complex)
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn ormqr<T>(trans: &u8, a: SubMat<T>, tau: &[T], c: SubMatMut<T>) where T: Ormqr {
+    let c = c.0;
+
+    debug_assert_eq!(a.nrows, c.nrows);
+
+    let backend = T::lapack_backend();
+    let ref m = c.nrows;
+    let ref n = c.ncols;
+    let ref k = i32::from(tau.len()).unwrap();
+    let ref lda = a.stride;
+    let ref ldc = c.stride;
+    let ref lwork = if *n == 0 { 1 } else { *n };
+    let mut work = Vec::with_capacity(usize::from(*lwork).extract());
+    let work = work.as_mut_ptr();
+    let ref mut info = 0;
+
+    let a = *a.data;
+    let c = *c.data;
+
+    backend.ormqr(&b'L', trans, m, n, k, a, lda, tau.as_ptr(), c, ldc, work, lwork, info);
+
+    assert!(*info == 0);
+}
+
+/// B := A^-1 * B, where `A` is a band matrix
+///
+/// `A` is overwritten with its LU factorization. Returns LAPACK's `info`, `info > 0` indicates
+/// that `U[info - 1, info - 1]` is exactly zero, i.e. that `A` is singular.
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn gbsv<T>(a: &mut BandMat<T>, b: SubMatMut<T>) -> i32 where T: Gbsv {
+    debug_assert_eq!(a.nrows(), b.nrows());
+
+    let b = b.0;
+
+    let backend = T::lapack_backend();
+    let ref n = a.ab.ncols;
+    let ref nrhs = b.ncols;
+    let ref ldab = a.ab.nrows;
+    let mut ipiv = Vec::with_capacity(usize::from(*n).extract());
+    ipiv.set_len(usize::from(*n).extract());
+    let ref ldb = b.stride;
+    let ref mut info = 0;
+
+    backend.gbsv(n, &a.kl, &a.ku, nrhs, *a.ab.data, ldab, ipiv.as_mut_ptr(), *b.data, ldb, info);
+
+    assert!(*info >= 0);
+
+    *info
+}
+
+/// B := A^-1 * B, where `A` is the tridiagonal matrix with sub-diagonal `dl`, diagonal `d` and
+/// super-diagonal `du`
+///
+/// The diagonals are overwritten with the LU factorization of `A`. Returns LAPACK's `info`,
+/// `info > 0` indicates that `U[info - 1, info - 1]` is exactly zero, i.e. that `A` is singular.
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn gtsv<T>(dl: &mut [T], d: &mut [T], du: &mut [T], b: SubMatMut<T>) -> i32 where
+    T: Gtsv,
+{
+    debug_assert_eq!(d.len(), usize::from(b.nrows()));
+    debug_assert_eq!(dl.len() + 1, d.len());
+    debug_assert_eq!(du.len() + 1, d.len());
+
+    let b = b.0;
+
+    let backend = T::lapack_backend();
+    let ref n = b.nrows;
+    let ref nrhs = b.ncols;
+    let ref ldb = b.stride;
+    let ref mut info = 0;
+
+    let (dl, d, du) = (dl.as_mut_ptr(), d.as_mut_ptr(), du.as_mut_ptr());
+
+    backend.gtsv(n, nrhs, dl, d, du, *b.data, ldb, info);
+
+    assert!(*info >= 0);
+
+    *info
+}
+
+/// Maps the `info` returned by a LAPACK LU factorization (`gbsv`, `getrf`, `gtsv`) to an error
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub fn check_info(info: i32) -> Result<(), Error> {
+    match info {
+        0 => Ok(()),
+        i if i > 0 => Err(Error::Singular { pivot: u32::from(i - 1).extract() }),
+        i => Err(Error::IllegalArgument { position: u32::from(-i).extract() }),
+    }
+}
+
+/// A := P * L * U
+///
+/// Returns the pivot indices and LAPACK's `info`. `info > 0` indicates that
+/// `U[info - 1, info - 1]` is exactly zero, i.e. that the matrix is singular. `info < 0` indicates
+/// that the `-info`-th argument had an illegal value.
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn getrf<T>(a: SubMatMut<T>) -> (Vec<i32>, i32) where T: Getrf {
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    let a = a.0;
+
+    let backend = T::lapack_backend();
+    let ref n = a.nrows;
+    let mut ipiv = Vec::with_capacity(usize::from(*n).extract());
+    ipiv.set_len(usize::from(*n).extract());
+    let ref lda = a.stride;
+    let ref mut info = 0;
+
+    backend.getrf(n, n, *a.data, lda, ipiv.as_mut_ptr(), info);
+
+    (ipiv, *info)
+}
+
+/// A := A^-1, where `A` has been LU factorized by `getrf`
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn getri<T>(a: SubMatMut<T>, ipiv: &[i32]) where T: Getri {
+    debug_assert_eq!(a.nrows(), a.ncols());
+    debug_assert_eq!(a.nrows(), u32::from(ipiv.len()).extract());
+
+    let a = a.0;
+
+    let backend = T::lapack_backend();
+    let ref n = a.nrows;
+    let ref lda = a.stride;
+    let lwork = n;
+    let mut work = Vec::with_capacity(usize::from(*lwork).extract());
+    let ref mut info = 0;
+
+    backend.getri(n, *a.data, lda, ipiv.as_ptr(), work.as_mut_ptr(), lwork, info);
+
+    assert!(*info == 0);
+}
+
+/// B := op(A)^-1 * B, where `A` has been LU factorized by `getrf`
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn getrs<T>(trans: &Transpose, a: SubMat<T>, ipiv: &[i32], b: SubMatMut<T>) where
+    T: Getrs,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+    debug_assert_eq!(a.nrows(), b.nrows());
+    debug_assert_eq!(a.nrows(), u32::from(ipiv.len()).extract());
+
+    let b = b.0;
+
+    let backend = T::lapack_backend();
+    let ref trans = match *trans {
+        Transpose::No => b'N',
+        Transpose::Yes => b'T',
+        Transpose::Conj => b'C',
+    };
+    let ref n = a.nrows;
+    let ref nrhs = b.ncols;
+    let ref lda = a.stride;
+    let ref ldb = b.stride;
+    let ref mut info = 0;
+
+    backend.getrs(trans, n, nrhs, *a.data, lda, ipiv.as_ptr(), *b.data, ldb, info);
+
+    assert!(*info == 0);
+}
+
+/// Returns an estimate of the reciprocal condition number of `A`, where `A` has been LU factorized
+/// by `getrf`
+///
+/// Where `norm` is `b'1'` (one norm) or `b'I'` (infinity norm), and `anorm` is that norm of the
+/// original matrix
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn gecon<T>(norm: &u8, a: SubMat<T>, anorm: &T::Real) -> T::Real where
+    T: Gecon,
+    T::Real: Zero,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    let backend = T::lapack_backend();
+    let ref n = a.nrows;
+    let ref lda = a.stride;
+    let n_ = usize::from(*n).extract();
+    let mut rcond = Zero::zero();
+    let mut work = Vec::with_capacity(4 * n_);
+    let mut rwork = Vec::with_capacity(2 * n_);
+    let mut iwork = Vec::with_capacity(n_);
+    let ref mut info = 0;
+
+    let work = work.as_mut_ptr();
+    let rwork = rwork.as_mut_ptr();
+    let iwork = iwork.as_mut_ptr();
+
+    backend.gecon(norm, n, *a.data, lda, anorm, &mut rcond, work, rwork, iwork, info);
+
+    assert!(*info == 0);
+
+    rcond
+}
+
+/// Returns the `norm` of `A`
+///
+/// Where `norm` is one of LAPACK's norm specifiers:
+///
+/// - `b'M'`: max-abs
+/// - `b'1'`: one norm
+/// - `b'I'`: infinity norm
+/// - `b'F'`: Frobenius norm
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn lange<T>(norm: &u8, a: SubMat<T>) -> T::Real where T: Lange {
+    let backend = T::lapack_backend();
+    let ref m = a.nrows;
+    let ref n = a.ncols;
+    let ref lda = a.stride;
+
+    // NB `work` is only referenced when computing the infinity norm
+    let mut work = Vec::with_capacity(usize::from(*m).extract());
+
+    backend.lange(norm, m, n, *a.data, lda, work.as_mut_ptr())
+}
+
+/// A := L * L'
+///
+/// Only the lower triangle of `A` is referenced and overwritten. Returns LAPACK's `info`,
+/// `info > 0` indicates that the leading minor of order `info` is not positive definite.
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn potrf<T>(a: SubMatMut<T>) -> i32 where T: Potrf {
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    let a = a.0;
+
+    let backend = T::lapack_backend();
+    let ref n = a.nrows;
+    let ref lda = a.stride;
+    let ref mut info = 0;
+
+    backend.potrf(&b'L', n, *a.data, lda, info);
+
+    assert!(*info >= 0);
+
+    *info
+}
+
+/// A := A^-1, where `A` has been Cholesky factorized by `potrf`
+///
+/// NOTE Only the lower triangle of the inverse is computed
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn potri<T>(a: SubMatMut<T>) where T: Potri {
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    let a = a.0;
+
+    let backend = T::lapack_backend();
+    let ref n = a.nrows;
+    let ref lda = a.stride;
+    let ref mut info = 0;
+
+    backend.potri(&b'L', n, *a.data, lda, info);
+
+    assert!(*info == 0);
+}
+
+/// B := A^-1 * B, where `A` has been Cholesky factorized by `potrf`
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn potrs<T>(a: SubMat<T>, b: SubMatMut<T>) where T: Potrs {
+    debug_assert_eq!(a.nrows(), a.ncols());
+    debug_assert_eq!(a.nrows(), b.nrows());
+
+    let b = b.0;
+
+    let backend = T::lapack_backend();
+    let ref n = a.nrows;
+    let ref nrhs = b.ncols;
+    let ref lda = a.stride;
+    let ref ldb = b.stride;
+    let ref mut info = 0;
+
+    backend.potrs(&b'L', n, nrhs, *a.data, lda, *b.data, ldb, info);
+
+    assert!(*info == 0);
+}
+
+/// B := A^-1 * B, where `A` is upper triangular
+///
+/// Returns LAPACK's `info`, `info > 0` indicates that `A[info - 1, info - 1]` is exactly zero,
+/// i.e. that `A` is singular
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub unsafe fn trtrs<T>(a: SubMat<T>, b: SubMatMut<T>) -> i32 where T: Trtrs {
+    debug_assert_eq!(a.nrows(), a.ncols());
+    debug_assert_eq!(a.nrows(), b.nrows());
+
+    let b = b.0;
+
+    let backend = T::lapack_backend();
+    let ref n = a.nrows;
+    let ref nrhs = b.ncols;
+    let ref lda = a.stride;
+    let ref ldb = b.stride;
+    let ref mut info = 0;
+
+    backend.trtrs(&b'U', &b'N', &b'N', n, nrhs, *a.data, lda, *b.data, ldb, info);
+
+    assert!(*info >= 0);
+
+    *info
 }
 
 /// x := alpha * x
-fn scal_slice<A, T>(alpha: &A, x: &mut [T]) where T: Scal<A> {
+fn scal_slice<A, T>(backend: &Backend<T>, alpha: &A, x: &mut [T]) where T: Scal<A> {
     unsafe {
-        let scal = T::scal();
         let mut n = x.len();
         let mut x = x.as_mut_ptr();
         let ref incx = 1;
@@ -270,30 +824,318 @@ fn scal_slice<A, T>(alpha: &A, x: &mut [T]) where T: Scal<A> {
         let offset = isize::from(i32::max_value());
 
         while n >= max {
-            scal(&i32::max_value(), alpha, x, incx);
+            T::scal(backend, &i32::max_value(), alpha, x, incx);
 
             x = x.offset(offset);
             n -= max;
         }
 
-        scal(&i32::from(n).extract(), alpha, x, incx);
+        T::scal(backend, &i32::from(n).extract(), alpha, x, incx);
     }
 }
 
 /// x := alpha * x
-unsafe fn scal_strided<A, T>(alpha: &A, x: &mut Slice<T>) where
+unsafe fn scal_strided<A, T>(backend: &Backend<T>, alpha: &A, x: &mut Slice<T>) where
     T: Scal<A>,
 {
-    let scal = T::scal();
     let ref incx = *x.stride;
     let ref n = x.len;
     let x = *x.data;
 
-    scal(n, alpha, x, incx);
+    T::scal(backend, n, alpha, x, incx);
+}
+
+/// C := alpha * A * B + beta * C, where `A` is sparse
+fn spmm<T>(alpha: &T, a: &SparseMat<T>, beta: &T, b: SubMat<T>, mut c: SubMatMut<T>) where
+    T: Scalar,
+{
+    debug_assert!(a.ncols == b.nrows() && a.nrows == c.nrows() && b.ncols() == c.ncols());
+
+    for j in 0..b.ncols() {
+        spmv(alpha, a, beta, b.col(j), c.col_mut(j))
+    }
+}
+
+/// y := alpha * A * x + beta * y, where `A` is sparse
+///
+/// NB The `Backend` doesn't provide sparse routines, this kernel is always native Rust
+fn spmv<T>(alpha: &T, a: &SparseMat<T>, beta: &T, x: Col<T>, mut y: ColMut<T>) where
+    T: Scalar,
+{
+    debug_assert!(a.ncols == x.nrows() && a.nrows == y.nrows());
+
+    let alpha = *alpha;
+    let beta = *beta;
+
+    // y := beta * y
+    if beta != T::one() {
+        for y in y.iter_mut() {
+            // NB `y` may be uninitialized when `beta == 0`
+            *y = if beta == T::zero() { T::zero() } else { beta * *y };
+        }
+    }
+
+    if alpha == T::zero() { return }
+
+    for (major, w) in (0..).zip(a.indptr.windows(2)) {
+        let (indices, data) = (&a.indices[w[0]..w[1]], &a.data[w[0]..w[1]]);
+
+        match a.format {
+            SparseFormat::Csc => {
+                let temp = alpha * x[major];
+
+                for (&i, &value) in indices.iter().zip(data) {
+                    y[i] = y[i] + value * temp;
+                }
+            },
+            SparseFormat::Csr => {
+                let temp = indices.iter().zip(data).fold(T::zero(), |temp, (&j, &value)| {
+                    temp + value * x[j]
+                });
+
+                y[major] = y[major] + alpha * temp;
+            },
+        }
+    }
+}
+
+/// C := alpha * A * B + beta * C, where `A` is symmetric
+unsafe fn symm<T>(
+    backend: &Backend<T>,
+    uplo: &Uplo,
+    alpha: &T,
+    a: SubMat<T>,
+    b: SubMat<T>,
+    beta: &T,
+    c: SubMatMut<T>,
+) where
+    T: Symm,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    let c = c.0;
+
+    debug_assert!(a.ncols == b.nrows && a.ncols != 0 && a.nrows == c.nrows && b.ncols == c.ncols);
+
+    let m = &c.nrows;
+    let n = &c.ncols;
+    let lda = &a.stride;
+    let ldb = &b.stride;
+    let ldc = &c.stride;
+
+    let a = *a.data;
+    let b = *b.data;
+    let c = *c.data;
+
+    backend.symm(&Side::Left, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+/// y := alpha * A * x + beta * y, where `A` is symmetric
+unsafe fn symv<T>(
+    backend: &Backend<T>,
+    uplo: &Uplo,
+    alpha: &T,
+    a: SubMat<T>,
+    beta: &T,
+    x: Col<T>,
+    y: ColMut<T>,
+) where
+    T: Symv,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    debug_assert!(a.ncols() == x.nrows() && a.nrows() == y.nrows() && x.nrows() != 0);
+
+    let x = x.0;
+    let y = (y.0).0;
+
+    let n = &a.nrows;
+    let lda = &a.stride;
+    let incx = &*x.stride;
+    let incy = &*y.stride;
+
+    let a = *a.data;
+    let x = *x.data;
+    let y = *y.data;
+
+    backend.symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy);
+}
+
+/// C := alpha * op(A) * op(A)'
+///
+/// NB `syrk` only computes the upper triangular part of the symmetric result, the lower part is
+/// filled by copying the rows of the upper part into the columns of the lower part
+unsafe fn syrk<T>(
+    backend: &Backend<T>,
+    trans: &Transpose,
+    alpha: &T,
+    a: SubMat<T>,
+    c: SubMatMut<T>,
+) where
+    T: Copy + Syrk + Zero,
+{
+    debug_assert!(*trans != Transpose::Conj);
+
+    let c = c.0;
+
+    let (n, k) = match *trans {
+        Transpose::No => (a.nrows, a.ncols),
+        Transpose::Yes | Transpose::Conj => (a.ncols, a.nrows),
+    };
+
+    debug_assert!(c.nrows == n && c.ncols == n && k != 0);
+
+    // NB see `trmm`
+    if n == 0 { return }
+
+    let lda = &a.stride;
+    let ldc = &c.stride;
+    let ref beta = T::zero();
+    let stride = isize::from(c.stride);
+
+    let a = *a.data;
+    let c = *c.data;
+
+    backend.syrk(&Uplo::Upper, trans, &n, &k, alpha, a, lda, beta, c, ldc);
+
+    for j in 0..n - 1 {
+        // C[j+1.., j] := C[j, j+1..]
+        let ref len = n - 1 - j;
+        let j = isize::from(j);
+        let x = c.offset((j + 1) * stride + j);
+        let y = c.offset(j * stride + j + 1);
+
+        backend.copy(len, x, ldc, y, &1);
+    }
+}
+
+/// B := alpha * op(A) * B, where `A` is triangular
+unsafe fn trmm<T>(
+    backend: &Backend<T>,
+    uplo: &Uplo,
+    transa: &Transpose,
+    diag: &Diagonal,
+    alpha: &T,
+    a: SubMat<T>,
+    b: SubMatMut<T>,
+) where
+    T: Trmm,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    let b = b.0;
+
+    debug_assert_eq!(a.ncols(), b.nrows());
+
+    // NB BLAS rejects a zero leading dimension, even if there's nothing to do
+    if b.nrows == 0 || b.ncols == 0 { return }
+
+    let ref m = b.nrows;
+    let ref n = b.ncols;
+    let lda = &a.stride;
+    let ldb = &b.stride;
+
+    let a = *a.data;
+    let b = *b.data;
+
+    backend.trmm(&Side::Left, uplo, transa, diag, m, n, alpha, a, lda, b, ldb);
+}
+
+/// x := op(A) * x, where `A` is triangular
+unsafe fn trmv<T>(
+    backend: &Backend<T>,
+    uplo: &Uplo,
+    trans: &Transpose,
+    diag: &Diagonal,
+    a: SubMat<T>,
+    x: ColMut<T>,
+) where
+    T: Trmv,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    debug_assert_eq!(a.ncols(), x.nrows());
+
+    // NB see `trmm`
+    if a.nrows == 0 { return }
+
+    let x = (x.0).0;
+
+    let n = &a.nrows;
+    let lda = &a.stride;
+    let incx = &*x.stride;
+
+    let a = *a.data;
+    let x = *x.data;
+
+    backend.trmv(uplo, trans, diag, n, a, lda, x, incx);
+}
+
+/// B := alpha * op(A)^-1 * B, where `A` is triangular
+unsafe fn trsm<T>(
+    backend: &Backend<T>,
+    uplo: &Uplo,
+    transa: &Transpose,
+    diag: &Diagonal,
+    alpha: &T,
+    a: SubMat<T>,
+    b: SubMatMut<T>,
+) where
+    T: Trsm,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    let b = b.0;
+
+    debug_assert_eq!(a.ncols(), b.nrows());
+
+    // NB see `trmm`
+    if b.nrows == 0 || b.ncols == 0 { return }
+
+    let ref m = b.nrows;
+    let ref n = b.ncols;
+    let lda = &a.stride;
+    let ldb = &b.stride;
+
+    let a = *a.data;
+    let b = *b.data;
+
+    backend.trsm(&Side::Left, uplo, transa, diag, m, n, alpha, a, lda, b, ldb);
+}
+
+/// x := op(A)^-1 * x, where `A` is triangular
+unsafe fn trsv<T>(
+    backend: &Backend<T>,
+    uplo: &Uplo,
+    trans: &Transpose,
+    diag: &Diagonal,
+    a: SubMat<T>,
+    x: ColMut<T>,
+) where
+    T: Trsv,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+
+    debug_assert_eq!(a.ncols(), x.nrows());
+
+    // NB see `trmm`
+    if a.nrows == 0 { return }
+
+    let x = (x.0).0;
+
+    let n = &a.nrows;
+    let lda = &a.stride;
+    let incx = &*x.stride;
+
+    let a = *a.data;
+    let x = *x.data;
+
+    backend.trsv(uplo, trans, diag, n, a, lda, x, incx);
 }
 
 /// y := alpha * op(A) * x
 pub unsafe fn row_mul_submat<T>(
+    backend: &Backend<T>,
     transa: &Transpose,
     alpha: &T,
     a: SubMat<T>,
@@ -301,17 +1143,19 @@ pub unsafe fn row_mul_submat<T>(
 ) -> RowVec<T> where
     T: Gemv + Zero,
 {
+    // NB `x * A^H` would require the conjugate of `A`, which BLAS doesn't provide. Row vectors
+    // only multiply a `Chain`, which never contains a conjugate transpose
+    debug_assert!(*transa != Transpose::Conj);
+
     let x = x.t();
-    let ref transa = match *transa {
-        Transpose::No => Transpose::Yes,
-        Transpose::Yes => Transpose::No,
-    };
+    let ref transa = if *transa == Transpose::No { Transpose::Yes } else { Transpose::No };
 
-    submat_mul_col(transa, alpha, a, x).t()
+    submat_mul_col(backend, transa, alpha, a, x).t()
 }
 
 /// y := alpha * op(A) * op(B)
 pub unsafe fn submat_mul_submat<T>(
+    backend: &Backend<T>,
     transa: &Transpose,
     transb: &Transpose,
     alpha: &T,
@@ -322,18 +1166,19 @@ pub unsafe fn submat_mul_submat<T>(
 {
     let mut c = Mat::uninitialized(match (*transa, *transb) {
         (Transpose::No, Transpose::No) => (a.nrows, b.ncols),
-        (Transpose::No, Transpose::Yes) => (a.nrows, b.nrows),
-        (Transpose::Yes, Transpose::No) => (a.ncols, b.ncols),
-        (Transpose::Yes, Transpose::Yes) => (a.ncols, b.nrows),
+        (Transpose::No, _) => (a.nrows, b.nrows),
+        (_, Transpose::No) => (a.ncols, b.ncols),
+        (_, _) => (a.ncols, b.nrows),
     });
 
-    gemm(transa, transb, alpha, a, b, &T::zero(), c.slice_mut(..));
+    gemm(backend, transa, transb, alpha, a, b, &T::zero(), c.slice_mut(..));
 
     c
 }
 
 /// y := alpha * op(A) * x
 pub unsafe fn submat_mul_col<T>(
+    backend: &Backend<T>,
     transa: &Transpose,
     alpha: &T,
     a: SubMat<T>,
@@ -343,10 +1188,10 @@ pub unsafe fn submat_mul_col<T>(
 {
     let mut c = ColVec(Tor::uninitialized(match *transa {
         Transpose::No => a.nrows,
-        Transpose::Yes => a.ncols,
+        Transpose::Yes | Transpose::Conj => a.ncols,
     }));
 
-    gemv(transa, alpha, a, &T::zero(), x, c.slice_mut(..));
+    gemv(backend, transa, alpha, a, &T::zero(), x, c.slice_mut(..));
 
     c
 }
@@ -28,14 +28,14 @@ impl<'a, 'b, T> Mul<Col<'a, T>> for Row<'b, T> where T: Dot {
         unsafe {
             assert_eq_inner_dimensions!(self, rhs);
 
-            let dot = T::dot();
+            let backend = T::backend();
             let n = &self.0.len;
             let x = *self.0.data;
             let incx = &*self.0.stride;
             let y = *rhs.0.data;
             let incy = &*rhs.0.stride;
 
-            dot(n, x, incx, y, incy)
+            backend.dot(n, x, incx, y, incy)
         }
     }
 }
@@ -46,6 +46,8 @@ impl<'a, 'b, 'c, T> Mul<Product<Chain<'a, T>, Col<'b, T>>> for Row<'c, T> where
     type Output = T;
 
     fn mul(self, rhs: Product<Chain<T>, Col<T>>) -> T {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::RowMulCol::*;
 
@@ -54,7 +56,7 @@ impl<'a, 'b, 'c, T> Mul<Product<Chain<'a, T>, Col<'b, T>>> for Row<'c, T> where
             let row = self;
             let Product(chain, col) = rhs;
 
-            let a_mul_b = (row, chain, col).reduce();
+            let a_mul_b = (row, chain, col).reduce(backend);
 
             let (row, col) = match a_mul_b {
                 RV_C(ref lhs, rhs) => (lhs.slice(..), rhs),
@@ -62,14 +64,13 @@ impl<'a, 'b, 'c, T> Mul<Product<Chain<'a, T>, Col<'b, T>>> for Row<'c, T> where
                 R_CV(lhs, ref rhs) => (lhs, rhs.slice(..)),
             };
 
-            let dot = T::dot();
             let n = &row.0.len;
             let x = *row.0.data;
             let incx = &*row.0.stride;
             let y = *col.0.data;
             let incy = &*col.0.stride;
 
-            dot(n, x, incx, y, incy)
+            backend.dot(n, x, incx, y, incy)
         }
     }
 }
@@ -7,11 +7,13 @@ use {Col, ColMut, ColVec};
 
 impl<'a, T, A> MulAssign<A> for ColMut<'a, T> where T: Scal<A> {
     fn mul_assign(&mut self, alpha: A) {
+        let ref backend = T::backend();
+
         unsafe {
             let ColMut(Col(ref mut x)) = *self;
             let ref alpha = alpha;
 
-            ops::scal_strided(alpha, x)
+            ops::scal_strided(backend, alpha, x)
         }
     }
 }
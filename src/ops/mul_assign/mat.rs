@@ -5,7 +5,7 @@ use complex::Complex;
 
 use Forward;
 use ops;
-use traits::{IterMut, Matrix, MatrixColsMut, MatrixRowsMut, Slice, SliceMut, Transpose};
+use traits::{Element, IterMut, Matrix, MatrixColsMut, MatrixRowsMut, Slice, SliceMut, Transpose};
 use {Col, ColMut, Mat, RowMut, Row, Transposed, SubMat, SubMatMut};
 
 // NOTE Core
@@ -102,20 +102,22 @@ macro_rules! scale {
             // NOTE Core
             impl<'a> MulAssign<$rhs> for SubMatMut<'a, $lhs> {
                 fn mul_assign(&mut self, alpha: $rhs) {
+                    let ref backend = <$lhs as Element>::backend();
+
                     unsafe {
                         let ref alpha = alpha;
 
                         if let Some(x) = self.as_slice_mut() {
-                            return ops::scal_slice(alpha, x);
+                            return ops::scal_slice(backend, alpha, x);
                         }
 
                         if self.0.nrows < self.0.ncols {
                             for RowMut(Row(ref mut x)) in self.rows_mut() {
-                                ops::scal_strided(alpha, x);
+                                ops::scal_strided(backend, alpha, x);
                             }
                         } else {
                             for ColMut(Col(ref mut x)) in self.cols_mut() {
-                                ops::scal_strided(alpha, x);
+                                ops::scal_strided(backend, alpha, x);
                             }
                         }
                     }
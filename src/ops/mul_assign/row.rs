@@ -7,11 +7,13 @@ use {Row, RowMut, RowVec};
 
 impl<'a, T, A> MulAssign<A> for RowMut<'a, T> where T: Scal<A> {
     fn mul_assign(&mut self, alpha: A) {
+        let ref backend = T::backend();
+
         unsafe {
             let RowMut(Row(ref mut x)) = *self;
             let ref alpha = alpha;
 
-            ops::scal_strided(alpha, x)
+            ops::scal_strided(backend, alpha, x)
         }
     }
 }
@@ -7,27 +7,27 @@ use {Col, ColMut, ColVec};
 
 // NOTE Core
 impl<'a, T> Norm for Col<'a, T> where T: Nrm2 {
-    type Output = T::Output;
+    type Output = T::Real;
 
-    fn norm(&self) -> T::Output {
-        norm::strided(self.0)
+    fn norm(&self) -> T::Real {
+        norm::strided(&T::backend(), self.0)
     }
 }
 
 // NOTE Forward
 impl<'a, T> Norm for ColMut<'a, T> where T: Nrm2 {
-    type Output = T::Output;
+    type Output = T::Real;
 
-    fn norm(&self) -> T::Output {
+    fn norm(&self) -> T::Real {
         self.slice(..).norm()
     }
 }
 
 // NOTE Forward
 impl<T> Norm for ColVec<T> where T: Nrm2 {
-    type Output = T::Output;
+    type Output = T::Real;
 
-    fn norm(&self) -> T::Output {
+    fn norm(&self) -> T::Real {
         self.slice(..).norm()
     }
 }
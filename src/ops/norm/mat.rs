@@ -1,48 +1,140 @@
 use std::ops::Add;
 
 use blas::Nrm2;
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use complex::Complex;
 use onezero::Zero;
 
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+use ops;
 use ops::norm;
-use traits::{Norm, Slice};
-use {Mat, SubMat, SubMatMut, Transposed};
+use traits::{Element, MatrixCols, MatrixNorm, Norm, Slice};
+use {Mat, NormKind, SubMat, SubMatMut, Transposed};
 
 // NOTE Core
-impl<T, U> Norm for Mat<T> where T: Nrm2<Output=U>, U: Add<Output=U> + Zero {
-    type Output = U;
+impl<T> Norm for Mat<T> where T: Nrm2, T::Real: Add<Output=T::Real> + Zero {
+    type Output = T::Real;
 
-    fn norm(&self) -> U {
-        norm::slice(self.as_slice())
+    fn norm(&self) -> T::Real {
+        norm::slice(&T::backend(), self.as_slice())
     }
 }
 
 // NOTE Core
-impl<'a, T, U> Norm for SubMat<'a, T> where T: Nrm2<Output=U>, U: Add<Output=U> + Zero {
-    type Output = U;
+impl<'a, T> Norm for SubMat<'a, T> where
+    T: Nrm2,
+    T::Real: Add<Output=T::Real> + Element<Real=T::Real> + Nrm2 + Zero,
+{
+    type Output = T::Real;
+
+    fn norm(&self) -> T::Real {
+        let ref backend = T::backend();
 
-    fn norm(&self) -> U {
         if let Some(slice) = self.as_slice() {
-            norm::slice(slice)
+            norm::slice(backend, slice)
         } else {
-            unimplemented!();
+            // NB the columns are contiguous, and the Frobenius norm of the matrix is the
+            // euclidean norm of the norms of its columns
+            let norms: Vec<_> = self.cols().map(|col| norm::strided(backend, col.0)).collect();
+
+            norm::slice(&<T::Real as Element>::backend(), &norms)
         }
     }
 }
 
 // NOTE Secondary
-impl<T, U> Norm for Transposed<Mat<T>> where T: Nrm2<Output=U>, U: Add<Output=U> + Zero {
-    type Output = U;
+// Remember that the Frobenius norm is invariant under transposition
+impl<M> Norm for Transposed<M> where M: Norm {
+    type Output = M::Output;
 
-    fn norm(&self) -> U {
+    fn norm(&self) -> M::Output {
         self.0.norm()
     }
 }
 
 // NOTE Forward
-impl<'a, T, U> Norm for SubMatMut<'a, T> where T: Nrm2<Output=U>, U: Add<Output=U> + Zero {
-    type Output = U;
+impl<'a, T> Norm for SubMatMut<'a, T> where
+    T: Nrm2,
+    T::Real: Add<Output=T::Real> + Element<Real=T::Real> + Nrm2 + Zero,
+{
+    type Output = T::Real;
 
-    fn norm(&self) -> U {
+    fn norm(&self) -> T::Real {
         self.slice(..).norm()
     }
 }
+
+macro_rules! matrix_norm {
+    ($($t:ty => $real:ty),+,) => {
+        $(
+            // NOTE Core
+            #[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+            impl<'a> MatrixNorm for SubMat<'a, $t> {
+                type Output = $real;
+
+                fn matrix_norm(&self, kind: NormKind) -> $real {
+                    unsafe {
+                        match kind {
+                            NormKind::Frobenius => ops::lange(&b'F', *self),
+                            NormKind::Inf => ops::lange(&b'I', *self),
+                            NormKind::MaxAbs => ops::lange(&b'M', *self),
+                            NormKind::One => ops::lange(&b'1', *self),
+                            NormKind::Nuclear => {
+                                Mat::from(*self).singular_values().iter().fold(0., |x, y| x + *y)
+                            },
+                            NormKind::Spectral => {
+                                // NB singular values are sorted in descending order
+                                let s = Mat::from(*self).singular_values();
+
+                                s.iter().next().map(|x| *x).unwrap_or(0.)
+                            },
+                        }
+                    }
+                }
+            }
+
+            // NOTE Forward
+            #[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+            impl MatrixNorm for Mat<$t> {
+                type Output = $real;
+
+                fn matrix_norm(&self, kind: NormKind) -> $real {
+                    self.slice(..).matrix_norm(kind)
+                }
+            }
+
+            // NOTE Forward
+            #[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+            impl<'a> MatrixNorm for SubMatMut<'a, $t> {
+                type Output = $real;
+
+                fn matrix_norm(&self, kind: NormKind) -> $real {
+                    self.slice(..).matrix_norm(kind)
+                }
+            }
+         )+
+    }
+}
+
+// 12 impls
+matrix_norm! {
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => f32,
+    Complex<f64> => f64,
+}
+
+// NOTE Secondary
+// Remember that the one norm of `A'` is the infinity norm of `A`, and vice versa. The other norms
+// are invariant under transposition
+impl<M> MatrixNorm for Transposed<M> where M: MatrixNorm {
+    type Output = M::Output;
+
+    fn matrix_norm(&self, kind: NormKind) -> M::Output {
+        self.0.matrix_norm(match kind {
+            NormKind::Inf => NormKind::One,
+            NormKind::One => NormKind::Inf,
+            kind => kind,
+        })
+    }
+}
@@ -2,18 +2,17 @@ use blas::Nrm2;
 use cast::From;
 use extract::Extract;
 
+use traits::Backend;
 use Slice;
 
 mod col;
 mod mat;
 mod row;
 
-fn slice<T>(x: &[T]) -> T::Output where
+fn slice<T>(backend: &Backend<T>, x: &[T]) -> T::Real where
     T: Nrm2,
 {
     unsafe {
-        let nrm2 = T::nrm2();
-
         let mut n = x.len();
         let mut x = x.as_ptr();
 
@@ -22,26 +21,24 @@ fn slice<T>(x: &[T]) -> T::Output where
         let offset = isize::from(i32::max_value());
 
         while n >= max {
-            nrm2(&i32::max_value(), x, incx);
+            backend.nrm2(&i32::max_value(), x, incx);
 
             x = x.offset(offset);
             n -= max;
         }
 
-        nrm2(&i32::from(n).extract(), x, incx)
+        backend.nrm2(&i32::from(n).extract(), x, incx)
     }
 }
 
-fn strided<T>(x: Slice<T>) -> T::Output where
+fn strided<T>(backend: &Backend<T>, x: Slice<T>) -> T::Real where
     T: Nrm2,
 {
     unsafe {
-        let nrm2 = T::nrm2();
-
         let ref incx = *x.stride;
         let ref n = x.len;
         let x = *x.data;
 
-        nrm2(n, x, incx)
+        backend.nrm2(n, x, incx)
     }
 }
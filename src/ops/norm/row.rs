@@ -7,27 +7,27 @@ use {Row, RowMut, RowVec};
 
 // NOTE Core
 impl<'a, T> Norm for Row<'a, T> where T: Nrm2 {
-    type Output = T::Output;
+    type Output = T::Real;
 
-    fn norm(&self) -> T::Output {
-        norm::strided(self.0)
+    fn norm(&self) -> T::Real {
+        norm::strided(&T::backend(), self.0)
     }
 }
 
 // NOTE Forward
 impl<'a, T> Norm for RowMut<'a, T> where T: Nrm2 {
-    type Output = T::Output;
+    type Output = T::Real;
 
-    fn norm(&self) -> T::Output {
+    fn norm(&self) -> T::Real {
         self.slice(..).norm()
     }
 }
 
 // NOTE Forward
 impl<T> Norm for RowVec<T> where T: Nrm2 {
-    type Output = T::Output;
+    type Output = T::Real;
 
-    fn norm(&self) -> T::Output {
+    fn norm(&self) -> T::Real {
         self.slice(..).norm()
     }
 }
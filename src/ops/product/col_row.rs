@@ -0,0 +1,109 @@
+use std::ops::Mul;
+
+use Forward;
+use traits::Slice;
+use {Col, ColMut, ColVec, Product, Row, RowMut, RowVec, Scaled};
+
+// Combinations:
+//
+// LHS: Col, &ColMut, &ColVec, Scaled<Col>
+// RHS: Row, &RowMut, &RowVec, Scaled<Row>
+//
+// -> 16 implementations
+
+// 4 impls
+// Core implementations
+impl<'a, 'b, T> Mul<Row<'b, T>> for Col<'a, T> {
+    type Output = Product<Col<'a, T>, Row<'b, T>>;
+
+    fn mul(self, rhs: Row<'b, T>) -> Product<Col<'a, T>, Row<'b, T>> {
+        Product(self, rhs)
+    }
+}
+
+// Secondary implementations
+impl<'a, 'b, T> Mul<Scaled<Row<'b, T>>> for Col<'a, T> {
+    type Output = Scaled<Product<Col<'a, T>, Row<'b, T>>>;
+
+    fn mul(self, rhs: Scaled<Row<'b, T>>) -> Scaled<Product<Col<'a, T>, Row<'b, T>>> {
+        Scaled(rhs.0, self * rhs.1)
+    }
+}
+
+impl<'a, 'b, T> Mul<Row<'b, T>> for Scaled<Col<'a, T>> {
+    type Output = Scaled<Product<Col<'a, T>, Row<'b, T>>>;
+
+    fn mul(self, rhs: Row<'b, T>) -> Scaled<Product<Col<'a, T>, Row<'b, T>>> {
+        Scaled(self.0, self.1 * rhs)
+    }
+}
+
+impl<'a, 'b, T> Mul<Scaled<Row<'b, T>>> for Scaled<Col<'a, T>> where T: Mul<Output=T> {
+    type Output = Scaled<Product<Col<'a, T>, Row<'b, T>>>;
+
+    fn mul(self, rhs: Scaled<Row<'b, T>>) -> Scaled<Product<Col<'a, T>, Row<'b, T>>> {
+        Scaled(self.0 * rhs.0, self.1 * rhs.1)
+    }
+}
+
+macro_rules! forward {
+    ($lhs:ty { $($rhs:ty => $output:ty),+, }) => {
+        $(
+            impl<'a, 'b, 'c, 'd, T> Mul<$rhs> for $lhs {
+                type Output = $output;
+
+                fn mul(self, rhs: $rhs) -> $output {
+                    self.slice(..) * rhs.slice(..)
+                }
+            }
+         )+
+    }
+}
+
+// 2 impls
+forward!(Col<'a, T> {
+    &'b RowMut<'c, T>
+        => Product<Col<'a, T>, Row<'b, T>>,
+
+    &'b RowVec<T>
+        => Product<Col<'a, T>, Row<'b, T>>,
+});
+
+// 4 impls
+forward!(&'a ColMut<'b, T> {
+    Row<'c, T>
+        => Product<Col<'a, T>, Row<'c, T>>,
+
+    &'c RowMut<'d, T>
+        => Product<Col<'a, T>, Row<'c, T>>,
+
+    &'c RowVec<T>
+        => Product<Col<'a, T>, Row<'c, T>>,
+
+    Scaled<Row<'c, T>>
+        => Scaled<Product<Col<'a, T>, Row<'c, T>>>,
+});
+
+// 4 impls
+forward!(&'a ColVec<T> {
+    Row<'b, T>
+        => Product<Col<'a, T>, Row<'b, T>>,
+
+    &'b RowMut<'c, T>
+        => Product<Col<'a, T>, Row<'b, T>>,
+
+    &'b RowVec<T>
+        => Product<Col<'a, T>, Row<'b, T>>,
+
+    Scaled<Row<'b, T>>
+        => Scaled<Product<Col<'a, T>, Row<'b, T>>>,
+});
+
+// 2 impls
+forward!(Scaled<Col<'a, T>> {
+    &'b RowMut<'c, T>
+        => Scaled<Product<Col<'a, T>, Row<'b, T>>>,
+
+    &'b RowVec<T>
+        => Scaled<Product<Col<'a, T>, Row<'b, T>>>,
+});
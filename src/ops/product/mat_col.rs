@@ -2,7 +2,10 @@ use std::ops::Mul;
 
 use Forward;
 use traits::{Matrix, Slice};
-use {Chain, Col, ColMut, ColVec, Mat, Product, Scaled, Transposed, SubMat, SubMatMut};
+use {
+    BandMat, Chain, Col, ColMut, ColVec, ConjChain, ConjTransposed, LowerTri, Mat, Product, Scaled,
+    SparseMat, Symmetric, Transposed, SubMat, SubMatMut, UpperTri,
+};
 
 // Combinations:
 //
@@ -13,6 +16,11 @@ use {Chain, Col, ColMut, ColVec, Mat, Product, Scaled, Transposed, SubMat, SubMa
 //      Scaled<Product<Transposed<SubMat>, Col>>, Scaled<Product<SubMat, Col>>
 //
 // -> 100 implementations
+//
+// NB `ConjTransposed<SubMat>` can only be multiplied by a column vector: `A^H * x`. The same
+// goes for the products that contain a conjugate transpose, `ConjChain`, the triangular views,
+// `LowerTri<SubMat>` and `UpperTri<SubMat>`, the symmetric view, `Symmetric<SubMat>`, band
+// matrices, `&BandMat`, sparse matrices, `&SparseMat`, and their scaled versions
 
 macro_rules! mul {
     ($lhs:ty, $rhs:ty) => {
@@ -54,8 +62,15 @@ macro_rules! mul {
     };
 }
 
-// 12 impls
+// 40 impls
+mul!(&'a BandMat<T>, Col<'b, T>);
 mul!(Chain<'a, T>, Col<'b, T>);
+mul!(ConjChain<'a, T>, Col<'b, T>);
+mul!(ConjTransposed<SubMat<'a, T>>, Col<'b, T>);
+mul!(LowerTri<SubMat<'a, T>>, Col<'b, T>);
+mul!(&'a SparseMat<T>, Col<'b, T>);
+mul!(Symmetric<SubMat<'a, T>>, Col<'b, T>);
+mul!(UpperTri<SubMat<'a, T>>, Col<'b, T>);
 mul!(Transposed<SubMat<'a, T>>, Col<'b, T>);
 mul!(SubMat<'a, T>, Col<'b, T>);
 
@@ -132,6 +147,15 @@ macro_rules! forward {
     }
 }
 
+// 2 impls
+forward!(&'a BandMat<T> {
+    &'b ColMut<'c, T>
+        => Product<&'a BandMat<T>, Col<'b, T>>,
+
+    &'b ColVec<T>
+        => Product<&'a BandMat<T>, Col<'b, T>>,
+});
+
 // 2 impls
 forward!(Chain<'a, T> {
     &'b ColMut<'c, T>
@@ -141,6 +165,33 @@ forward!(Chain<'a, T> {
         => Product<Chain<'a, T>, Col<'b, T>>,
 });
 
+// 2 impls
+forward!(ConjChain<'a, T> {
+    &'b ColMut<'c, T>
+        => Product<ConjChain<'a, T>, Col<'b, T>>,
+
+    &'b ColVec<T>
+        => Product<ConjChain<'a, T>, Col<'b, T>>,
+});
+
+// 2 impls
+forward!(ConjTransposed<SubMat<'a, T>> {
+    &'b ColMut<'c, T>
+        => Product<ConjTransposed<SubMat<'a, T>>, Col<'b, T>>,
+
+    &'b ColVec<T>
+        => Product<ConjTransposed<SubMat<'a, T>>, Col<'b, T>>,
+});
+
+// 2 impls
+forward!(LowerTri<SubMat<'a, T>> {
+    &'b ColMut<'c, T>
+        => Product<LowerTri<SubMat<'a, T>>, Col<'b, T>>,
+
+    &'b ColVec<T>
+        => Product<LowerTri<SubMat<'a, T>>, Col<'b, T>>,
+});
+
 // 10 impls
 forward!(&'a Mat<T> {
     Col<'b, T>
@@ -174,6 +225,15 @@ forward!(&'a Mat<T> {
         => Scaled<Product<Chain<'a, T>, Col<'b, T>>>,
 });
 
+// 2 impls
+forward!(Scaled<&'a BandMat<T>> {
+    &'b ColMut<'c, T>
+        => Scaled<Product<&'a BandMat<T>, Col<'b, T>>>,
+
+    &'b ColVec<T>
+        => Scaled<Product<&'a BandMat<T>, Col<'b, T>>>,
+});
+
 // 2 impls
 forward!(Scaled<Chain<'a, T>> {
     &'b ColMut<'c, T>
@@ -183,6 +243,42 @@ forward!(Scaled<Chain<'a, T>> {
         => Scaled<Product<Chain<'a, T>, Col<'b, T>>>,
 });
 
+// 2 impls
+forward!(Scaled<ConjChain<'a, T>> {
+    &'b ColMut<'c, T>
+        => Scaled<Product<ConjChain<'a, T>, Col<'b, T>>>,
+
+    &'b ColVec<T>
+        => Scaled<Product<ConjChain<'a, T>, Col<'b, T>>>,
+});
+
+// 2 impls
+forward!(Scaled<LowerTri<SubMat<'a, T>>> {
+    &'b ColMut<'c, T>
+        => Scaled<Product<LowerTri<SubMat<'a, T>>, Col<'b, T>>>,
+
+    &'b ColVec<T>
+        => Scaled<Product<LowerTri<SubMat<'a, T>>, Col<'b, T>>>,
+});
+
+// 2 impls
+forward!(Scaled<&'a SparseMat<T>> {
+    &'b ColMut<'c, T>
+        => Scaled<Product<&'a SparseMat<T>, Col<'b, T>>>,
+
+    &'b ColVec<T>
+        => Scaled<Product<&'a SparseMat<T>, Col<'b, T>>>,
+});
+
+// 2 impls
+forward!(Scaled<Symmetric<SubMat<'a, T>>> {
+    &'b ColMut<'c, T>
+        => Scaled<Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>>,
+
+    &'b ColVec<T>
+        => Scaled<Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>>,
+});
+
 // 2 impls
 forward!(Scaled<Transposed<SubMat<'a, T>>> {
     &'b ColMut<'c, T>
@@ -201,6 +297,33 @@ forward!(Scaled<SubMat<'a, T>> {
         => Scaled<Product<SubMat<'a, T>, Col<'b, T>>>,
 });
 
+// 2 impls
+forward!(Scaled<UpperTri<SubMat<'a, T>>> {
+    &'b ColMut<'c, T>
+        => Scaled<Product<UpperTri<SubMat<'a, T>>, Col<'b, T>>>,
+
+    &'b ColVec<T>
+        => Scaled<Product<UpperTri<SubMat<'a, T>>, Col<'b, T>>>,
+});
+
+// 2 impls
+forward!(&'a SparseMat<T> {
+    &'b ColMut<'c, T>
+        => Product<&'a SparseMat<T>, Col<'b, T>>,
+
+    &'b ColVec<T>
+        => Product<&'a SparseMat<T>, Col<'b, T>>,
+});
+
+// 2 impls
+forward!(Symmetric<SubMat<'a, T>> {
+    &'b ColMut<'c, T>
+        => Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>,
+
+    &'b ColVec<T>
+        => Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>,
+});
+
 // 10 impls
 forward!(&'a Transposed<Mat<T>> {
     Col<'b, T>
@@ -317,3 +440,12 @@ forward!(&'a SubMatMut<'b, T> {
     Scaled<Product<SubMat<'a, T>, Col<'b, T>>>
         => Scaled<Product<Chain<'a, T>, Col<'b, T>>>,
 });
+
+// 2 impls
+forward!(UpperTri<SubMat<'a, T>> {
+    &'b ColMut<'c, T>
+        => Product<UpperTri<SubMat<'a, T>>, Col<'b, T>>,
+
+    &'b ColVec<T>
+        => Product<UpperTri<SubMat<'a, T>>, Col<'b, T>>,
+});
@@ -4,13 +4,22 @@ use blas::Transpose;
 
 use Forward;
 use traits::{Matrix, Slice};
-use {Chain, Mat, Scaled, Transposed, SubMat, SubMatMut};
+use {
+    Chain, ConjChain, ConjTransposed, LowerTri, Mat, Product, Scaled, SparseMat, Symmetric,
+    Transposed, SubMat, SubMatMut, UpperTri,
+};
 
-// LHS: Chain, &Mat, Scaled<Chain>, Scaled<Transposed<SubMat>>, Scaled<SubMat>, &Transposed<Mat>,
-// Transposed<SubMat>, &Transposed<SubMatMut>, SubMat, &SubMatMut
+// LHS: Chain, ConjChain, ConjTransposed<SubMat>, &Mat, Scaled<Chain>, Scaled<Transposed<SubMat>>,
+// Scaled<SubMat>, &Transposed<Mat>, Transposed<SubMat>, &Transposed<SubMatMut>, SubMat, &SubMatMut
 // RHS: Same as RHS
 //
-// -> 100 implementations
+// NB Any product that involves a `ConjTransposed<SubMat>` is a `ConjChain`, not a `Chain`.
+// `ConjChain` and `ConjTransposed<SubMat>` are only multiplied by the other matrix-like operands,
+// and `Scaled<ConjTransposed<SubMat>>` is not supported
+//
+// The triangular views, `LowerTri<SubMat>` and `UpperTri<SubMat>`, and the symmetric view,
+// `Symmetric<SubMat>`, don't join a `Chain`, they multiply a (scaled) `SubMat` from the left, and
+// produce a lazy `Product`. The same goes for sparse matrices, `&SparseMat`
 
 // 9 impls
 // Core implementations
@@ -150,6 +159,179 @@ impl<'a, T> Mul<SubMat<'a, T>> for SubMat<'a, T> {
     }
 }
 
+// 16 impls
+// Core implementations
+impl<'a, T> Mul<ConjChain<'a, T>> for ConjChain<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjChain<'a, T>) -> ConjChain<'a, T> {
+        ConjChain(self.0 * rhs.0)
+    }
+}
+
+impl<'a, T> Mul<Chain<'a, T>> for ConjChain<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: Chain<'a, T>) -> ConjChain<'a, T> {
+        ConjChain(self.0 * rhs)
+    }
+}
+
+impl<'a, T> Mul<ConjTransposed<SubMat<'a, T>>> for ConjChain<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjTransposed<SubMat<'a, T>>) -> ConjChain<'a, T> {
+        self.0 * rhs
+    }
+}
+
+impl<'a, T> Mul<Transposed<SubMat<'a, T>>> for ConjChain<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: Transposed<SubMat<'a, T>>) -> ConjChain<'a, T> {
+        ConjChain(self.0 * rhs)
+    }
+}
+
+impl<'a, T> Mul<SubMat<'a, T>> for ConjChain<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: SubMat<'a, T>) -> ConjChain<'a, T> {
+        ConjChain(self.0 * rhs)
+    }
+}
+
+impl<'a, T> Mul<ConjChain<'a, T>> for Chain<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjChain<'a, T>) -> ConjChain<'a, T> {
+        ConjChain(self * rhs.0)
+    }
+}
+
+impl<'a, T> Mul<ConjTransposed<SubMat<'a, T>>> for Chain<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(mut self, rhs: ConjTransposed<SubMat<'a, T>>) -> ConjChain<'a, T> {
+        assert_eq_inner_dimensions!(self, rhs);
+
+        self.tail.push((Transpose::Conj, rhs.0));
+        ConjChain(self)
+    }
+}
+
+impl<'a, T> Mul<ConjChain<'a, T>> for ConjTransposed<SubMat<'a, T>> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjChain<'a, T>) -> ConjChain<'a, T> {
+        self * rhs.0
+    }
+}
+
+impl<'a, T> Mul<Chain<'a, T>> for ConjTransposed<SubMat<'a, T>> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: Chain<'a, T>) -> ConjChain<'a, T> {
+        assert_eq_inner_dimensions!(self, rhs);
+
+        let Chain { first: was_first, second: was_second, mut tail } = rhs;
+
+        tail.insert(0, was_second);
+
+        ConjChain(Chain {
+            first: (Transpose::Conj, self.0),
+            second: was_first,
+            tail: tail,
+        })
+    }
+}
+
+impl<'a, T> Mul<ConjTransposed<SubMat<'a, T>>> for ConjTransposed<SubMat<'a, T>> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjTransposed<SubMat<'a, T>>) -> ConjChain<'a, T> {
+        assert_eq_inner_dimensions!(self, rhs);
+
+        ConjChain(Chain {
+            first: (Transpose::Conj, self.0),
+            second: (Transpose::Conj, rhs.0),
+            tail: vec![],
+        })
+    }
+}
+
+impl<'a, T> Mul<Transposed<SubMat<'a, T>>> for ConjTransposed<SubMat<'a, T>> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: Transposed<SubMat<'a, T>>) -> ConjChain<'a, T> {
+        assert_eq_inner_dimensions!(self, rhs);
+
+        ConjChain(Chain {
+            first: (Transpose::Conj, self.0),
+            second: (Transpose::Yes, rhs.0),
+            tail: vec![],
+        })
+    }
+}
+
+impl<'a, T> Mul<SubMat<'a, T>> for ConjTransposed<SubMat<'a, T>> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: SubMat<'a, T>) -> ConjChain<'a, T> {
+        assert_eq_inner_dimensions!(self, rhs);
+
+        ConjChain(Chain {
+            first: (Transpose::Conj, self.0),
+            second: (Transpose::No, rhs),
+            tail: vec![],
+        })
+    }
+}
+
+impl<'a, T> Mul<ConjChain<'a, T>> for Transposed<SubMat<'a, T>> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjChain<'a, T>) -> ConjChain<'a, T> {
+        ConjChain(self * rhs.0)
+    }
+}
+
+impl<'a, T> Mul<ConjTransposed<SubMat<'a, T>>> for Transposed<SubMat<'a, T>> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjTransposed<SubMat<'a, T>>) -> ConjChain<'a, T> {
+        assert_eq_inner_dimensions!(self, rhs);
+
+        ConjChain(Chain {
+            first: (Transpose::Yes, self.0),
+            second: (Transpose::Conj, rhs.0),
+            tail: vec![],
+        })
+    }
+}
+
+impl<'a, T> Mul<ConjChain<'a, T>> for SubMat<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjChain<'a, T>) -> ConjChain<'a, T> {
+        ConjChain(self * rhs.0)
+    }
+}
+
+impl<'a, T> Mul<ConjTransposed<SubMat<'a, T>>> for SubMat<'a, T> {
+    type Output = ConjChain<'a, T>;
+
+    fn mul(self, rhs: ConjTransposed<SubMat<'a, T>>) -> ConjChain<'a, T> {
+        assert_eq_inner_dimensions!(self, rhs);
+
+        ConjChain(Chain {
+            first: (Transpose::No, self),
+            second: (Transpose::Conj, rhs.0),
+            tail: vec![],
+        })
+    }
+}
+
 // Secondary implementations
 macro_rules! scaled {
     ($lhs:ty { $($rhs:ty),+ }) => {
@@ -190,6 +372,121 @@ scaled!(Transposed<SubMat<'a, T>> { Chain<'a, T>, Transposed<SubMat<'a, T>>, Sub
 // 9 impls
 scaled!(SubMat<'a, T> { Chain<'a, T>, Transposed<SubMat<'a, T>>, SubMat<'a, T> });
 
+macro_rules! scaled_rhs {
+    ($($lhs:ty),+) => {
+        $(
+            impl<'a, T> Mul<ConjTransposed<SubMat<'a, T>>> for Scaled<$lhs> {
+                type Output = Scaled<ConjChain<'a, T>>;
+
+                fn mul(self, rhs: ConjTransposed<SubMat<'a, T>>) -> Scaled<ConjChain<'a, T>> {
+                    Scaled(self.0, self.1 * rhs)
+                }
+            }
+         )+
+    }
+}
+
+// 3 impls
+scaled_rhs!(Chain<'a, T>, Transposed<SubMat<'a, T>>, SubMat<'a, T>);
+
+macro_rules! view {
+    ($($view:ident),+) => {
+        $(
+            // Core implementations
+            impl<'a, 'b, T> Mul<SubMat<'b, T>> for $view<SubMat<'a, T>> {
+                type Output = Product<$view<SubMat<'a, T>>, SubMat<'b, T>>;
+
+                fn mul(self, rhs: SubMat<'b, T>) -> Product<$view<SubMat<'a, T>>, SubMat<'b, T>> {
+                    assert_eq_inner_dimensions!(self, rhs);
+
+                    Product(self, rhs)
+                }
+            }
+
+            // Secondary implementations
+            impl<'a, 'b, T> Mul<Scaled<SubMat<'b, T>>> for $view<SubMat<'a, T>> {
+                type Output = Scaled<Product<$view<SubMat<'a, T>>, SubMat<'b, T>>>;
+
+                fn mul(
+                    self,
+                    rhs: Scaled<SubMat<'b, T>>,
+                ) -> Scaled<Product<$view<SubMat<'a, T>>, SubMat<'b, T>>> {
+                    Scaled(rhs.0, self * rhs.1)
+                }
+            }
+
+            impl<'a, 'b, T> Mul<SubMat<'b, T>> for Scaled<$view<SubMat<'a, T>>> {
+                type Output = Scaled<Product<$view<SubMat<'a, T>>, SubMat<'b, T>>>;
+
+                fn mul(
+                    self,
+                    rhs: SubMat<'b, T>,
+                ) -> Scaled<Product<$view<SubMat<'a, T>>, SubMat<'b, T>>> {
+                    Scaled(self.0, self.1 * rhs)
+                }
+            }
+
+            impl<'a, 'b, T> Mul<Scaled<SubMat<'b, T>>> for Scaled<$view<SubMat<'a, T>>> where
+                T: Mul<Output=T>,
+            {
+                type Output = Scaled<Product<$view<SubMat<'a, T>>, SubMat<'b, T>>>;
+
+                fn mul(
+                    self,
+                    rhs: Scaled<SubMat<'b, T>>,
+                ) -> Scaled<Product<$view<SubMat<'a, T>>, SubMat<'b, T>>> {
+                    Scaled(self.0 * rhs.0, self.1 * rhs.1)
+                }
+            }
+         )+
+    }
+}
+
+// 12 impls
+view!(LowerTri, Symmetric, UpperTri);
+
+// 4 impls
+// Core implementations
+impl<'a, 'b, T> Mul<SubMat<'b, T>> for &'a SparseMat<T> {
+    type Output = Product<&'a SparseMat<T>, SubMat<'b, T>>;
+
+    fn mul(self, rhs: SubMat<'b, T>) -> Product<&'a SparseMat<T>, SubMat<'b, T>> {
+        assert_eq_inner_dimensions!(self, rhs);
+
+        Product(self, rhs)
+    }
+}
+
+// Secondary implementations
+impl<'a, 'b, T> Mul<Scaled<SubMat<'b, T>>> for &'a SparseMat<T> {
+    type Output = Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>>;
+
+    fn mul(self, rhs: Scaled<SubMat<'b, T>>) -> Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>> {
+        Scaled(rhs.0, self * rhs.1)
+    }
+}
+
+impl<'a, 'b, T> Mul<SubMat<'b, T>> for Scaled<&'a SparseMat<T>> {
+    type Output = Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>>;
+
+    fn mul(self, rhs: SubMat<'b, T>) -> Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>> {
+        Scaled(self.0, self.1 * rhs)
+    }
+}
+
+impl<'a, 'b, T> Mul<Scaled<SubMat<'b, T>>> for Scaled<&'a SparseMat<T>> where
+    T: Mul<Output=T>,
+{
+    type Output = Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>>;
+
+    fn mul(
+        self,
+        rhs: Scaled<SubMat<'b, T>>,
+    ) -> Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>> {
+        Scaled(self.0 * rhs.0, self.1 * rhs.1)
+    }
+}
+
 macro_rules! forward {
     ($lhs:ty { $($rhs:ty => $output:ty),+, }) => {
         $(
@@ -219,11 +516,32 @@ forward!(Chain<'a, T> {
         => Chain<'a, T>,
 });
 
-// 10 impls
+// 4 impls
+forward!(ConjChain<'a, T> {
+    &'a Mat<T>
+        => ConjChain<'a, T>,
+
+    &'a Transposed<Mat<T>>
+        => ConjChain<'a, T>,
+
+    &'a Transposed<SubMatMut<'b, T>>
+        => ConjChain<'a, T>,
+
+    &'a SubMatMut<'b, T>
+        => ConjChain<'a, T>,
+});
+
+// 12 impls
 forward!(&'a Mat<T> {
     Chain<'a, T>
         => Chain<'a, T>,
 
+    ConjChain<'a, T>
+        => ConjChain<'a, T>,
+
+    ConjTransposed<SubMat<'a, T>>
+        => ConjChain<'a, T>,
+
     &'a Mat<T>
         => Chain<'a, T>,
 
@@ -297,11 +615,17 @@ forward!(Scaled<SubMat<'a, T>> {
         => Scaled<Chain<'a, T>>,
 });
 
-// 10 impls
+// 12 impls
 forward!(&'a Transposed<Mat<T>> {
     Chain<'a, T>
         => Chain<'a, T>,
 
+    ConjChain<'a, T>
+        => ConjChain<'a, T>,
+
+    ConjTransposed<SubMat<'a, T>>
+        => ConjChain<'a, T>,
+
     &'a Mat<T>
         => Chain<'a, T>,
 
@@ -330,6 +654,21 @@ forward!(&'a Transposed<Mat<T>> {
         => Chain<'a, T>,
 });
 
+// 4 impls
+forward!(ConjTransposed<SubMat<'a, T>> {
+    &'a Mat<T>
+        => ConjChain<'a, T>,
+
+    &'a Transposed<Mat<T>>
+        => ConjChain<'a, T>,
+
+    &'a Transposed<SubMatMut<'b, T>>
+        => ConjChain<'a, T>,
+
+    &'a SubMatMut<'b, T>
+        => ConjChain<'a, T>,
+});
+
 // 4 impls
 forward!(Transposed<SubMat<'a, T>> {
     &'a Mat<T>
@@ -345,11 +684,17 @@ forward!(Transposed<SubMat<'a, T>> {
         => Chain<'a, T>,
 });
 
-// 10 impls
+// 12 impls
 forward!(&'a Transposed<SubMatMut<'b, T>> {
     Chain<'a, T>
         => Chain<'a, T>,
 
+    ConjChain<'a, T>
+        => ConjChain<'a, T>,
+
+    ConjTransposed<SubMat<'a, T>>
+        => ConjChain<'a, T>,
+
     &'a Mat<T>
         => Chain<'a, T>,
 
@@ -393,11 +738,17 @@ forward!(SubMat<'a, T> {
         => Chain<'a, T>,
 });
 
-// 10 impls
+// 12 impls
 forward!(&'a SubMatMut<'b, T> {
     Chain<'a, T>
         => Chain<'a, T>,
 
+    ConjChain<'a, T>
+        => ConjChain<'a, T>,
+
+    ConjTransposed<SubMat<'a, T>>
+        => ConjChain<'a, T>,
+
     &'a Mat<T>
         => Chain<'a, T>,
 
@@ -425,3 +776,75 @@ forward!(&'a SubMatMut<'b, T> {
     &'a SubMatMut<'b, T>
         => Chain<'a, T>,
 });
+
+// 2 impls
+forward!(LowerTri<SubMat<'a, T>> {
+    &'b Mat<T>
+        => Product<LowerTri<SubMat<'a, T>>, SubMat<'b, T>>,
+
+    &'b SubMatMut<'c, T>
+        => Product<LowerTri<SubMat<'a, T>>, SubMat<'b, T>>,
+});
+
+// 2 impls
+forward!(Scaled<LowerTri<SubMat<'a, T>>> {
+    &'b Mat<T>
+        => Scaled<Product<LowerTri<SubMat<'a, T>>, SubMat<'b, T>>>,
+
+    &'b SubMatMut<'c, T>
+        => Scaled<Product<LowerTri<SubMat<'a, T>>, SubMat<'b, T>>>,
+});
+
+// 2 impls
+forward!(Scaled<&'a SparseMat<T>> {
+    &'b Mat<T>
+        => Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>>,
+
+    &'b SubMatMut<'c, T>
+        => Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>>,
+});
+
+// 2 impls
+forward!(Scaled<Symmetric<SubMat<'a, T>>> {
+    &'b Mat<T>
+        => Scaled<Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>>,
+
+    &'b SubMatMut<'c, T>
+        => Scaled<Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>>,
+});
+
+// 2 impls
+forward!(Scaled<UpperTri<SubMat<'a, T>>> {
+    &'b Mat<T>
+        => Scaled<Product<UpperTri<SubMat<'a, T>>, SubMat<'b, T>>>,
+
+    &'b SubMatMut<'c, T>
+        => Scaled<Product<UpperTri<SubMat<'a, T>>, SubMat<'b, T>>>,
+});
+
+// 2 impls
+forward!(&'a SparseMat<T> {
+    &'b Mat<T>
+        => Product<&'a SparseMat<T>, SubMat<'b, T>>,
+
+    &'b SubMatMut<'c, T>
+        => Product<&'a SparseMat<T>, SubMat<'b, T>>,
+});
+
+// 2 impls
+forward!(Symmetric<SubMat<'a, T>> {
+    &'b Mat<T>
+        => Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>,
+
+    &'b SubMatMut<'c, T>
+        => Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>,
+});
+
+// 2 impls
+forward!(UpperTri<SubMat<'a, T>> {
+    &'b Mat<T>
+        => Product<UpperTri<SubMat<'a, T>>, SubMat<'b, T>>,
+
+    &'b SubMatMut<'c, T>
+        => Product<UpperTri<SubMat<'a, T>>, SubMat<'b, T>>,
+});
@@ -1,5 +1,6 @@
 #![doc(hidden)]
 
+mod col_row;
 mod mat_col;
 mod mat_mat;
 mod row_mat;
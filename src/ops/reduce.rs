@@ -8,7 +8,7 @@ use onezero::{One, Zero};
 use ops::Reduce;
 use ops::mcop::{Table, self};
 use ops;
-use traits::{Matrix, Slice};
+use traits::{Backend, Matrix, Slice};
 use {Chain, Col, ColVec, Mat, Product, Row, RowVec, SubMat};
 
 #[allow(non_camel_case_types)]
@@ -19,10 +19,10 @@ pub enum MatMulMat<'a, T> {
     SM_SM((Transpose, SubMat<'a, T>), (Transpose, SubMat<'a, T>))
 }
 
-impl<'a, T> Reduce for Chain<'a, T> where T: Gemm + One + Zero {
+impl<'a, T> Reduce<T> for Chain<'a, T> where T: Gemm + One + Zero {
     type Output = MatMulMat<'a, T>;
 
-    fn reduce(self) -> MatMulMat<'a, T> {
+    fn reduce(self, backend: &Backend<T>) -> MatMulMat<'a, T> {
         unsafe {
             use self::MatMulMat::*;
 
@@ -45,16 +45,42 @@ impl<'a, T> Reduce for Chain<'a, T> where T: Gemm + One + Zero {
             let i = split[0..n];
 
             if i == 1 {
-                SM_M(self.first, reduce_to_mat(&self, i..n, split))
+                SM_M(self.first, reduce_to_mat(backend, &self, i..n, split))
             } else if i == n - 1 {
-                M_SM(reduce_to_mat(&self, 0..i, split), *self.tail.get(i - 2).extract())
+                M_SM(reduce_to_mat(backend, &self, 0..i, split), *self.tail.get(i - 2).extract())
             } else {
-                M_M(reduce_to_mat(&self, 0..i, split), reduce_to_mat(&self, i..n, split))
+                let a = reduce_to_mat(backend, &self, 0..i, split);
+                let b = reduce_to_mat(backend, &self, i..n, split);
+
+                M_M(a, b)
             }
         }
     }
 }
 
+/// Returns `(op, A)` if `chain` is the product of a matrix by its own transpose, i.e.
+/// `op(A) * op(A)'`. The (symmetric) result of such product can be computed with `syrk`
+pub fn gram<'a, T>(chain: &Chain<'a, T>) -> Option<(Transpose, SubMat<'a, T>)> {
+    if !chain.tail.is_empty() {
+        return None
+    }
+
+    let (transa, a) = chain.first;
+    let (transb, b) = chain.second;
+
+    let same = *a.data == *b.data &&
+        a.nrows == b.nrows &&
+        a.ncols == b.ncols &&
+        a.stride == b.stride;
+
+    match (transa, transb) {
+        (Transpose::No, Transpose::Yes) | (Transpose::Yes, Transpose::No) if same => {
+            Some((transa, a))
+        },
+        _ => None,
+    }
+}
+
 #[allow(non_camel_case_types)]
 pub enum MatMulCol<'a, 'b, T> {
     M_C(Mat<T>, Col<'b, T>),
@@ -62,12 +88,12 @@ pub enum MatMulCol<'a, 'b, T> {
     SM_CV((Transpose, SubMat<'a, T>), ColVec<T>),
 }
 
-impl<'a, 'b, T> Reduce for Product<Chain<'a, T>, Col<'b, T>> where
+impl<'a, 'b, T> Reduce<T> for Product<Chain<'a, T>, Col<'b, T>> where
     T: Gemm + Gemv + One + Zero,
 {
     type Output = MatMulCol<'a, 'b, T>;
 
-    fn reduce(self) -> MatMulCol<'a, 'b, T> {
+    fn reduce(self, backend: &Backend<T>) -> MatMulCol<'a, 'b, T> {
         unsafe {
             use self::MatMulCol::*;
 
@@ -90,11 +116,14 @@ impl<'a, 'b, T> Reduce for Product<Chain<'a, T>, Col<'b, T>> where
             let i = split[0..n];
 
             if i == 1 {
-                SM_CV(chain.first, reduce_to_col(chain, col, i.., split))
+                SM_CV(chain.first, reduce_to_col(backend, chain, col, i.., split))
             } else if i == n - 1 {
-                M_C(reduce_to_mat(chain, 0..i, split), col)
+                M_C(reduce_to_mat(backend, chain, 0..i, split), col)
             } else {
-                M_CV(reduce_to_mat(chain, 0..i, split), reduce_to_col(chain, col, i.., split))
+                let a = reduce_to_mat(backend, chain, 0..i, split);
+                let x = reduce_to_col(backend, chain, col, i.., split);
+
+                M_CV(a, x)
             }
         }
     }
@@ -107,13 +136,14 @@ pub enum RowMulCol<'a, 'b, T> {
     R_CV(Row<'a, T>, ColVec<T>),
 }
 
-impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
+impl<'a, 'b, 'c, T> Reduce<T> for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
     T: Gemm + Gemv + One + Zero,
 {
     type Output = RowMulCol<'a, 'c, T>;
 
-    fn reduce(self) -> RowMulCol<'a, 'c, T> {
+    fn reduce(self, backend: &Backend<T>) -> RowMulCol<'a, 'c, T> {
         unsafe fn reduce_to_col<T>(
+            backend: &Backend<T>,
             chain: &Chain<T>,
             col: Col<T>,
             RangeFrom { start }: RangeFrom<usize>,
@@ -131,18 +161,18 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
 
             match (i == start + 1, i == end - 1) {
                 (false, false) => {
-                    let a = reduce_to_mat(chain, start..i, split);
+                    let a = reduce_to_mat(backend, chain, start..i, split);
                     let ref transa = Transpose::No;
-                    let x = reduce_to_col(chain, col, i.., split);
+                    let x = reduce_to_col(backend, chain, col, i.., split);
 
-                    ops::submat_mul_col(transa, alpha, a.slice(..), x.slice(..))
+                    ops::submat_mul_col(backend, transa, alpha, a.slice(..), x.slice(..))
                 },
                 (false, true) => {
-                    let a = reduce_to_mat(chain, start..i, split);
+                    let a = reduce_to_mat(backend, chain, start..i, split);
                     let ref transa = Transpose::No;
                     let x = col;
 
-                    ops::submat_mul_col(transa, alpha, a.slice(..), x)
+                    ops::submat_mul_col(backend, transa, alpha, a.slice(..), x)
                 },
                 (true, false) => {
                     let (ref transa, a) = if start == 0 {
@@ -156,9 +186,9 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
                         *chain.tail.get(start - 3).extract()
                     };
 
-                    let x = reduce_to_col(chain, col, i.., split);
+                    let x = reduce_to_col(backend, chain, col, i.., split);
 
-                    ops::submat_mul_col(transa, alpha, a, x.slice(..))
+                    ops::submat_mul_col(backend, transa, alpha, a, x.slice(..))
                 },
                 (true, true) => {
                     let x = col;
@@ -172,17 +202,18 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
                     } else if start == 2 {
                         let (ref transa, a) = chain.second;
 
-                        ops::submat_mul_col(transa, alpha, a, x)
+                        ops::submat_mul_col(backend, transa, alpha, a, x)
                     } else {
                         let &(ref transa, a) = chain.tail.get(start - 3).extract();
 
-                        ops::submat_mul_col(transa, alpha, a, x)
+                        ops::submat_mul_col(backend, transa, alpha, a, x)
                     }
                 },
             }
         }
 
         unsafe fn reduce_to_mat<T>(
+            backend: &Backend<T>,
             chain: &Chain<T>,
             Range{ start, end }: Range<usize>,
             split: &Table<usize>,
@@ -198,23 +229,25 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
 
             match (i == start + 1, i == end - 1) {
                 (false, false) => {
-                    let a = reduce_to_mat(chain, start..i, split);
-                    let b = reduce_to_mat(chain, i..end, split);
+                    let a = reduce_to_mat(backend, chain, start..i, split);
+                    let b = reduce_to_mat(backend, chain, i..end, split);
                     let ref transa = Transpose::No;
                     let ref transb = Transpose::No;
 
-                    ops::submat_mul_submat(transa, transb, alpha, a.slice(..), b.slice(..))
+                    let (a, b) = (a.slice(..), b.slice(..));
+
+                    ops::submat_mul_submat(backend, transa, transb, alpha, a, b)
                 },
                 (false, true) => {
-                    let a = reduce_to_mat(chain, start..i, split);
+                    let a = reduce_to_mat(backend, chain, start..i, split);
                     let ref transa = Transpose::No;
 
                     let (ref transb, b) = *chain.tail.get(i - 3).extract();
 
-                    ops::submat_mul_submat(transa, transb, alpha, a.slice(..), b)
+                    ops::submat_mul_submat(backend, transa, transb, alpha, a.slice(..), b)
                 },
                 (true, false) => {
-                    let b = reduce_to_mat(chain, i..end, split);
+                    let b = reduce_to_mat(backend, chain, i..end, split);
                     let ref transb = Transpose::No;
 
                     let (ref transa, a) = if start == 1 {
@@ -225,7 +258,7 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
                         *chain.tail.get(start - 3).extract()
                     };
 
-                    ops::submat_mul_submat(transa, transb, alpha, a, b.slice(..))
+                    ops::submat_mul_submat(backend, transa, transb, alpha, a, b.slice(..))
                 },
                 (true, true) => {
                     let ((ref transa, a), (ref transb, b)) = if start == 1 {
@@ -239,12 +272,13 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
                         )
                     };
 
-                    ops::submat_mul_submat(transa, transb, alpha, a, b)
+                    ops::submat_mul_submat(backend, transa, transb, alpha, a, b)
                 },
             }
         }
 
         unsafe fn reduce_to_row<T>(
+            backend: &Backend<T>,
             row: Row<T>,
             chain: &Chain<T>,
             RangeTo { end }: RangeTo<usize>,
@@ -262,14 +296,14 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
 
             match (i == start + 1, i == end - 1) {
                 (false, false) => {
-                    let x = reduce_to_row(row, chain, ..i, split);
-                    let a = reduce_to_mat(chain, i..end, split);
+                    let x = reduce_to_row(backend, row, chain, ..i, split);
+                    let a = reduce_to_mat(backend, chain, i..end, split);
                     let ref transa = Transpose::No;
 
-                    ops::row_mul_submat(transa, alpha, a.slice(..), x.slice(..))
+                    ops::row_mul_submat(backend, transa, alpha, a.slice(..), x.slice(..))
                 },
                 (false, true) => {
-                    let x = reduce_to_row(row, chain, ..i, split);
+                    let x = reduce_to_row(backend, row, chain, ..i, split);
 
                     let (ref transa, a) = if i == 0 {
                         // row
@@ -282,20 +316,20 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
                         *chain.tail.get(i - 3).extract()
                     };
 
-                    ops::row_mul_submat(transa, alpha, a, x.slice(..))
+                    ops::row_mul_submat(backend, transa, alpha, a, x.slice(..))
                 },
                 (true, false) => {
                     let x = row;
-                    let a = reduce_to_mat(chain, i..end, split);
+                    let a = reduce_to_mat(backend, chain, i..end, split);
                     let ref transa = Transpose::No;
 
-                    ops::row_mul_submat(transa, alpha, a.slice(..), x)
+                    ops::row_mul_submat(backend, transa, alpha, a.slice(..), x)
                 },
                 (true, true) => {
                     let x = row;
                     let (ref transa, a) = chain.first;
 
-                    ops::row_mul_submat(transa, alpha, a, x)
+                    ops::row_mul_submat(backend, transa, alpha, a, x)
                 },
             }
         }
@@ -325,17 +359,21 @@ impl<'a, 'b, 'c, T> Reduce for (Row<'a, T>, Chain<'b, T>, Col<'c, T>) where
             let i = split[0..n];
 
             if i == 1 {
-                R_CV(row, reduce_to_col(chain, col, i.., split))
+                R_CV(row, reduce_to_col(backend, chain, col, i.., split))
             } else if i == n - 1 {
-                RV_C(reduce_to_row(row, chain, ..i, split), col)
+                RV_C(reduce_to_row(backend, row, chain, ..i, split), col)
             } else {
-                RV_CV(reduce_to_row(row, chain, ..i, split), reduce_to_col(chain, col, i.., split))
+                let y = reduce_to_row(backend, row, chain, ..i, split);
+                let x = reduce_to_col(backend, chain, col, i.., split);
+
+                RV_CV(y, x)
             }
         }
     }
 }
 
 unsafe fn reduce_to_col<T>(
+    backend: &Backend<T>,
     chain: &Chain<T>,
     col: Col<T>,
     RangeFrom { start }: RangeFrom<usize>,
@@ -351,18 +389,18 @@ unsafe fn reduce_to_col<T>(
 
     match (i == start + 1, i == end -1) {
         (false, false) => {
-            let a = reduce_to_mat(chain, start..i, split);
+            let a = reduce_to_mat(backend, chain, start..i, split);
             let ref transa = Transpose::No;
-            let x = reduce_to_col(chain, col, i.., split);
+            let x = reduce_to_col(backend, chain, col, i.., split);
 
-            ops::submat_mul_col(transa, alpha, a.slice(..), x.slice(..))
+            ops::submat_mul_col(backend, transa, alpha, a.slice(..), x.slice(..))
         },
         (false, true) => {
-            let a = reduce_to_mat(chain, start..i, split);
+            let a = reduce_to_mat(backend, chain, start..i, split);
             let ref transa = Transpose::No;
             let x = col;
 
-            ops::submat_mul_col(transa, alpha, a.slice(..), x)
+            ops::submat_mul_col(backend, transa, alpha, a.slice(..), x)
         },
         (true, false) => {
             let (ref transa, a) = if start == 0 {
@@ -373,9 +411,9 @@ unsafe fn reduce_to_col<T>(
                 *chain.tail.get(start - 2).extract()
             };
 
-            let x = reduce_to_col(chain, col, i.., split);
+            let x = reduce_to_col(backend, chain, col, i.., split);
 
-            ops::submat_mul_col(transa, alpha, a, x.slice(..))
+            ops::submat_mul_col(backend, transa, alpha, a, x.slice(..))
         },
         (true, true) => {
             let x = col;
@@ -386,17 +424,18 @@ unsafe fn reduce_to_col<T>(
             } else if start == 1 {
                 let (ref transa, a) = chain.second;
 
-                ops::submat_mul_col(transa, alpha, a, x)
+                ops::submat_mul_col(backend, transa, alpha, a, x)
             } else {
                 let &(ref transa, a) = chain.tail.get(start - 2).extract();
 
-                ops::submat_mul_col(transa, alpha, a, x)
+                ops::submat_mul_col(backend, transa, alpha, a, x)
             }
         },
     }
 }
 
 unsafe fn reduce_to_mat<T>(
+    backend: &Backend<T>,
     chain: &Chain<T>,
     Range { start, end }: Range<usize>,
     split: &Table<usize>,
@@ -411,23 +450,23 @@ unsafe fn reduce_to_mat<T>(
 
     match (i == start + 1, i == end - 1) {
         (false, false) => {
-            let a = reduce_to_mat(chain, start..i, split);
-            let b = reduce_to_mat(chain, i..end, split);
+            let a = reduce_to_mat(backend, chain, start..i, split);
+            let b = reduce_to_mat(backend, chain, i..end, split);
             let ref transa = Transpose::No;
             let ref transb = Transpose::No;
 
-            ops::submat_mul_submat(transa, transb, alpha, a.slice(..), b.slice(..))
+            ops::submat_mul_submat(backend, transa, transb, alpha, a.slice(..), b.slice(..))
         },
         (false, true) => {
-            let a = reduce_to_mat(chain, start..i, split);
+            let a = reduce_to_mat(backend, chain, start..i, split);
             let ref transa = Transpose::No;
 
             let (ref transb, b) = *chain.tail.get(i - 2).extract();
 
-            ops::submat_mul_submat(transa, transb, alpha, a.slice(..), b)
+            ops::submat_mul_submat(backend, transa, transb, alpha, a.slice(..), b)
         },
         (true, false) => {
-            let b = reduce_to_mat(chain, i..end, split);
+            let b = reduce_to_mat(backend, chain, i..end, split);
             let ref transb = Transpose::No;
 
             let (ref transa, a) = if start == 0 {
@@ -438,7 +477,7 @@ unsafe fn reduce_to_mat<T>(
                 *chain.tail.get(start - 2).extract()
             };
 
-            ops::submat_mul_submat(transa, transb, alpha, a, b.slice(..))
+            ops::submat_mul_submat(backend, transa, transb, alpha, a, b.slice(..))
         },
         (true, true) => {
             let ((ref transa, a), (ref transb, b)) = if start == 0 {
@@ -449,7 +488,7 @@ unsafe fn reduce_to_mat<T>(
                 (*chain.tail.get(start - 2).extract(), *chain.tail.get(start - 1).extract())
             };
 
-            ops::submat_mul_submat(transa, transb, alpha, a, b)
+            ops::submat_mul_submat(backend, transa, transb, alpha, a, b)
         },
     }
 }
@@ -6,8 +6,8 @@ use complex::Complex;
 use onezero::One;
 
 use {
-    Chain, Col, ColMut, ColVec, Mat, Product, Row, RowMut, RowVec, Scaled, Transposed, SubMat,
-    SubMatMut,
+    BandMat, Chain, Col, ColMut, ColVec, LowerTri, Mat, Product, Row, RowMut, RowVec, Scaled,
+    SparseMat, Symmetric, Transposed, SubMat, SubMatMut, UpperTri,
 };
 use traits::{Matrix, Slice};
 
@@ -66,7 +66,10 @@ macro_rules! chain {
     };
 }
 
-chain!(f32, f64, Complex<f32>, Complex<f64>);
+chain! {
+    f32, f64, Complex<f32>, Complex<f64>,
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+}
 
 macro_rules! mul {
     ($ty:ident $ty_mut:ident $ty_owned:ident for $($t:ty),+) => {
@@ -141,9 +144,23 @@ macro_rules! mul {
     };
 }
 
-mul!(Col ColMut ColVec for f32, f64, Complex<f32>, Complex<f64>);
-mul!(Row RowMut RowVec for f32, f64, Complex<f32>, Complex<f64>);
-mul!(SubMat SubMatMut Mat for f32, f64, Complex<f32>, Complex<f64>);
+mul! {
+    Col ColMut ColVec for
+        f32, f64, Complex<f32>, Complex<f64>,
+        i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+}
+
+mul! {
+    Row RowMut RowVec for
+        f32, f64, Complex<f32>, Complex<f64>,
+        i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+}
+
+mul! {
+    SubMat SubMatMut Mat for
+        f32, f64, Complex<f32>, Complex<f64>,
+        i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+}
 
 impl<T, L, R> Mul<T> for Product<L, R> where
     L: Matrix<Elem=T>,
@@ -173,7 +190,10 @@ macro_rules! product {
     };
 }
 
-product!(f32, f64, Complex<f32>, Complex<f64>);
+product! {
+    f32, f64, Complex<f32>, Complex<f64>,
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+}
 
 impl<T, M> Mul<T> for Scaled<M> where
     M: Matrix<Elem=T>,
@@ -200,7 +220,10 @@ macro_rules! scaled {
     };
 }
 
-scaled!(f32, f64, Complex<f32>, Complex<f64>);
+scaled! {
+    f32, f64, Complex<f32>, Complex<f64>,
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+}
 
 impl<T> Mul<T> for Transposed<Mat<T>> {
     type Output = Scaled<Transposed<Mat<T>>>;
@@ -278,4 +301,101 @@ macro_rules! transposed {
     };
 }
 
-transposed!(f32, f64, Complex<f32>, Complex<f64>);
+transposed! {
+    f32, f64, Complex<f32>, Complex<f64>,
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+}
+
+impl<'a, T> Mul<T> for &'a BandMat<T> {
+    type Output = Scaled<&'a BandMat<T>>;
+
+    fn mul(self, rhs: T) -> Scaled<&'a BandMat<T>> {
+        Scaled(rhs, self)
+    }
+}
+
+impl<'a, T> Mul<T> for LowerTri<SubMat<'a, T>> {
+    type Output = Scaled<LowerTri<SubMat<'a, T>>>;
+
+    fn mul(self, rhs: T) -> Scaled<LowerTri<SubMat<'a, T>>> {
+        Scaled(rhs, self)
+    }
+}
+
+impl<'a, T> Mul<T> for &'a SparseMat<T> {
+    type Output = Scaled<&'a SparseMat<T>>;
+
+    fn mul(self, rhs: T) -> Scaled<&'a SparseMat<T>> {
+        Scaled(rhs, self)
+    }
+}
+
+impl<'a, T> Mul<T> for Symmetric<SubMat<'a, T>> {
+    type Output = Scaled<Symmetric<SubMat<'a, T>>>;
+
+    fn mul(self, rhs: T) -> Scaled<Symmetric<SubMat<'a, T>>> {
+        Scaled(rhs, self)
+    }
+}
+
+impl<'a, T> Mul<T> for UpperTri<SubMat<'a, T>> {
+    type Output = Scaled<UpperTri<SubMat<'a, T>>>;
+
+    fn mul(self, rhs: T) -> Scaled<UpperTri<SubMat<'a, T>>> {
+        Scaled(rhs, self)
+    }
+}
+
+macro_rules! view {
+    ($($t:ty),+) => {
+        $(
+            impl<'a> Mul<&'a BandMat<$t>> for $t {
+                type Output = Scaled<&'a BandMat<$t>>;
+
+                fn mul(self, rhs: &'a BandMat<$t>) -> Scaled<&'a BandMat<$t>> {
+                    rhs * self
+                }
+            }
+
+            impl<'a> Mul<LowerTri<SubMat<'a, $t>>> for $t {
+                type Output = Scaled<LowerTri<SubMat<'a, $t>>>;
+
+                fn mul(self, rhs: LowerTri<SubMat<'a, $t>>) -> Scaled<LowerTri<SubMat<'a, $t>>> {
+                    rhs * self
+                }
+            }
+
+            impl<'a> Mul<&'a SparseMat<$t>> for $t {
+                type Output = Scaled<&'a SparseMat<$t>>;
+
+                fn mul(self, rhs: &'a SparseMat<$t>) -> Scaled<&'a SparseMat<$t>> {
+                    rhs * self
+                }
+            }
+
+            impl<'a> Mul<Symmetric<SubMat<'a, $t>>> for $t {
+                type Output = Scaled<Symmetric<SubMat<'a, $t>>>;
+
+                fn mul(
+                    self,
+                    rhs: Symmetric<SubMat<'a, $t>>,
+                ) -> Scaled<Symmetric<SubMat<'a, $t>>> {
+                    rhs * self
+                }
+            }
+
+            impl<'a> Mul<UpperTri<SubMat<'a, $t>>> for $t {
+                type Output = Scaled<UpperTri<SubMat<'a, $t>>>;
+
+                fn mul(self, rhs: UpperTri<SubMat<'a, $t>>) -> Scaled<UpperTri<SubMat<'a, $t>>> {
+                    rhs * self
+                }
+            }
+         )+
+    };
+}
+
+view! {
+    f32, f64, Complex<f32>, Complex<f64>,
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+}
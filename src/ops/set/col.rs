@@ -1,31 +1,38 @@
-use blas::{Copy, Gemv, Transpose};
+use assign::MulAssign;
+use blas::{Copy, Gbmv, Gemv, Scal, Symv, Transpose, Trmv, Uplo};
 
 use Forward;
 use onezero::{One, Zero};
 use ops::{set, self};
-use traits::{Matrix, Set, Slice, SliceMut};
-use {ColMut, Col, ColVec, Product, Scaled, Transposed, SubMat};
+use traits::{Matrix, Scalar, Set, Slice, SliceMut};
+use {
+    BandMat, ColMut, Col, ColVec, LowerTri, Product, Scaled, SparseMat, Symmetric, Transposed,
+    SubMat, UpperTri,
+};
 
 // NOTE Core
 impl<'a, T> Set<T> for ColMut<'a, T> where T: Copy {
     fn set(&mut self, value: T) {
+        let ref backend = T::backend();
         let ColMut(Col(ref mut y)) = *self;
         let ref x = value;
 
-        set::strided(x, y)
+        set::strided(backend, x, y)
     }
 }
 
 // NOTE Core
 impl<'a, 'b, T> Set<Col<'a, T>> for ColMut<'b, T> where T: Copy {
     fn set(&mut self, rhs: Col<T>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq!(self.nrows(), rhs.nrows());
 
             let ColMut(Col(ref mut y)) = *self;
             let Col(ref x) = rhs;
 
-            ops::copy_strided(x, y)
+            ops::copy_strided(backend, x, y)
         }
     }
 }
@@ -35,6 +42,8 @@ impl<'a, 'b, 'c, T> Set<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for ColMut<'
     T: Gemv + Zero,
 {
     fn set(&mut self, rhs: Scaled<Product<SubMat<T>, Col<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq!(self.nrows(), rhs.nrows());
 
@@ -43,7 +52,7 @@ impl<'a, 'b, 'c, T> Set<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for ColMut<'
             let y = self.slice_mut(..);
             let ref beta = T::zero();
 
-            ops::gemv(trans, alpha, a, beta, x, y)
+            ops::gemv(backend, trans, alpha, a, beta, x, y)
         }
     }
 }
@@ -54,6 +63,8 @@ Set<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for ColMut<'c, T> wh
     T: Gemv + Zero,
 {
     fn set(&mut self, rhs: Scaled<Product<Transposed<SubMat<T>>, Col<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq!(self.nrows(), rhs.nrows());
 
@@ -62,7 +73,7 @@ Set<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for ColMut<'c, T> wh
             let y = self.slice_mut(..);
             let ref beta = T::zero();
 
-            ops::gemv(trans, alpha, a, beta, x, y)
+            ops::gemv(backend, trans, alpha, a, beta, x, y)
         }
     }
 }
@@ -99,6 +110,194 @@ impl<'a, T> Set<Col<'a, T>> for ColVec<T> where T: Copy {
     }
 }
 
+// NOTE Core
+impl<'a, 'b, 'c, T> Set<Scaled<Product<&'a BandMat<T>, Col<'b, T>>>> for ColMut<'c, T> where
+    T: Gbmv + Zero,
+{
+    fn set(&mut self, rhs: Scaled<Product<&BandMat<T>, Col<T>>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            assert_eq!(self.nrows(), rhs.nrows());
+
+            let Scaled(ref alpha, Product(a, x)) = rhs;
+
+            ops::gbmv(backend, alpha, a, &T::zero(), x, self.slice_mut(..))
+        }
+    }
+}
+
+// NOTE Secondary
+impl<'a, 'b, 'c, T> Set<Product<&'a BandMat<T>, Col<'b, T>>> for ColMut<'c, T> where
+    T: Gbmv + One + Zero,
+{
+    fn set(&mut self, rhs: Product<&BandMat<T>, Col<T>>) {
+        self.set(Scaled(T::one(), rhs))
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Product<&'a BandMat<T>, Col<'b, T>>> for ColVec<T> where
+    T: Gbmv + One + Zero,
+{
+    fn set(&mut self, rhs: Product<&BandMat<T>, Col<T>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Scaled<Product<&'a BandMat<T>, Col<'b, T>>>> for ColVec<T> where
+    T: Gbmv + Zero,
+{
+    fn set(&mut self, rhs: Scaled<Product<&BandMat<T>, Col<T>>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+// NOTE Core
+impl<'a, 'b, 'c, T> Set<Scaled<Product<&'a SparseMat<T>, Col<'b, T>>>> for ColMut<'c, T> where
+    T: Scalar,
+{
+    fn set(&mut self, rhs: Scaled<Product<&SparseMat<T>, Col<T>>>) {
+        assert_eq!(self.nrows(), rhs.nrows());
+
+        let Scaled(ref alpha, Product(a, x)) = rhs;
+
+        ops::spmv(alpha, a, &T::zero(), x, self.slice_mut(..))
+    }
+}
+
+// NOTE Secondary
+impl<'a, 'b, 'c, T> Set<Product<&'a SparseMat<T>, Col<'b, T>>> for ColMut<'c, T> where
+    T: Scalar,
+{
+    fn set(&mut self, rhs: Product<&SparseMat<T>, Col<T>>) {
+        self.set(Scaled(T::one(), rhs))
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Product<&'a SparseMat<T>, Col<'b, T>>> for ColVec<T> where T: Scalar {
+    fn set(&mut self, rhs: Product<&SparseMat<T>, Col<T>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Scaled<Product<&'a SparseMat<T>, Col<'b, T>>>> for ColVec<T> where
+    T: Scalar,
+{
+    fn set(&mut self, rhs: Scaled<Product<&SparseMat<T>, Col<T>>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+// NOTE Core
+impl<'a, 'b, 'c, T>
+Set<Scaled<Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>>> for ColMut<'c, T> where
+    T: Symv + Zero,
+{
+    fn set(&mut self, rhs: Scaled<Product<Symmetric<SubMat<T>>, Col<T>>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            assert_eq!(self.nrows(), rhs.nrows());
+
+            let Scaled(ref alpha, Product(Symmetric(a, ref uplo), x)) = rhs;
+
+            ops::symv(backend, uplo, alpha, a, &T::zero(), x, self.slice_mut(..))
+        }
+    }
+}
+
+// NOTE Secondary
+impl<'a, 'b, 'c, T> Set<Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>> for ColMut<'c, T> where
+    T: One + Symv + Zero,
+{
+    fn set(&mut self, rhs: Product<Symmetric<SubMat<T>>, Col<T>>) {
+        self.set(Scaled(T::one(), rhs))
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>> for ColVec<T> where
+    T: One + Symv + Zero,
+{
+    fn set(&mut self, rhs: Product<Symmetric<SubMat<T>>, Col<T>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Scaled<Product<Symmetric<SubMat<'a, T>>, Col<'b, T>>>> for ColVec<T> where
+    T: Symv + Zero,
+{
+    fn set(&mut self, rhs: Scaled<Product<Symmetric<SubMat<T>>, Col<T>>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+macro_rules! tri {
+    ($($tri:ident => $uplo:expr),+) => {
+        $(
+            // NOTE Core
+            impl<'a, 'b, 'c, T>
+            Set<Product<$tri<SubMat<'a, T>>, Col<'b, T>>> for ColMut<'c, T> where
+                T: Copy + Trmv,
+            {
+                fn set(&mut self, rhs: Product<$tri<SubMat<T>>, Col<T>>) {
+                    let ref backend = T::backend();
+
+                    unsafe {
+                        assert_eq!(rhs.0.ncols(), rhs.1.nrows());
+
+                        let Product($tri(a, ref diag), x) = rhs;
+                        let ref trans = Transpose::No;
+
+                        self.set(x);
+
+                        ops::trmv(backend, $uplo, trans, diag, a, self.slice_mut(..))
+                    }
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, 'c, T>
+            Set<Scaled<Product<$tri<SubMat<'a, T>>, Col<'b, T>>>> for ColMut<'c, T> where
+                T: Copy + Scal<T> + Trmv,
+            {
+                fn set(&mut self, rhs: Scaled<Product<$tri<SubMat<T>>, Col<T>>>) {
+                    let Scaled(alpha, product) = rhs;
+
+                    self.set(product);
+                    self.mul_assign(alpha)
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, T> Set<Product<$tri<SubMat<'a, T>>, Col<'b, T>>> for ColVec<T> where
+                T: Copy + Trmv,
+            {
+                fn set(&mut self, rhs: Product<$tri<SubMat<T>>, Col<T>>) {
+                    self.slice_mut(..).set(rhs)
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, T>
+            Set<Scaled<Product<$tri<SubMat<'a, T>>, Col<'b, T>>>> for ColVec<T> where
+                T: Copy + Scal<T> + Trmv,
+            {
+                fn set(&mut self, rhs: Scaled<Product<$tri<SubMat<T>>, Col<T>>>) {
+                    self.slice_mut(..).set(rhs)
+                }
+            }
+         )+
+    }
+}
+
+tri!(LowerTri => &Uplo::Lower, UpperTri => &Uplo::Upper);
+
 macro_rules! forward {
     ($lhs:ty { $($rhs:ty { $($bound:ident),+ }),+, }) => {
         $(
@@ -6,9 +6,10 @@ use {DiagMut, Diag};
 
 impl<'a, T> Set<T> for DiagMut<'a, T> where T: Copy {
     fn set(&mut self, value: T) {
+        let ref backend = T::backend();
         let DiagMut(Diag(ref mut y)) = *self;
         let ref x = value;
 
-        set::strided(x, y)
+        set::strided(backend, x, y)
     }
 }
@@ -1,28 +1,35 @@
-use blas::{Copy, Gemm, Transpose};
+use blas::{Copy, Gemm, Symm, Syrk, Transpose, Trmm, Uplo};
 
 use Forward;
 use onezero::{One, Zero};
+use ops::reduce;
 use ops::{Reduce, set, self};
 use traits::Transpose as _0;
-use traits::{Matrix, MatrixCols, MatrixColsMut, MatrixRows, MatrixRowsMut, Set, Slice, SliceMut};
-use {Chain, Col, ColMut, Mat, Row, RowMut, Transposed, Scaled, SubMat, SubMatMut};
+use traits::{
+    Matrix, MatrixCols, MatrixColsMut, MatrixRows, MatrixRowsMut, Scalar, Set, Slice, SliceMut,
+};
+use {
+    Chain, Col, ColMut, LowerTri, Mat, Product, Row, RowMut, Transposed, Scaled, SparseMat, SubMat,
+    SubMatMut, Symmetric, UpperTri,
+};
 
 // NOTE Core
 impl<'a, T> Set<T> for SubMatMut<'a, T> where T: Copy {
     fn set(&mut self, value: T) {
+        let ref backend = T::backend();
         let ref x = value;
 
         if let Some(y) = self.as_slice_mut() {
-            return set::slice(x, y);
+            return set::slice(backend, x, y);
         }
 
         if self.nrows() < self.ncols() {
             for RowMut(Row(ref mut y)) in self.rows_mut() {
-                set::strided(x, y)
+                set::strided(backend, x, y)
             }
         } else {
             for ColMut(Col(ref mut y)) in self.cols_mut() {
-                set::strided(x, y)
+                set::strided(backend, x, y)
             }
         }
     }
@@ -31,20 +38,22 @@ impl<'a, T> Set<T> for SubMatMut<'a, T> where T: Copy {
 // NOTE Core
 impl<'a, 'b, T> Set<SubMat<'a, T>> for SubMatMut<'b, T> where T: Copy {
     fn set(&mut self, rhs: SubMat<T>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq!(self.size(), rhs.size());
 
             if let (Some(y), Some(x)) = (self.as_slice_mut(), rhs.as_slice()) {
-                return ops::copy_slice(x, y);
+                return ops::copy_slice(backend, x, y);
             }
 
             if self.nrows() < self.ncols() {
                 for (RowMut(Row(ref mut y)), Row(ref x)) in self.rows_mut().zip(rhs.rows()) {
-                    ops::copy_strided(x, y)
+                    ops::copy_strided(backend, x, y)
                 }
             } else {
                 for (ColMut(Col(ref mut y)), Col(ref x)) in self.cols_mut().zip(rhs.cols()) {
-                    ops::copy_strided(x, y)
+                    ops::copy_strided(backend, x, y)
                 }
             }
         }
@@ -54,16 +63,18 @@ impl<'a, 'b, T> Set<SubMat<'a, T>> for SubMatMut<'b, T> where T: Copy {
 // NOTE Core
 impl<'a, 'b, T> Set<Transposed<SubMat<'a, T>>> for SubMatMut<'b, T> where T: Copy {
     fn set(&mut self, rhs: Transposed<SubMat<T>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq!(self.size(), rhs.size());
 
             if self.nrows() < self.ncols() {
                 for (RowMut(Row(ref mut y)), Row(ref x)) in self.rows_mut().zip(rhs.rows()) {
-                    ops::copy_strided(x, y)
+                    ops::copy_strided(backend, x, y)
                 }
             } else {
                 for (ColMut(Col(ref mut y)), Col(ref x)) in self.cols_mut().zip(rhs.cols()) {
-                    ops::copy_strided(x, y)
+                    ops::copy_strided(backend, x, y)
                 }
             }
         }
@@ -71,8 +82,12 @@ impl<'a, 'b, T> Set<Transposed<SubMat<'a, T>>> for SubMatMut<'b, T> where T: Cop
 }
 
 // NOTE Core
-impl<'a, 'b, T> Set<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where T: Gemm + One + Zero {
+impl<'a, 'b, T> Set<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where
+    T: Copy + Gemm + One + Syrk + Zero,
+{
     fn set(&mut self, rhs: Scaled<Chain<T>>) {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulMat::*;
 
@@ -80,7 +95,12 @@ impl<'a, 'b, T> Set<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where T: Gemm + O
 
             let Scaled(alpha, rhs) = rhs;
             let ref alpha = alpha;
-            let a_mul_b = rhs.reduce();
+
+            if let Some((ref trans, a)) = reduce::gram(&rhs) {
+                return ops::syrk(backend, trans, alpha, a, self.slice_mut(..))
+            }
+
+            let a_mul_b = rhs.reduce(backend);
 
             let ((ref transa, a), (ref transb, b)) = match a_mul_b {
                 M_M(ref lhs, ref rhs) => {
@@ -94,7 +114,7 @@ impl<'a, 'b, T> Set<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where T: Gemm + O
             let c = self.slice_mut(..);
             let ref beta = T::zero();
 
-            ops::gemm(transa, transb, alpha, a, b, beta, c)
+            ops::gemm(backend, transa, transb, alpha, a, b, beta, c)
         }
     }
 }
@@ -107,14 +127,18 @@ impl<'a, T> Set<T> for Transposed<SubMatMut<'a, T>> where T: Copy {
 }
 
 // NOTE Secondary
-impl<'a, 'b, T> Set<Chain<'a, T>> for SubMatMut<'b, T> where T: Gemm + One + Zero {
+impl<'a, 'b, T> Set<Chain<'a, T>> for SubMatMut<'b, T> where
+    T: Copy + Gemm + One + Syrk + Zero,
+{
     fn set(&mut self, rhs: Chain<T>) {
         self.set(Scaled(T::one(), rhs))
     }
 }
 
 // NOTE Secondary
-impl<'a, 'b, T> Set<Chain<'a, T>> for Transposed<SubMatMut<'b, T>> where T: Gemm + One + Zero {
+impl<'a, 'b, T> Set<Chain<'a, T>> for Transposed<SubMatMut<'b, T>> where
+    T: Copy + Gemm + One + Syrk + Zero,
+{
     fn set(&mut self, rhs: Chain<T>) {
         self.set(Scaled(T::one(), rhs))
     }
@@ -122,7 +146,7 @@ impl<'a, 'b, T> Set<Chain<'a, T>> for Transposed<SubMatMut<'b, T>> where T: Gemm
 
 // NOTE Secondary
 impl<'a, 'b, T> Set<Scaled<Chain<'a, T>>> for Transposed<SubMatMut<'b, T>> where
-    T: Gemm + One + Zero,
+    T: Copy + Gemm + One + Syrk + Zero,
 {
     fn set(&mut self, rhs: Scaled<Chain<T>>) {
         self.0.set(rhs.t())
@@ -157,6 +181,149 @@ impl<T> Set<T> for Mat<T> where T: Copy {
     }
 }
 
+// NOTE Core
+impl<'a, 'b, 'c, T>
+Set<Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>>> for SubMatMut<'c, T> where
+    T: Scalar,
+{
+    fn set(&mut self, rhs: Scaled<Product<&SparseMat<T>, SubMat<T>>>) {
+        assert_eq!(self.size(), rhs.size());
+
+        let Scaled(ref alpha, Product(a, b)) = rhs;
+
+        ops::spmm(alpha, a, &T::zero(), b, self.slice_mut(..))
+    }
+}
+
+// NOTE Secondary
+impl<'a, 'b, 'c, T> Set<Product<&'a SparseMat<T>, SubMat<'b, T>>> for SubMatMut<'c, T> where
+    T: Scalar,
+{
+    fn set(&mut self, rhs: Product<&SparseMat<T>, SubMat<T>>) {
+        self.set(Scaled(T::one(), rhs))
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Product<&'a SparseMat<T>, SubMat<'b, T>>> for Mat<T> where T: Scalar {
+    fn set(&mut self, rhs: Product<&SparseMat<T>, SubMat<T>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Scaled<Product<&'a SparseMat<T>, SubMat<'b, T>>>> for Mat<T> where
+    T: Scalar,
+{
+    fn set(&mut self, rhs: Scaled<Product<&SparseMat<T>, SubMat<T>>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+// NOTE Core
+impl<'a, 'b, 'c, T>
+Set<Scaled<Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>>> for SubMatMut<'c, T> where
+    T: Symm + Zero,
+{
+    fn set(&mut self, rhs: Scaled<Product<Symmetric<SubMat<T>>, SubMat<T>>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            assert_eq!(self.size(), rhs.size());
+
+            let Scaled(ref alpha, Product(Symmetric(a, ref uplo), b)) = rhs;
+
+            ops::symm(backend, uplo, alpha, a, b, &T::zero(), self.slice_mut(..))
+        }
+    }
+}
+
+// NOTE Secondary
+impl<'a, 'b, 'c, T>
+Set<Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>> for SubMatMut<'c, T> where
+    T: One + Symm + Zero,
+{
+    fn set(&mut self, rhs: Product<Symmetric<SubMat<T>>, SubMat<T>>) {
+        self.set(Scaled(T::one(), rhs))
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>> for Mat<T> where
+    T: One + Symm + Zero,
+{
+    fn set(&mut self, rhs: Product<Symmetric<SubMat<T>>, SubMat<T>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+// NOTE Forward
+impl<'a, 'b, T> Set<Scaled<Product<Symmetric<SubMat<'a, T>>, SubMat<'b, T>>>> for Mat<T> where
+    T: Symm + Zero,
+{
+    fn set(&mut self, rhs: Scaled<Product<Symmetric<SubMat<T>>, SubMat<T>>>) {
+        self.slice_mut(..).set(rhs)
+    }
+}
+
+macro_rules! tri {
+    ($($tri:ident => $uplo:expr),+) => {
+        $(
+            // NOTE Core
+            impl<'a, 'b, 'c, T>
+            Set<Scaled<Product<$tri<SubMat<'a, T>>, SubMat<'b, T>>>> for SubMatMut<'c, T> where
+                T: Copy + Trmm,
+            {
+                fn set(&mut self, rhs: Scaled<Product<$tri<SubMat<T>>, SubMat<T>>>) {
+                    let ref backend = T::backend();
+
+                    unsafe {
+                        assert_eq!((rhs.1).0.ncols(), (rhs.1).1.nrows());
+
+                        let Scaled(ref alpha, Product($tri(a, ref diag), b)) = rhs;
+                        let ref transa = Transpose::No;
+
+                        self.set(b);
+
+                        ops::trmm(backend, $uplo, transa, diag, alpha, a, self.slice_mut(..))
+                    }
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, 'c, T>
+            Set<Product<$tri<SubMat<'a, T>>, SubMat<'b, T>>> for SubMatMut<'c, T> where
+                T: Copy + One + Trmm,
+            {
+                fn set(&mut self, rhs: Product<$tri<SubMat<T>>, SubMat<T>>) {
+                    self.set(Scaled(T::one(), rhs))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, T> Set<Product<$tri<SubMat<'a, T>>, SubMat<'b, T>>> for Mat<T> where
+                T: Copy + One + Trmm,
+            {
+                fn set(&mut self, rhs: Product<$tri<SubMat<T>>, SubMat<T>>) {
+                    self.slice_mut(..).set(rhs)
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, T>
+            Set<Scaled<Product<$tri<SubMat<'a, T>>, SubMat<'b, T>>>> for Mat<T> where
+                T: Copy + Trmm,
+            {
+                fn set(&mut self, rhs: Scaled<Product<$tri<SubMat<T>>, SubMat<T>>>) {
+                    self.slice_mut(..).set(rhs)
+                }
+            }
+         )+
+    }
+}
+
+tri!(LowerTri => &Uplo::Lower, UpperTri => &Uplo::Upper);
+
 macro_rules! forward {
     ($lhs:ty { $($rhs:ty { $($bound:ident),+ }),+, }) => {
         $(
@@ -170,7 +337,7 @@ macro_rules! forward {
 }
 
 forward!(Mat<T> {
-    Chain<'a, T> { Gemm, One, Zero },
+    Chain<'a, T> { Copy, Gemm, One, Syrk, Zero },
     Scaled<Chain<'a, T>> { Gemm, One, Zero },
     SubMat<'a, T> { Copy },
     &'a SubMatMut<'b, T> { Copy },
@@ -178,7 +345,7 @@ forward!(Mat<T> {
 
 forward!(Transposed<Mat<T>> {
     Scaled<Chain<'a, T>> { Gemm, One, Zero },
-    Chain<'a, T> { Gemm, One, Zero },
+    Chain<'a, T> { Copy, Gemm, One, Syrk, Zero },
     SubMat<'a, T> { Copy },
     &'a SubMatMut<'b, T> { Copy },
 });
@@ -10,23 +10,26 @@ use {Product, RowMut, Row, RowVec, Scaled, SubMat, Transposed};
 // NOTE Core
 impl<'a, T> Set<T> for RowMut<'a, T> where T: Copy {
     fn set(&mut self, value: T) {
+        let ref backend = T::backend();
         let RowMut(Row(ref mut y)) = *self;
         let ref x = value;
 
-        set::strided(x, y)
+        set::strided(backend, x, y)
     }
 }
 
 // NOTE Core
 impl<'a, 'b, T> Set<Row<'a, T>> for RowMut<'b, T> where T: Copy {
     fn set(&mut self, rhs: Row<T>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq!(self.ncols(), rhs.ncols());
 
             let RowMut(Row(ref mut y)) = *self;
             let Row(ref x) = rhs;
 
-            ops::copy_strided(x, y)
+            ops::copy_strided(backend, x, y)
         }
     }
 }
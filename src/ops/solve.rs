@@ -0,0 +1,553 @@
+use blas::{Copy, Transpose};
+use complex::Complex;
+use lapack::{Gbsv, Getrf, Getrs, Gtsv, Lange, Potrs};
+
+use ops;
+use traits::{Matrix, MatrixDiag, Slice, SliceMut, Solve, TrySolve};
+use {BandMat, Cholesky, Col, ColMut, ColVec, Error, Lu, Mat, Qr, Transposed, SubMat, SubMatMut};
+
+// Combinations:
+//
+// LHS: BandMat, Mat, &Mat, Transposed<Mat>, &Transposed<Mat>, SubMat, &SubMatMut,
+//      Transposed<SubMat>, &Cholesky, &Lu
+// RHS: Col, &ColMut, ColVec, &ColVec, Mat, &Mat, SubMat, &SubMatMut
+//
+// -> 80 implementations
+//
+// LHS: &Qr
+// RHS: Col, ColVec, Mat, SubMat
+//
+// -> 4 implementations per element type
+//
+// `TrySolve`
+//
+// LHS: BandMat, &Lu
+// RHS: Col, &ColMut, ColVec, &ColVec, Mat, &Mat, SubMat, &SubMatMut
+//
+// -> 16 implementations
+
+/// B := op(A)^-1 * B
+///
+/// NOTE `A` gets overwritten with its LU factorization
+unsafe fn solve<T>(trans: &Transpose, mut a: SubMatMut<T>, b: SubMatMut<T>) where
+    T: Getrf + Getrs,
+{
+    debug_assert_eq!(a.nrows(), a.ncols());
+    debug_assert_eq!(a.nrows(), b.nrows());
+
+    let (ipiv, info) = ops::getrf(a.slice_mut(..));
+
+    assert!(info == 0);
+
+    ops::getrs(trans, a.0, &ipiv, b);
+}
+
+/// B := A^-1 * B, where `A` is a band matrix
+///
+/// NOTE `A` gets overwritten with its LU factorization, unless it's tridiagonal
+unsafe fn solve_band<T>(a: &mut BandMat<T>, b: SubMatMut<T>) -> Result<(), Error> where
+    T: Gbsv + Gtsv,
+{
+    debug_assert_eq!(a.nrows(), b.nrows());
+
+    let info = if a.kl == 1 && a.ku == 1 && a.nrows() > 1 {
+        let mut dl = a.diag(-1).iter().cloned().collect::<Vec<_>>();
+        let mut d = a.diag(0).iter().cloned().collect::<Vec<_>>();
+        let mut du = a.diag(1).iter().cloned().collect::<Vec<_>>();
+
+        ops::gtsv(&mut dl, &mut d, &mut du, b)
+    } else {
+        ops::gbsv(a, b)
+    };
+
+    ops::check_info(info)
+}
+
+/// Views an owned column vector as a `n x 1` matrix
+fn as_submat_mut<T>(b: &mut ColVec<T>) -> SubMatMut<T> {
+    let n = b.nrows();
+
+    SubMatMut::reshape(b.as_slice_mut(), (n, 1))
+}
+
+// 10 impls
+// NOTE Core
+impl<T> Solve<ColVec<T>> for BandMat<T> where T: Gbsv + Gtsv {
+    type Output = ColVec<T>;
+
+    fn solve(mut self, mut b: ColVec<T>) -> ColVec<T> {
+        unsafe {
+            assert_eq!(self.nrows(), b.nrows());
+
+            solve_band(&mut self, as_submat_mut(&mut b)).unwrap();
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+impl<T> Solve<Mat<T>> for BandMat<T> where T: Gbsv + Gtsv {
+    type Output = Mat<T>;
+
+    fn solve(mut self, mut b: Mat<T>) -> Mat<T> {
+        unsafe {
+            assert_eq!(self.nrows(), b.nrows());
+
+            solve_band(&mut self, b.slice_mut(..)).unwrap();
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+impl<'a, T> Solve<ColVec<T>> for &'a Cholesky<T> where T: Potrs {
+    type Output = ColVec<T>;
+
+    fn solve(self, mut b: ColVec<T>) -> ColVec<T> {
+        unsafe {
+            assert_eq!(self.0.nrows(), b.nrows());
+
+            ops::potrs(self.0.slice(..), as_submat_mut(&mut b));
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+impl<'a, T> Solve<Mat<T>> for &'a Cholesky<T> where T: Potrs {
+    type Output = Mat<T>;
+
+    fn solve(self, mut b: Mat<T>) -> Mat<T> {
+        unsafe {
+            assert_eq!(self.0.nrows(), b.nrows());
+
+            ops::potrs(self.0.slice(..), b.slice_mut(..));
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+impl<'a, T> Solve<ColVec<T>> for &'a Lu<T> where T: Getrs + Lange {
+    type Output = ColVec<T>;
+
+    fn solve(self, mut b: ColVec<T>) -> ColVec<T> {
+        unsafe {
+            assert_eq!(self.lu.nrows(), b.nrows());
+            assert!(self.info == 0);
+
+            ops::getrs(&Transpose::No, self.lu.slice(..), &self.ipiv, as_submat_mut(&mut b));
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+impl<'a, T> Solve<Mat<T>> for &'a Lu<T> where T: Getrs + Lange {
+    type Output = Mat<T>;
+
+    fn solve(self, mut b: Mat<T>) -> Mat<T> {
+        unsafe {
+            assert_eq!(self.lu.nrows(), b.nrows());
+            assert!(self.info == 0);
+
+            ops::getrs(&Transpose::No, self.lu.slice(..), &self.ipiv, b.slice_mut(..));
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+impl<T> Solve<ColVec<T>> for Mat<T> where T: Getrf + Getrs {
+    type Output = ColVec<T>;
+
+    fn solve(mut self, mut b: ColVec<T>) -> ColVec<T> {
+        unsafe {
+            assert_eq!(self.nrows(), self.ncols());
+            assert_eq!(self.nrows(), b.nrows());
+
+            solve(&Transpose::No, self.slice_mut(..), as_submat_mut(&mut b));
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+impl<T> Solve<Mat<T>> for Mat<T> where T: Getrf + Getrs {
+    type Output = Mat<T>;
+
+    fn solve(mut self, mut b: Mat<T>) -> Mat<T> {
+        unsafe {
+            assert_eq!(self.nrows(), self.ncols());
+            assert_eq!(self.nrows(), b.nrows());
+
+            solve(&Transpose::No, self.slice_mut(..), b.slice_mut(..));
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+// Remember that `A' * x = b` can be solved using the LU factorization of `A`
+impl<T> Solve<ColVec<T>> for Transposed<Mat<T>> where T: Getrf + Getrs {
+    type Output = ColVec<T>;
+
+    fn solve(mut self, mut b: ColVec<T>) -> ColVec<T> {
+        unsafe {
+            assert_eq!(self.nrows(), self.ncols());
+            assert_eq!(self.nrows(), b.nrows());
+
+            solve(&Transpose::Yes, self.0.slice_mut(..), as_submat_mut(&mut b));
+
+            b
+        }
+    }
+}
+
+// NOTE Core
+impl<T> Solve<Mat<T>> for Transposed<Mat<T>> where T: Getrf + Getrs {
+    type Output = Mat<T>;
+
+    fn solve(mut self, mut b: Mat<T>) -> Mat<T> {
+        unsafe {
+            assert_eq!(self.nrows(), self.ncols());
+            assert_eq!(self.nrows(), b.nrows());
+
+            solve(&Transpose::Yes, self.0.slice_mut(..), b.slice_mut(..));
+
+            b
+        }
+    }
+}
+
+// 4 impls
+// NOTE Core
+impl<T> TrySolve<ColVec<T>> for BandMat<T> where T: Gbsv + Gtsv {
+    fn try_solve(mut self, mut b: ColVec<T>) -> Result<ColVec<T>, Error> {
+        unsafe {
+            if self.nrows() != b.nrows() {
+                return Err(Error::DimensionMismatch { lhs: self.size(), rhs: b.size() })
+            }
+
+            try!(solve_band(&mut self, as_submat_mut(&mut b)));
+
+            Ok(b)
+        }
+    }
+}
+
+// NOTE Core
+impl<T> TrySolve<Mat<T>> for BandMat<T> where T: Gbsv + Gtsv {
+    fn try_solve(mut self, mut b: Mat<T>) -> Result<Mat<T>, Error> {
+        unsafe {
+            if self.nrows() != b.nrows() {
+                return Err(Error::DimensionMismatch { lhs: self.size(), rhs: b.size() })
+            }
+
+            try!(solve_band(&mut self, b.slice_mut(..)));
+
+            Ok(b)
+        }
+    }
+}
+
+// NOTE Core
+impl<'a, T> TrySolve<ColVec<T>> for &'a Lu<T> where T: Getrs + Lange {
+    fn try_solve(self, b: ColVec<T>) -> Result<ColVec<T>, Error> {
+        if self.lu.nrows() != b.nrows() {
+            return Err(Error::DimensionMismatch { lhs: self.size(), rhs: b.size() })
+        }
+
+        try!(ops::check_info(self.info));
+
+        Ok(self.solve(b))
+    }
+}
+
+// NOTE Core
+impl<'a, T> TrySolve<Mat<T>> for &'a Lu<T> where T: Getrs + Lange {
+    fn try_solve(self, b: Mat<T>) -> Result<Mat<T>, Error> {
+        if self.lu.nrows() != b.nrows() {
+            return Err(Error::DimensionMismatch { lhs: self.size(), rhs: b.size() })
+        }
+
+        try!(ops::check_info(self.info));
+
+        Ok(self.solve(b))
+    }
+}
+
+// Remember that `A * X = B` can be solved as `R * X = Q' * B`, where `R` is upper triangular
+macro_rules! qr {
+    ($($t:ty => $trans:expr),+,) => {
+        $(
+            // NOTE Core
+            impl<'a> Solve<ColVec<$t>> for &'a Qr<$t> {
+                type Output = ColVec<$t>;
+
+                fn solve(self, mut b: ColVec<$t>) -> ColVec<$t> {
+                    unsafe {
+                        let (m, n) = self.qr.size();
+
+                        assert!(m >= n);
+                        assert_eq!(m, b.nrows());
+
+                        {
+                            let mut b = as_submat_mut(&mut b);
+                            let r = self.qr.slice((..n, ..));
+
+                            ops::ormqr($trans, self.qr.slice(..), &self.tau, b.slice_mut(..));
+                            assert!(ops::trtrs(r, b.slice_mut((..n, ..))) == 0);
+                        }
+
+                        if m == n {
+                            b
+                        } else {
+                            ColVec::from(b.slice(..n))
+                        }
+                    }
+                }
+            }
+
+            // NOTE Core
+            impl<'a> Solve<Mat<$t>> for &'a Qr<$t> {
+                type Output = Mat<$t>;
+
+                fn solve(self, mut b: Mat<$t>) -> Mat<$t> {
+                    unsafe {
+                        let (m, n) = self.qr.size();
+
+                        assert!(m >= n);
+                        assert_eq!(m, b.nrows());
+
+                        let r = self.qr.slice((..n, ..));
+
+                        ops::ormqr($trans, self.qr.slice(..), &self.tau, b.slice_mut(..));
+                        assert!(ops::trtrs(r, b.slice_mut((..n, ..))) == 0);
+
+                        if m == n {
+                            b
+                        } else {
+                            Mat::from(b.slice((..n, ..)))
+                        }
+                    }
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b> Solve<Col<'b, $t>> for &'a Qr<$t> {
+                type Output = ColVec<$t>;
+
+                fn solve(self, b: Col<'b, $t>) -> ColVec<$t> {
+                    self.solve(ColVec::from(b))
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b> Solve<SubMat<'b, $t>> for &'a Qr<$t> {
+                type Output = Mat<$t>;
+
+                fn solve(self, b: SubMat<'b, $t>) -> Mat<$t> {
+                    self.solve(Mat::from(b))
+                }
+            }
+         )+
+    }
+}
+
+// 16 impls
+qr! {
+    f32 => &b'T',
+    f64 => &b'T',
+    Complex<f32> => &b'C',
+    Complex<f64> => &b'C',
+}
+
+macro_rules! rhs {
+    ($($lhs:ty { $($bound:ident),+ }),+,) => {
+        $(
+            // NOTE Secondary
+            impl<'a, 'b, 'c, 'd, T> Solve<Col<'c, T>> for $lhs where
+                T: Copy $(+ $bound)+,
+            {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: Col<'c, T>) -> ColVec<T> {
+                    self.solve(ColVec::from(b))
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, 'c, 'd, T> Solve<SubMat<'c, T>> for $lhs where
+                T: Copy $(+ $bound)+,
+            {
+                type Output = Mat<T>;
+
+                fn solve(self, b: SubMat<'c, T>) -> Mat<T> {
+                    self.solve(Mat::from(b))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, 'd, T> Solve<&'c ColMut<'d, T>> for $lhs where
+                T: Copy $(+ $bound)+,
+            {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: &'c ColMut<'d, T>) -> ColVec<T> {
+                    self.solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, 'd, T> Solve<&'c ColVec<T>> for $lhs where
+                T: Copy $(+ $bound)+,
+            {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: &'c ColVec<T>) -> ColVec<T> {
+                    self.solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, 'd, T> Solve<&'c Mat<T>> for $lhs where
+                T: Copy $(+ $bound)+,
+            {
+                type Output = Mat<T>;
+
+                fn solve(self, b: &'c Mat<T>) -> Mat<T> {
+                    self.solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, 'd, T> Solve<&'c SubMatMut<'d, T>> for $lhs where
+                T: Copy $(+ $bound)+,
+            {
+                type Output = Mat<T>;
+
+                fn solve(self, b: &'c SubMatMut<'d, T>) -> Mat<T> {
+                    self.solve(b.slice(..))
+                }
+            }
+         )+
+    }
+}
+
+// 30 impls
+rhs! {
+    BandMat<T> { Gbsv, Gtsv },
+    &'a Cholesky<T> { Potrs },
+    &'a Lu<T> { Getrs, Lange },
+    Mat<T> { Getrf, Getrs },
+    Transposed<Mat<T>> { Getrf, Getrs },
+}
+
+macro_rules! try_rhs {
+    ($($lhs:ty { $($bound:ident),+ }),+,) => {
+        $(
+            // NOTE Secondary
+            impl<'a, 'c, 'd, T> TrySolve<Col<'c, T>> for $lhs where T: Copy $(+ $bound)+ {
+                fn try_solve(self, b: Col<'c, T>) -> Result<ColVec<T>, Error> {
+                    self.try_solve(ColVec::from(b))
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'c, 'd, T> TrySolve<SubMat<'c, T>> for $lhs where T: Copy $(+ $bound)+ {
+                fn try_solve(self, b: SubMat<'c, T>) -> Result<Mat<T>, Error> {
+                    self.try_solve(Mat::from(b))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'c, 'd, T> TrySolve<&'c ColMut<'d, T>> for $lhs where
+                T: Copy $(+ $bound)+,
+            {
+                fn try_solve(self, b: &'c ColMut<'d, T>) -> Result<ColVec<T>, Error> {
+                    self.try_solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'c, 'd, T> TrySolve<&'c ColVec<T>> for $lhs where T: Copy $(+ $bound)+ {
+                fn try_solve(self, b: &'c ColVec<T>) -> Result<ColVec<T>, Error> {
+                    self.try_solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'c, 'd, T> TrySolve<&'c Mat<T>> for $lhs where T: Copy $(+ $bound)+ {
+                fn try_solve(self, b: &'c Mat<T>) -> Result<Mat<T>, Error> {
+                    self.try_solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'c, 'd, T> TrySolve<&'c SubMatMut<'d, T>> for $lhs where
+                T: Copy $(+ $bound)+,
+            {
+                fn try_solve(self, b: &'c SubMatMut<'d, T>) -> Result<Mat<T>, Error> {
+                    self.try_solve(b.slice(..))
+                }
+            }
+         )+
+    }
+}
+
+// 12 impls
+try_rhs! {
+    BandMat<T> { Gbsv, Gtsv },
+    &'a Lu<T> { Getrs, Lange },
+}
+
+// The LU factorization overwrites the coefficient matrix, so views must be copied first
+macro_rules! lhs {
+    ($($lhs:ty => |$a:ident| $owned:expr),+,) => {
+        $(
+            // NOTE Secondary
+            impl<'a, 'b, T> Solve<ColVec<T>> for $lhs where T: Copy + Getrf + Getrs {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: ColVec<T>) -> ColVec<T> {
+                    let $a = self;
+
+                    $owned.solve(b)
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, T> Solve<Mat<T>> for $lhs where T: Copy + Getrf + Getrs {
+                type Output = Mat<T>;
+
+                fn solve(self, b: Mat<T>) -> Mat<T> {
+                    let $a = self;
+
+                    $owned.solve(b)
+                }
+            }
+         )+
+
+        rhs! {
+            $($lhs { Getrf, Getrs }),+,
+        }
+    }
+}
+
+// 40 impls
+lhs! {
+    &'a Mat<T> => |a| Mat::from(a.slice(..)),
+    &'a Transposed<Mat<T>> => |a| Transposed(Mat::from(a.0.slice(..))),
+    SubMat<'a, T> => |a| Mat::from(a),
+    &'a SubMatMut<'b, T> => |a| Mat::from(a),
+    Transposed<SubMat<'a, T>> => |a| Transposed(Mat::from(a.0)),
+}
@@ -0,0 +1,178 @@
+use assign::MulAssign;
+use blas::{Copy, Scal, Transpose, Trsm, Trsv, Uplo};
+use onezero::One;
+
+use ops;
+use traits::{Matrix, Slice, SliceMut, Solve};
+use {Col, ColMut, ColVec, LowerTri, Mat, Scaled, SubMat, SubMatMut, UpperTri};
+
+// Combinations:
+//
+// LHS: LowerTri<SubMat>, UpperTri<SubMat>
+// RHS: Col, &ColMut, ColVec, &ColVec, Mat, &Mat, Scaled<Col>, Scaled<ColVec>, Scaled<Mat>,
+//      Scaled<SubMat>, SubMat, &SubMatMut
+//
+// -> 24 implementations
+//
+// NB Unlike the general solvers, the triangular solvers don't need to factorize (or copy) the
+// coefficient matrix. `L.solve(alpha * B)`, i.e. `alpha * L^-1 * B`, is a single `trsm` call that
+// overwrites `B`
+
+macro_rules! tri {
+    ($($tri:ident => $uplo:expr),+) => {
+        $(
+            // NOTE Core
+            impl<'a, T> Solve<ColVec<T>> for $tri<SubMat<'a, T>> where T: Trsv {
+                type Output = ColVec<T>;
+
+                fn solve(self, mut b: ColVec<T>) -> ColVec<T> {
+                    let ref backend = T::backend();
+
+                    unsafe {
+                        assert_eq!(self.nrows(), b.nrows());
+
+                        let $tri(a, ref diag) = self;
+                        let ref trans = Transpose::No;
+
+                        ops::trsv(backend, $uplo, trans, diag, a, b.slice_mut(..));
+
+                        b
+                    }
+                }
+            }
+
+            // NOTE Core
+            impl<'a, T> Solve<Scaled<Mat<T>>> for $tri<SubMat<'a, T>> where T: Trsm {
+                type Output = Mat<T>;
+
+                fn solve(self, b: Scaled<Mat<T>>) -> Mat<T> {
+                    let ref backend = T::backend();
+
+                    unsafe {
+                        assert_eq!(self.nrows(), b.nrows());
+
+                        let $tri(a, ref diag) = self;
+                        let Scaled(alpha, mut b) = b;
+                        let ref alpha = alpha;
+                        let ref transa = Transpose::No;
+
+                        ops::trsm(backend, $uplo, transa, diag, alpha, a, b.slice_mut(..));
+
+                        b
+                    }
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, T> Solve<Mat<T>> for $tri<SubMat<'a, T>> where T: One + Trsm {
+                type Output = Mat<T>;
+
+                fn solve(self, b: Mat<T>) -> Mat<T> {
+                    self.solve(Scaled(T::one(), b))
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, T> Solve<Scaled<ColVec<T>>> for $tri<SubMat<'a, T>> where
+                T: Scal<T> + Trsv,
+            {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: Scaled<ColVec<T>>) -> ColVec<T> {
+                    let Scaled(alpha, mut b) = b;
+
+                    b.mul_assign(alpha);
+
+                    self.solve(b)
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, T> Solve<Col<'b, T>> for $tri<SubMat<'a, T>> where T: Copy + Trsv {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: Col<'b, T>) -> ColVec<T> {
+                    self.solve(ColVec::from(b))
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, T> Solve<Scaled<Col<'b, T>>> for $tri<SubMat<'a, T>> where
+                T: Copy + Scal<T> + Trsv,
+            {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: Scaled<Col<'b, T>>) -> ColVec<T> {
+                    self.solve(Scaled(b.0, ColVec::from(b.1)))
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, T> Solve<Scaled<SubMat<'b, T>>> for $tri<SubMat<'a, T>> where
+                T: Copy + Trsm,
+            {
+                type Output = Mat<T>;
+
+                fn solve(self, b: Scaled<SubMat<'b, T>>) -> Mat<T> {
+                    self.solve(Scaled(b.0, Mat::from(b.1)))
+                }
+            }
+
+            // NOTE Secondary
+            impl<'a, 'b, T> Solve<SubMat<'b, T>> for $tri<SubMat<'a, T>> where
+                T: Copy + One + Trsm,
+            {
+                type Output = Mat<T>;
+
+                fn solve(self, b: SubMat<'b, T>) -> Mat<T> {
+                    self.solve(Mat::from(b))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, T> Solve<&'b ColMut<'c, T>> for $tri<SubMat<'a, T>> where
+                T: Copy + Trsv,
+            {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: &'b ColMut<'c, T>) -> ColVec<T> {
+                    self.solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, T> Solve<&'b ColVec<T>> for $tri<SubMat<'a, T>> where T: Copy + Trsv {
+                type Output = ColVec<T>;
+
+                fn solve(self, b: &'b ColVec<T>) -> ColVec<T> {
+                    self.solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, T> Solve<&'b Mat<T>> for $tri<SubMat<'a, T>> where
+                T: Copy + One + Trsm,
+            {
+                type Output = Mat<T>;
+
+                fn solve(self, b: &'b Mat<T>) -> Mat<T> {
+                    self.solve(b.slice(..))
+                }
+            }
+
+            // NOTE Forward
+            impl<'a, 'b, 'c, T> Solve<&'b SubMatMut<'c, T>> for $tri<SubMat<'a, T>> where
+                T: Copy + One + Trsm,
+            {
+                type Output = Mat<T>;
+
+                fn solve(self, b: &'b SubMatMut<'c, T>) -> Mat<T> {
+                    self.solve(b.slice(..))
+                }
+            }
+         )+
+    }
+}
+
+// 24 impls
+tri!(LowerTri => &Uplo::Lower, UpperTri => &Uplo::Upper);
@@ -28,6 +28,8 @@ impl<'a, 'b, T> Sub<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Scaled<ColVec
     type Output = ColVec<T>;
 
     fn sub(self, rhs: Scaled<Product<Chain<T>, Col<T>>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulCol::*;
 
@@ -38,7 +40,7 @@ impl<'a, 'b, T> Sub<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Scaled<ColVec
             let Scaled(beta, rhs) = rhs;
             let ref beta = beta;
 
-            let a_mul_b = rhs.reduce();
+            let a_mul_b = rhs.reduce(backend);
 
             let (ref transa, a, x) = match a_mul_b {
                 M_C(ref lhs, x) => {
@@ -52,7 +54,7 @@ impl<'a, 'b, T> Sub<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Scaled<ColVec
                 }
             };
 
-            ops::gemv(transa, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, transa, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -66,6 +68,8 @@ Sub<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for Scaled<ColVec<T>
     type Output = ColVec<T>;
 
     fn sub(self, rhs: Scaled<Product<Transposed<SubMat<T>>, Col<T>>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -76,7 +80,7 @@ Sub<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for Scaled<ColVec<T>
 
             let ref trans = Transpose::Yes;
 
-            ops::gemv(trans, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, trans, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -90,6 +94,8 @@ Sub<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for Scaled<ColVec<T>> where
     type Output = ColVec<T>;
 
     fn sub(self, rhs: Scaled<Product<SubMat<T>, Col<T>>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -100,7 +106,7 @@ Sub<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for Scaled<ColVec<T>> where
 
             let ref trans = Transpose::No;
 
-            ops::gemv(trans, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, trans, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -113,6 +119,8 @@ impl<'a, 'b, T> Sub<Scaled<ColVec<T>>> for Scaled<Product<Chain<'a, T>, Col<'b,
     type Output = ColVec<T>;
 
     fn sub(self, rhs: Scaled<ColVec<T>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulCol::*;
 
@@ -123,7 +131,7 @@ impl<'a, 'b, T> Sub<Scaled<ColVec<T>>> for Scaled<Product<Chain<'a, T>, Col<'b,
             let Scaled(alpha, mut y) = rhs;
             let ref alpha = alpha;
 
-            let a_mul_b = lhs.reduce();
+            let a_mul_b = lhs.reduce(backend);
 
             let (ref transa, a, x) = match a_mul_b {
                 M_C(ref lhs, x) => {
@@ -137,7 +145,7 @@ impl<'a, 'b, T> Sub<Scaled<ColVec<T>>> for Scaled<Product<Chain<'a, T>, Col<'b,
                 }
             };
 
-            ops::gemv(transa, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, transa, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -151,6 +159,8 @@ Sub<Scaled<ColVec<T>>> for Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>
     type Output = ColVec<T>;
 
     fn sub(self, rhs: Scaled<ColVec<T>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -161,7 +171,7 @@ Sub<Scaled<ColVec<T>>> for Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>
 
             let ref trans = Transpose::Yes;
 
-            ops::gemv(trans, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, trans, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -174,6 +184,8 @@ impl<'a, 'b, T> Sub<Scaled<ColVec<T>>> for Scaled<Product<SubMat<'a, T>, Col<'b,
     type Output = ColVec<T>;
 
     fn sub(self, rhs: Scaled<ColVec<T>>) -> ColVec<T> {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -184,7 +196,7 @@ impl<'a, 'b, T> Sub<Scaled<ColVec<T>>> for Scaled<Product<SubMat<'a, T>, Col<'b,
 
             let ref trans = Transpose::No;
 
-            ops::gemv(trans, alpha, a, beta, x, y.slice_mut(..));
+            ops::gemv(backend, trans, alpha, a, beta, x, y.slice_mut(..));
 
             y
         }
@@ -27,6 +27,8 @@ impl<'a, T> Sub<Scaled<Mat<T>>> for Scaled<Chain<'a, T>> where
     type Output = Mat<T>;
 
     fn sub(self, rhs: Scaled<Mat<T>>) -> Mat<T> {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulMat::*;
 
@@ -37,7 +39,7 @@ impl<'a, T> Sub<Scaled<Mat<T>>> for Scaled<Chain<'a, T>> where
             let Scaled(beta, mut c) = rhs;
             let ref beta = beta.neg();
 
-            let a_mul_b = chain.reduce();
+            let a_mul_b = chain.reduce(backend);
 
             let (ref transa, ref transb, a, b) = match a_mul_b {
                 M_M(ref lhs, ref rhs) => {
@@ -54,7 +56,7 @@ impl<'a, T> Sub<Scaled<Mat<T>>> for Scaled<Chain<'a, T>> where
                 }
             };
 
-            ops::gemm(transa, transb, alpha, a, b, beta, c.slice_mut(..));
+            ops::gemm(backend, transa, transb, alpha, a, b, beta, c.slice_mut(..));
 
             c
         }
@@ -67,6 +69,8 @@ impl<'a, T> Sub<Scaled<Chain<'a, T>>> for Scaled<Mat<T>> where
     type Output = Mat<T>;
 
     fn sub(self, rhs: Scaled<Chain<T>>) -> Mat<T> {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulMat::*;
 
@@ -77,7 +81,7 @@ impl<'a, T> Sub<Scaled<Chain<'a, T>>> for Scaled<Mat<T>> where
             let Scaled(alpha, chain) = rhs;
             let ref alpha = alpha.neg();
 
-            let a_mul_b = chain.reduce();
+            let a_mul_b = chain.reduce(backend);
 
             let (ref transa, ref transb, a, b) = match a_mul_b {
                 M_M(ref lhs, ref rhs) => {
@@ -94,7 +98,7 @@ impl<'a, T> Sub<Scaled<Chain<'a, T>>> for Scaled<Mat<T>> where
                 }
             };
 
-            ops::gemm(transa, transb, alpha, a, b, beta, c.slice_mut(..));
+            ops::gemm(backend, transa, transb, alpha, a, b, beta, c.slice_mut(..));
 
             c
         }
@@ -22,11 +22,12 @@ use {Chain, Col, ColMut, ColVec, Product, Scaled, Transposed, SubMat};
 // Core implementations
 impl<'a, 'b, T> SubAssign<&'a T> for ColMut<'b, T> where T: Axpy + Neg<Output=T> + One {
     fn sub_assign(&mut self, rhs: &T) {
+        let ref backend = T::backend();
         let ref alpha = T::one().neg();
         let x = rhs;
         let ColMut(Col(ref mut y)) = *self;
 
-        ops::axpy_strided_scalar(alpha, x, y)
+        ops::axpy_strided_scalar(backend, alpha, x, y)
     }
 }
 
@@ -34,6 +35,8 @@ impl<'a, 'b, 'c, T> SubAssign<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Col
     T: Gemm + Gemv + Neg<Output=T> + One + Zero,
 {
     fn sub_assign(&mut self, rhs: Scaled<Product<Chain<T>, Col<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulCol::*;
 
@@ -42,7 +45,7 @@ impl<'a, 'b, 'c, T> SubAssign<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Col
             let Scaled(alpha, rhs) = rhs;
             let ref alpha = alpha.neg();
 
-            let a_mul_b = rhs.reduce();
+            let a_mul_b = rhs.reduce(backend);
 
             let (ref transa, a, x) = match a_mul_b {
                 M_C(ref lhs, x) => {
@@ -59,7 +62,7 @@ impl<'a, 'b, 'c, T> SubAssign<Scaled<Product<Chain<'a, T>, Col<'b, T>>>> for Col
             let y = self.slice_mut(..);
             let ref beta = T::one();
 
-            ops::gemv(transa, alpha, a, beta, x, y)
+            ops::gemv(backend, transa, alpha, a, beta, x, y)
         }
     }
 }
@@ -69,6 +72,8 @@ SubAssign<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for ColMut<'c,
     T: Gemv + Neg<Output=T> + One,
 {
     fn sub_assign(&mut self, rhs: Scaled<Product<Transposed<SubMat<T>>, Col<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -78,7 +83,7 @@ SubAssign<Scaled<Product<Transposed<SubMat<'a, T>>, Col<'b, T>>>> for ColMut<'c,
             let ref beta = T::one();
             let y = ColMut(self.0);
 
-            ops::gemv(trans, alpha, a, beta, x, y);
+            ops::gemv(backend, trans, alpha, a, beta, x, y);
         }
     }
 }
@@ -87,6 +92,8 @@ impl<'a, 'b, 'c, T> SubAssign<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for Co
     T: Gemv + Neg<Output=T> + One,
 {
     fn sub_assign(&mut self, rhs: Scaled<Product<SubMat<T>, Col<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -96,13 +103,15 @@ impl<'a, 'b, 'c, T> SubAssign<Scaled<Product<SubMat<'a, T>, Col<'b, T>>>> for Co
             let ref beta = T::one();
             let y = ColMut(self.0);
 
-            ops::gemv(trans, alpha, a, beta, x, y);
+            ops::gemv(backend, trans, alpha, a, beta, x, y);
         }
     }
 }
 
 impl<'a, 'b, T> SubAssign<Scaled<Col<'a, T>>> for ColMut<'b, T> where T: Axpy + Neg<Output=T> {
     fn sub_assign(&mut self, rhs: Scaled<Col<T>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -111,7 +120,7 @@ impl<'a, 'b, T> SubAssign<Scaled<Col<'a, T>>> for ColMut<'b, T> where T: Axpy +
             let ref alpha = alpha.neg();
             let Col(ref x) = col;
 
-            ops::axpy_strided_strided(alpha, x, y);
+            ops::axpy_strided_strided(backend, alpha, x, y);
         }
     }
 }
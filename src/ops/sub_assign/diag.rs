@@ -17,11 +17,12 @@ use {DiagMut, Diag};
 // Core implementations
 impl<'a, 'b, T> SubAssign<&'a T> for DiagMut<'b, T> where T: Axpy + Neg<Output=T> + One {
     fn sub_assign(&mut self, rhs: &T) {
+        let ref backend = T::backend();
         let DiagMut(Diag(ref mut y)) = *self;
         let x = rhs;
         let ref alpha = T::one().neg();
 
-        ops::axpy_strided_scalar(alpha, x, y)
+        ops::axpy_strided_scalar(backend, alpha, x, y)
     }
 }
 
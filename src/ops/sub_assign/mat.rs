@@ -1,7 +1,7 @@
 use std::ops::Neg;
 
 use assign::SubAssign;
-use blas::{Axpy, Gemm, Transpose};
+use blas::{Axpy, Gemm, Ger, Transpose};
 use onezero::{One, Zero};
 
 use Forward;
@@ -10,7 +10,10 @@ use traits::{
     Matrix, MatrixCols, MatrixColsMut, MatrixRows, MatrixRowsMut, Slice, SliceMut,
 };
 use traits::Transpose as _0;
-use {Chain, Col, ColMut, Mat, Scaled, Row, RowMut, Transposed, SubMat, SubMatMut};
+use {
+    Broadcast, Chain, Col, ColMut, ColVec, Mat, Product, Scaled, Row, RowMut, RowVec, Transposed,
+    SubMat, SubMatMut,
+};
 
 // Combinations:
 //
@@ -19,34 +22,88 @@ use {Chain, Col, ColMut, Mat, Scaled, Row, RowMut, Transposed, SubMat, SubMatMut
 // &Transposed<Mat>, Transposed<SubMat>, &Transposed<SubMatMut>, SubMat, &SubMatMut
 //
 // -> 48 implementations
+//
+// LHS: Mat, Transposed<Mat>, Transposed<SubMatMut>, SubMatMut
+// RHS: Broadcast<Col>, Broadcast<Row>, Product<Col, Row>, Scaled<Product<Col, Row>>
+//
+// -> 16 implementations
 
-// 4 impls
+// 7 impls
 // Core implementations
 impl<'a, 'b, T> SubAssign<&'a T> for SubMatMut<'b, T> where T: Axpy + Neg<Output=T> + One {
     fn sub_assign(&mut self, rhs: &T) {
+        let ref backend = T::backend();
         let ref alpha = T::one().neg();
         let x = rhs;
 
         if let Some(y) = self.as_slice_mut() {
-            return ops::axpy_slice_scalar(alpha, x, y)
+            return ops::axpy_slice_scalar(backend, alpha, x, y)
         }
 
         if self.nrows() < self.ncols() {
             for RowMut(Row(ref mut y)) in self.rows_mut() {
-                ops::axpy_strided_scalar(alpha, x, y)
+                ops::axpy_strided_scalar(backend, alpha, x, y)
             }
         } else {
             for ColMut(Col(ref mut y)) in self.cols_mut() {
-                ops::axpy_strided_scalar(alpha, x, y)
+                ops::axpy_strided_scalar(backend, alpha, x, y)
             }
         }
     }
 }
 
+impl<'a, 'b, T> SubAssign<Broadcast<Col<'a, T>>> for SubMatMut<'b, T> where
+    T: Ger + Neg<Output=T> + One,
+{
+    fn sub_assign(&mut self, rhs: Broadcast<Col<T>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            let Broadcast(x) = rhs;
+
+            assert_eq!(self.nrows(), x.nrows());
+
+            // NB vectors can't be empty
+            if self.ncols() == 0 {
+                return
+            }
+
+            let ref ones = RowVec::ones(self.ncols());
+
+            ops::ger(backend, &T::one().neg(), x, ones.slice(..), self.slice_mut(..))
+        }
+    }
+}
+
+impl<'a, 'b, T> SubAssign<Broadcast<Row<'a, T>>> for SubMatMut<'b, T> where
+    T: Ger + Neg<Output=T> + One,
+{
+    fn sub_assign(&mut self, rhs: Broadcast<Row<T>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            let Broadcast(y) = rhs;
+
+            assert_eq!(self.ncols(), y.ncols());
+
+            // NB vectors can't be empty
+            if self.nrows() == 0 {
+                return
+            }
+
+            let ref ones = ColVec::ones(self.nrows());
+
+            ops::ger(backend, &T::one().neg(), ones.slice(..), y, self.slice_mut(..))
+        }
+    }
+}
+
 impl<'a, 'b, T> SubAssign<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where
     T: Gemm + Neg<Output=T> + One + Zero,
 {
     fn sub_assign(&mut self, rhs: Scaled<Chain<T>>) {
+        let ref backend = T::backend();
+
         unsafe {
             use ops::reduce::MatMulMat::*;
 
@@ -55,7 +112,7 @@ impl<'a, 'b, T> SubAssign<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where
             let Scaled(alpha, chain) = rhs;
             let ref alpha = alpha.neg();
 
-            let a_mul_b = chain.reduce();
+            let a_mul_b = chain.reduce(backend);
 
             let (ref transa, ref transb, a, b) = match a_mul_b {
                 M_M(ref lhs, ref rhs) => {
@@ -75,7 +132,25 @@ impl<'a, 'b, T> SubAssign<Scaled<Chain<'a, T>>> for SubMatMut<'b, T> where
             let c = SubMatMut(self.0);
             let ref beta = T::one();
 
-            ops::gemm(transa, transb, alpha, a, b, beta, c);
+            ops::gemm(backend, transa, transb, alpha, a, b, beta, c);
+        }
+    }
+}
+
+impl<'a, 'b, 'c, T>
+SubAssign<Scaled<Product<Col<'a, T>, Row<'b, T>>>> for SubMatMut<'c, T> where
+    T: Ger + Neg<Output=T>,
+{
+    fn sub_assign(&mut self, rhs: Scaled<Product<Col<T>, Row<T>>>) {
+        let ref backend = T::backend();
+
+        unsafe {
+            assert_eq_size!(self, rhs);
+
+            let Scaled(alpha, Product(x, y)) = rhs;
+            let ref alpha = alpha.neg();
+
+            ops::ger(backend, alpha, x, y, self.slice_mut(..))
         }
     }
 }
@@ -84,6 +159,8 @@ impl<'a, 'b, T> SubAssign<Scaled<Transposed<SubMat<'a, T>>>> for SubMatMut<'b, T
     T: Axpy + Neg<Output=T>,
 {
     fn sub_assign(&mut self, rhs: Scaled<Transposed<SubMat<T>>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -92,11 +169,11 @@ impl<'a, 'b, T> SubAssign<Scaled<Transposed<SubMat<'a, T>>>> for SubMatMut<'b, T
 
             if self.nrows() < self.ncols() {
                 for (RowMut(Row(ref mut y)), Row(ref x)) in self.rows_mut().zip(rhs.rows()) {
-                    ops::axpy_strided_strided(alpha, x, y)
+                    ops::axpy_strided_strided(backend, alpha, x, y)
                 }
             } else {
                 for (ColMut(Col(ref mut y)), Col(ref x)) in self.cols_mut().zip(rhs.cols()) {
-                    ops::axpy_strided_strided(alpha, x, y)
+                    ops::axpy_strided_strided(backend, alpha, x, y)
                 }
             }
         }
@@ -106,6 +183,8 @@ impl<'a, 'b, T> SubAssign<Scaled<Transposed<SubMat<'a, T>>>> for SubMatMut<'b, T
 impl<'a, 'b, T>
 SubAssign<Scaled<SubMat<'a, T>>> for SubMatMut<'b, T> where T: Axpy + Neg<Output=T> {
     fn sub_assign(&mut self, rhs: Scaled<SubMat<T>>) {
+        let ref backend = T::backend();
+
         unsafe {
             assert_eq_size!(self, rhs);
 
@@ -113,23 +192,23 @@ SubAssign<Scaled<SubMat<'a, T>>> for SubMatMut<'b, T> where T: Axpy + Neg<Output
             let ref alpha = alpha.neg();
 
             if let (Some(y), Some(x)) = (self.as_slice_mut(), rhs.as_slice()) {
-                return ops::axpy_slice_slice(alpha, x, y)
+                return ops::axpy_slice_slice(backend, alpha, x, y)
             }
 
             if self.nrows() < self.ncols() {
                 for (RowMut(Row(ref mut y)), Row(ref x)) in self.rows_mut().zip(rhs.rows()) {
-                    ops::axpy_strided_strided(alpha, x, y)
+                    ops::axpy_strided_strided(backend, alpha, x, y)
                 }
             } else {
                 for (ColMut(Col(ref mut y)), Col(ref x)) in self.cols_mut().zip(rhs.cols()) {
-                    ops::axpy_strided_strided(alpha, x, y)
+                    ops::axpy_strided_strided(backend, alpha, x, y)
                 }
             }
         }
     }
 }
 
-// 10 impls
+// 15 impls
 // Secondary implementations
 impl<'a, 'b, T> SubAssign<&'a T> for Transposed<SubMatMut<'b, T>> where
     T: Axpy + Neg<Output=T> + One,
@@ -139,6 +218,22 @@ impl<'a, 'b, T> SubAssign<&'a T> for Transposed<SubMatMut<'b, T>> where
     }
 }
 
+impl<'a, 'b, T> SubAssign<Broadcast<Col<'a, T>>> for Transposed<SubMatMut<'b, T>> where
+    T: Ger + Neg<Output=T> + One,
+{
+    fn sub_assign(&mut self, rhs: Broadcast<Col<T>>) {
+        self.0.sub_assign(Broadcast(Row((rhs.0).0)))
+    }
+}
+
+impl<'a, 'b, T> SubAssign<Broadcast<Row<'a, T>>> for Transposed<SubMatMut<'b, T>> where
+    T: Ger + Neg<Output=T> + One,
+{
+    fn sub_assign(&mut self, rhs: Broadcast<Row<T>>) {
+        self.0.sub_assign(Broadcast(Col((rhs.0).0)))
+    }
+}
+
 impl<'a, 'b, T> SubAssign<Chain<'a, T>> for Transposed<SubMatMut<'b, T>> where
     T: Gemm + Neg<Output=T> + One + Zero,
 {
@@ -147,6 +242,15 @@ impl<'a, 'b, T> SubAssign<Chain<'a, T>> for Transposed<SubMatMut<'b, T>> where
     }
 }
 
+impl<'a, 'b, 'c, T>
+SubAssign<Product<Col<'a, T>, Row<'b, T>>> for Transposed<SubMatMut<'c, T>> where
+    T: Ger + Neg<Output=T> + One,
+{
+    fn sub_assign(&mut self, rhs: Product<Col<T>, Row<T>>) {
+        self.sub_assign(Scaled(T::one(), rhs))
+    }
+}
+
 impl<'a, 'b, T> SubAssign<Scaled<Chain<'a, T>>> for Transposed<SubMatMut<'b, T>> where
     T: Gemm + Neg<Output=T> + One + Zero,
 {
@@ -155,6 +259,15 @@ impl<'a, 'b, T> SubAssign<Scaled<Chain<'a, T>>> for Transposed<SubMatMut<'b, T>>
     }
 }
 
+impl<'a, 'b, 'c, T>
+SubAssign<Scaled<Product<Col<'a, T>, Row<'b, T>>>> for Transposed<SubMatMut<'c, T>> where
+    T: Ger + Neg<Output=T>,
+{
+    fn sub_assign(&mut self, rhs: Scaled<Product<Col<T>, Row<T>>>) {
+        self.0.sub_assign(rhs.t())
+    }
+}
+
 impl<'a, 'b, T> SubAssign<Scaled<Transposed<SubMat<'a, T>>>> for Transposed<SubMatMut<'b, T>> where
     T: Axpy + Neg<Output=T>,
 {
@@ -195,6 +308,14 @@ impl<'a, 'b, T> SubAssign<Chain<'a, T>> for SubMatMut<'b, T> where T:
     }
 }
 
+impl<'a, 'b, 'c, T> SubAssign<Product<Col<'a, T>, Row<'b, T>>> for SubMatMut<'c, T> where
+    T: Ger + Neg<Output=T> + One,
+{
+    fn sub_assign(&mut self, rhs: Product<Col<T>, Row<T>>) {
+        self.sub_assign(Scaled(T::one(), rhs))
+    }
+}
+
 impl<'a, 'b, T> SubAssign<Transposed<SubMat<'a, T>>> for SubMatMut<'b, T> where
     T: Axpy + Neg<Output=T> + One,
 {
@@ -221,11 +342,15 @@ macro_rules! forward {
     };
 }
 
-// 12 impls
+// 16 impls
 forward!(Mat<T> {
+    Broadcast<Col<'a, T>> { Ger, One },
+    Broadcast<Row<'a, T>> { Ger, One },
     Chain<'a, T> { Gemm, One, Zero },
     &'a Mat<T> { Axpy, One },
+    Product<Col<'a, T>, Row<'b, T>> { Ger, One },
     Scaled<Chain<'a, T>> { Gemm, One, Zero  },
+    Scaled<Product<Col<'a, T>, Row<'b, T>>> { Ger },
     Scaled<Transposed<SubMat<'a, T>>> { Axpy  },
     Scaled<SubMat<'a, T>> { Axpy  },
     &'a Transposed<Mat<T>> { Axpy, One },
@@ -247,11 +372,15 @@ impl<T> SubAssign<T> for Mat<T> where T: Axpy + Neg<Output=T> + One {
     }
 }
 
-// 12 impls
+// 16 impls
 forward!(Transposed<Mat<T>> {
+    Broadcast<Col<'a, T>> { Ger, One },
+    Broadcast<Row<'a, T>> { Ger, One },
     Chain<'a, T> { Gemm, One, Zero },
     &'a Mat<T> { Axpy, One },
+    Product<Col<'a, T>, Row<'b, T>> { Ger, One },
     Scaled<Chain<'a, T>> { Gemm, One, Zero },
+    Scaled<Product<Col<'a, T>, Row<'b, T>>> { Ger },
     Scaled<Transposed<SubMat<'a, T>>> { Axpy  },
     Scaled<SubMat<'a, T>> { Axpy  },
     &'a Transposed<Mat<T>> { Axpy, One },
@@ -2,9 +2,12 @@
 //!
 //! This module is meant to be manually glob imported.
 
+pub use BandMat;
 pub use ColVec;
 pub use Mat;
+pub use RowMat;
 pub use RowVec;
+pub use SparseMat;
 
 pub use assign::AddAssign as __linalg_0;
 pub use assign::DivAssign as __linalg_1;
@@ -39,3 +42,22 @@ pub use traits::SliceMut as __linalg_28;
 pub use traits::Transpose as __linalg_29;
 pub use traits::VSplit as __linalg_30;
 pub use traits::VSplitMut as __linalg_31;
+pub use traits::Solve as __linalg_32;
+pub use traits::Lstsq as __linalg_33;
+pub use traits::TryAddAssign as __linalg_34;
+pub use traits::TryEval as __linalg_35;
+pub use traits::TryInv as __linalg_36;
+pub use traits::TrySet as __linalg_37;
+pub use traits::Det as __linalg_38;
+pub use traits::MatrixNorm as __linalg_39;
+pub use traits::ConjTranspose as __linalg_40;
+pub use traits::Conjugate as __linalg_41;
+pub use traits::ComplexParts as __linalg_42;
+pub use traits::Triangular as __linalg_43;
+pub use traits::Sym as __linalg_44;
+pub use traits::Fold as __linalg_45;
+pub use traits::Map as __linalg_46;
+pub use traits::MapInplace as __linalg_47;
+pub use traits::AxisReduce as __linalg_48;
+pub use traits::TryMul as __linalg_49;
+pub use traits::TrySolve as __linalg_50;
@@ -0,0 +1,128 @@
+use std::cmp;
+
+use blas::Copy;
+use complex::Complex;
+use lapack::{Geqrf, Orgqr, Ormqr};
+use onezero::Zero;
+
+use ops;
+use traits::{Matrix, Slice, SliceMut, Triangular};
+use {Mat, Qr, SubMat, UpperTri};
+
+impl<T> Mat<T> {
+    /// Computes the QR factorization of a matrix
+    ///
+    /// NOTE The matrix is taken by value, its buffer will be re-used to store the factors
+    pub fn qr(mut self) -> Qr<T> where T: Geqrf {
+        unsafe {
+            let tau = ops::geqrf(self.slice_mut(..));
+
+            Qr {
+                qr: self,
+                tau: tau,
+            }
+        }
+    }
+}
+
+impl<T> Qr<T> {
+    /// Returns the `m x k` matrix `Q`, whose columns are orthonormal
+    ///
+    /// Where `(m, n)` is the size of the factorized matrix, and `k = min(m, n)`
+    pub fn q(&self) -> Mat<T> where T: Copy + Orgqr {
+        unsafe {
+            let k = cmp::min(self.qr.nrows(), self.qr.ncols());
+            let mut q = Mat::from(self.qr.slice((.., ..k)));
+
+            ops::orgqr(q.slice_mut(..), &self.tau);
+
+            q
+        }
+    }
+
+    /// Returns `Q * C`, where `Q` is the full `m x m` orthogonal (unitary) factor
+    ///
+    /// Where `m` is the number of rows of the factorized matrix. `Q` is never formed, instead the
+    /// elementary reflectors stored in the factorization are applied to `C`.
+    ///
+    /// NOTE `C` is taken by value, its buffer will be re-used to store the product
+    ///
+    /// # Panics
+    ///
+    /// If `C.nrows() != m`
+    pub fn q_mul(&self, mut c: Mat<T>) -> Mat<T> where T: Ormqr {
+        unsafe {
+            assert_eq!(self.qr.nrows(), c.nrows());
+
+            ops::ormqr(&b'N', self.qr.slice(..), &self.tau, c.slice_mut(..));
+
+            c
+        }
+    }
+
+    /// Returns the `k x n` upper triangular matrix `R`
+    ///
+    /// Where `(m, n)` is the size of the factorized matrix, and `k = min(m, n)`
+    pub fn r(&self) -> Mat<T> where T: Clone + Zero {
+        let (m, n) = self.qr.size();
+        let k = cmp::min(m, n);
+
+        Mat::from_fn((k, n), |(row, col)| {
+            if row > col {
+                T::zero()
+            } else {
+                self.qr[(row, col)].clone()
+            }
+        })
+    }
+
+    /// Returns an upper triangular view into the leading `k x k` block of `R`
+    ///
+    /// Where `(m, n)` is the size of the factorized matrix, and `k = min(m, n)`. Unlike `r()`, no
+    /// elements are copied. If `m >= n`, the view spans the whole `R` factor.
+    pub fn r_tri(&self) -> UpperTri<SubMat<T>> {
+        let k = cmp::min(self.qr.nrows(), self.qr.ncols());
+
+        self.qr.slice((..k, ..k)).upper()
+    }
+
+    /// Returns the size of the factorized matrix
+    pub fn size(&self) -> (u32, u32) {
+        self.qr.size()
+    }
+}
+
+macro_rules! qh_mul {
+    ($($t:ty => $trans:expr),+,) => {
+        $(
+            impl Qr<$t> {
+                /// Returns `Q^H * C`, where `Q` is the full `m x m` orthogonal (unitary) factor
+                ///
+                /// Where `m` is the number of rows of the factorized matrix, and `Q^H` is the
+                /// (conjugate) transpose of `Q`.
+                ///
+                /// NOTE `C` is taken by value, its buffer will be re-used to store the product
+                ///
+                /// # Panics
+                ///
+                /// If `C.nrows() != m`
+                pub fn qh_mul(&self, mut c: Mat<$t>) -> Mat<$t> {
+                    unsafe {
+                        assert_eq!(self.qr.nrows(), c.nrows());
+
+                        ops::ormqr($trans, self.qr.slice(..), &self.tau, c.slice_mut(..));
+
+                        c
+                    }
+                }
+            }
+         )+
+    }
+}
+
+qh_mul! {
+    f32 => &b'T',
+    f64 => &b'T',
+    Complex<f32> => &b'C',
+    Complex<f64> => &b'C',
+}
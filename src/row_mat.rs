@@ -0,0 +1,100 @@
+use onezero::{One, Zero};
+
+use {Mat, RowMat, Transposed};
+
+// NB A row-major `m x n` matrix has the same memory layout as a column-major `n x m` matrix, all
+// these constructors build the latter and wrap it in `Transposed`
+impl<T> RowMat<T> {
+    /// Creates an owned row-major matrix with dimensions `(nrows, ncols)` filled with `elem`
+    /// values
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31 ||`
+    /// - `nrows * ncols > usize::max_value()`
+    pub fn from_elem((nrows, ncols): (u32, u32), elem: T) -> RowMat<T> where T: Clone {
+        Transposed(Mat::from_elem((ncols, nrows), elem))
+    }
+
+    /// Creates an owned row-major matrix with dimensions `(nrows, ncols)` where each element
+    /// gets initialized using the function `f`
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31 ||`
+    /// - `nrows * ncols > usize::max_value()`
+    pub fn from_fn<F>((nrows, ncols): (u32, u32), mut f: F) -> RowMat<T> where
+        F: FnMut((u32, u32)) -> T,
+    {
+        Transposed(Mat::from_fn((ncols, nrows), |(col, row)| f((row, col))))
+    }
+
+    /// Creates an owned row-major matrix with dimensions `(nrows, ncols)` from a vector that
+    /// holds its elements in row major order
+    ///
+    /// The allocation of `elems` is re-used, no elements are copied (unless `elems` has spare
+    /// capacity, in which case the allocation is shrunk to fit first).
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows * ncols != elems.len() ||`
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31`
+    pub fn from_vec((nrows, ncols): (u32, u32), elems: Vec<T>) -> RowMat<T> {
+        Transposed(Mat::from_vec((ncols, nrows), elems))
+    }
+
+    /// Creates an owned row-major matrix with dimensions `(nrows, ncols)` filled with ones
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31 ||`
+    /// - `nrows * ncols > usize::max_value()`
+    pub fn ones((nrows, ncols): (u32, u32)) -> RowMat<T> where T: Clone + One {
+        RowMat::from_elem((nrows, ncols), T::one())
+    }
+
+    /// Creates an owned row-major matrix with dimensions `(nrows, ncols)` filled with zeros
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31 ||`
+    /// - `nrows * ncols > usize::max_value()`
+    pub fn zeros((nrows, ncols): (u32, u32)) -> RowMat<T> where T: Clone + Zero {
+        RowMat::from_elem((nrows, ncols), T::zero())
+    }
+
+    /// Converts the matrix into a vector that holds its elements in row major order, the
+    /// allocation is re-used
+    pub fn into_vec(self) -> Vec<T> {
+        self.0.into_vec()
+    }
+
+    /// Changes the dimensions of the matrix to `(nrows, ncols)`, the elements keep their row
+    /// major order and the allocation is re-used
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `nrows * ncols != self.nrows() * self.ncols() ||`
+    /// - `nrows > 2^31 ||`
+    /// - `ncols > 2^31`
+    pub fn reshape(self, (nrows, ncols): (u32, u32)) -> RowMat<T> {
+        Transposed(self.0.reshape((ncols, nrows)))
+    }
+}
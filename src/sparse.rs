@@ -0,0 +1,126 @@
+use std::ops::Add;
+
+use cast::From;
+
+use traits::{Matrix, Transpose};
+use {SparseFormat, SparseMat};
+
+impl<T> SparseMat<T> {
+    /// Creates a sparse matrix with dimensions `(nrows, ncols)` stored in the given `format`, from
+    /// a list of `(row, col, value)` triplets
+    ///
+    /// The values of duplicated entries are summed, the triplets can be given in any order.
+    ///
+    /// # Panics
+    ///
+    /// If any `row >= nrows` or any `col >= ncols`
+    pub fn from_triplets(
+        (nrows, ncols): (u32, u32),
+        format: SparseFormat,
+        triplets: &[(u32, u32, T)],
+    ) -> SparseMat<T> where
+        T: Add<Output=T> + Copy,
+    {
+        let mut entries = triplets.iter().map(|&(row, col, value)| {
+            assert!(row < nrows && col < ncols);
+
+            match format {
+                SparseFormat::Csc => (col, row, value),
+                SparseFormat::Csr => (row, col, value),
+            }
+        }).collect::<Vec<_>>();
+
+        entries.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        let nmajor = usize::from(match format {
+            SparseFormat::Csc => ncols,
+            SparseFormat::Csr => nrows,
+        });
+
+        let mut data: Vec<T> = Vec::with_capacity(entries.len());
+        let mut indices = Vec::with_capacity(entries.len());
+        let mut indptr = Vec::with_capacity(nmajor + 1);
+        let mut last = None;
+
+        for (major, minor, value) in entries {
+            if last == Some((major, minor)) {
+                let n = data.len();
+
+                data[n - 1] = data[n - 1] + value;
+            } else {
+                // NB closes the preceding major indices, including the empty ones
+                while indptr.len() <= usize::from(major) {
+                    indptr.push(data.len());
+                }
+
+                data.push(value);
+                indices.push(minor);
+                last = Some((major, minor));
+            }
+        }
+
+        while indptr.len() <= nmajor {
+            indptr.push(data.len());
+        }
+
+        SparseMat {
+            data: data,
+            format: format,
+            indices: indices,
+            indptr: indptr,
+            ncols: ncols,
+            nrows: nrows,
+        }
+    }
+
+    /// Returns the storage format of the matrix
+    pub fn format(&self) -> SparseFormat {
+        self.format
+    }
+
+    /// Returns the number of stored elements
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl<T> Matrix for SparseMat<T> {
+    type Elem = T;
+
+    fn ncols(&self) -> u32 {
+        self.ncols
+    }
+
+    fn nrows(&self) -> u32 {
+        self.nrows
+    }
+}
+
+impl<'a, T> Matrix for &'a SparseMat<T> {
+    type Elem = T;
+
+    fn ncols(&self) -> u32 {
+        (**self).ncols()
+    }
+
+    fn nrows(&self) -> u32 {
+        (**self).nrows()
+    }
+}
+
+/// Switches the format of the matrix, no data is moved
+impl<T> Transpose for SparseMat<T> {
+    type Output = SparseMat<T>;
+
+    fn t(self) -> SparseMat<T> {
+        SparseMat {
+            format: match self.format {
+                SparseFormat::Csc => SparseFormat::Csr,
+                SparseFormat::Csr => SparseFormat::Csc,
+            },
+            ncols: self.nrows,
+            nrows: self.ncols,
+            ..self
+        }
+    }
+}
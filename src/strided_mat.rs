@@ -0,0 +1,152 @@
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Index;
+
+use cast::From;
+use complex::Complex;
+use core::nonzero::NonZero;
+use extract::Extract;
+
+use traits::{ComplexParts, Matrix, MatrixCol, MatrixRow, Slice};
+use {Col, Mat, Row, StridedMat, SubMat, SubMatMut};
+
+impl<'a, T> StridedMat<'a, T> {
+    unsafe fn new(
+        data: *mut T,
+        (nrows, ncols): (i32, i32),
+        step: i32,
+        stride: i32,
+    ) -> StridedMat<'a, T> {
+        debug_assert!(ncols >= 0);
+        debug_assert!(nrows >= 0);
+        debug_assert!(step > 0);
+        debug_assert!(stride >= 0);
+
+        StridedMat {
+            _marker: PhantomData,
+            data: NonZero::new(data),
+            ncols: ncols,
+            nrows: nrows,
+            step: step,
+            stride: stride,
+        }
+    }
+
+    unsafe fn raw_index(&self, (row, col): (u32, u32)) -> *mut T {
+        assert!(row < self.nrows() && col < self.ncols());
+
+        let row = isize::from(row);
+        let col = isize::from(col);
+        let step = isize::from(self.step);
+        let stride = isize::from(self.stride);
+
+        self.data.offset(col * stride + row * step)
+    }
+}
+
+impl<'a, T> Index<(u32, u32)> for StridedMat<'a, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (u32, u32)) -> &T {
+        unsafe {
+            &*self.raw_index((row, col))
+        }
+    }
+}
+
+impl<'a, T> Matrix for StridedMat<'a, T> {
+    type Elem = T;
+
+    fn ncols(&self) -> u32 {
+        unsafe {
+            u32::from(self.ncols).extract()
+        }
+    }
+
+    fn nrows(&self) -> u32 {
+        unsafe {
+            u32::from(self.nrows).extract()
+        }
+    }
+}
+
+impl<'a, T> MatrixCol for StridedMat<'a, T> {
+    fn col(&self, i: u32) -> Col<T> {
+        unsafe {
+            assert!(i < self.ncols());
+
+            let stride = isize::from(self.stride);
+            let ptr = self.data.offset(isize::from(i) * stride);
+
+            Col(::strided::Slice::new(ptr, self.nrows, self.step))
+        }
+    }
+}
+
+impl<'a, T> MatrixRow for StridedMat<'a, T> {
+    fn row(&self, i: u32) -> Row<T> {
+        unsafe {
+            assert!(i < self.nrows());
+
+            let step = isize::from(self.step);
+            let ptr = self.data.offset(isize::from(i) * step);
+
+            // NB `Slice` requires a positive stride, any stride works if the row is empty
+            let stride = if self.ncols == 0 { 1 } else { self.stride };
+
+            Row(::strided::Slice::new(ptr, self.ncols, stride))
+        }
+    }
+}
+
+// NOTE Core
+impl<'a, 'b, T> ComplexParts<'a> for SubMat<'b, Complex<T>> {
+    type Output = StridedMat<'b, T>;
+
+    fn imag(&'a self) -> StridedMat<'b, T> {
+        unsafe {
+            part(*self, 1)
+        }
+    }
+
+    fn real(&'a self) -> StridedMat<'b, T> {
+        unsafe {
+            part(*self, 0)
+        }
+    }
+}
+
+impl<'a, T> ComplexParts<'a> for Mat<Complex<T>> {
+    type Output = StridedMat<'a, T>;
+
+    fn imag(&'a self) -> StridedMat<'a, T> {
+        self.slice(..).imag()
+    }
+
+    fn real(&'a self) -> StridedMat<'a, T> {
+        self.slice(..).real()
+    }
+}
+
+impl<'a, 'b, T> ComplexParts<'a> for SubMatMut<'b, Complex<T>> {
+    type Output = StridedMat<'a, T>;
+
+    fn imag(&'a self) -> StridedMat<'a, T> {
+        self.slice(..).imag()
+    }
+
+    fn real(&'a self) -> StridedMat<'a, T> {
+        self.slice(..).real()
+    }
+}
+
+/// Returns a view into the real (`offset = 0`) or imaginary (`offset = 1`) parts of `m`
+// NB This relies on `Complex<T>` having the same layout as `[T; 2]`
+unsafe fn part<'a, T>(m: SubMat<'a, Complex<T>>, offset: isize) -> StridedMat<'a, T> {
+    // NB the sizes are constants, this check gets optimized away
+    assert_eq!(mem::size_of::<Complex<T>>(), 2 * mem::size_of::<T>());
+
+    let data = (*m.data as *mut T).offset(offset);
+
+    StridedMat::new(data, (m.nrows, m.ncols), 2, 2 * m.stride)
+}
@@ -0,0 +1,290 @@
+use std::{cmp, ptr};
+
+use cast::From as _0;
+use complex::Complex;
+use extract::Extract;
+
+use traits::Lapack;
+use {ColVec, Error, Mat, SubMat, Tor};
+
+/// Dispatches to the real or complex flavor of LAPACK's `gesdd`
+trait Sdd: Sized {
+    /// The type of the singular values
+    type Real;
+
+    /// Computes the singular value decomposition of `A`: `A = U * S * Vt`
+    ///
+    /// `jobz` is one of: `b'A'` (full `U` and `Vt`), `b'S'` (thin `U` and `Vt`) or `b'N'` (only
+    /// the singular values, `U` and `Vt` will be empty)
+    unsafe fn gesdd(
+        jobz: u8,
+        a: Mat<Self>,
+    ) -> Result<(Mat<Self>, ColVec<Self::Real>, Mat<Self>), Error>;
+}
+
+/// Returns the size of `U` and `Vt` for the given `jobz`
+fn sizes(jobz: u8, m: i32, n: i32) -> ((i32, i32), (i32, i32)) {
+    let k = cmp::min(m, n);
+
+    match jobz {
+        b'A' => ((m, m), (n, n)),
+        b'S' => ((m, k), (k, n)),
+        _ => ((0, 0), (0, 0)),
+    }
+}
+
+/// Maps the `info` returned by `gesdd` to an error
+///
+/// NB `gesdd` doesn't report the number of iterations that it performed, its (positive) `info` is
+/// reported in their place
+fn check_info(info: i32) -> Result<(), Error> {
+    assert!(info >= 0);
+
+    if info == 0 {
+        Ok(())
+    } else {
+        Err(Error::NoConvergence { iterations: u32::from_(info).extract() })
+    }
+}
+
+macro_rules! sdd {
+    ($($t:ident),+) => {
+        $(
+            impl Sdd for $t {
+                type Real = $t;
+
+                unsafe fn gesdd(
+                    jobz: u8,
+                    a: Mat<$t>,
+                ) -> Result<(Mat<$t>, ColVec<$t>, Mat<$t>), Error> {
+                    let backend = <$t as Lapack>::lapack_backend();
+                    let (m, n) = (a.nrows, a.ncols);
+                    let k = cmp::min(m, n);
+                    let (u_size, vt_size) = sizes(jobz, m, n);
+
+                    let s = ColVec(Tor::uninitialized(k));
+                    let u = Mat::uninitialized(u_size);
+                    let vt = Mat::uninitialized(vt_size);
+                    let mut iwork = Vec::with_capacity(usize::from_(8 * k).extract());
+
+                    let ref jobz = jobz;
+                    let ref lda = cmp::max(1, m);
+                    let ref ldu = cmp::max(1, u.nrows);
+                    let ref ldvt = cmp::max(1, vt.nrows);
+                    let ref mut info = 0;
+
+                    let a_ = *a.data;
+                    let s_ = *s.0.data;
+                    let u_ = *u.data;
+                    let vt_ = *vt.data;
+                    let iwork = iwork.as_mut_ptr();
+
+                    // workspace query
+                    let mut lwork: $t = 0.;
+                    backend.gesdd(
+                        jobz, &m, &n, a_, lda, s_, u_, ldu, vt_, ldvt, &mut lwork, &-1,
+                        ptr::null_mut(), iwork, info,
+                    );
+
+                    assert!(*info == 0);
+
+                    let ref lwork = lwork as i32;
+                    let mut work = Vec::with_capacity(usize::from_(*lwork).extract());
+                    let work = work.as_mut_ptr();
+
+                    backend.gesdd(
+                        jobz, &m, &n, a_, lda, s_, u_, ldu, vt_, ldvt, work, lwork,
+                        ptr::null_mut(), iwork, info,
+                    );
+
+                    try!(check_info(*info));
+
+                    Ok((u, s, vt))
+                }
+            }
+
+            impl Sdd for Complex<$t> {
+                type Real = $t;
+
+                unsafe fn gesdd(
+                    jobz: u8,
+                    a: Mat<Complex<$t>>,
+                ) -> Result<(Mat<Complex<$t>>, ColVec<$t>, Mat<Complex<$t>>), Error> {
+                    let backend = <Complex<$t> as Lapack>::lapack_backend();
+                    let (m, n) = (a.nrows, a.ncols);
+                    let k = cmp::min(m, n);
+                    let (u_size, vt_size) = sizes(jobz, m, n);
+
+                    let s = ColVec(Tor::uninitialized(k));
+                    let u = Mat::uninitialized(u_size);
+                    let vt = Mat::uninitialized(vt_size);
+                    let mut iwork = Vec::with_capacity(usize::from_(8 * k).extract());
+                    let lrwork = if jobz == b'N' {
+                        7 * k
+                    } else {
+                        k * cmp::max(5 * k + 7, 2 * cmp::max(m, n) + 2 * k + 1)
+                    };
+                    let lrwork = usize::from_(cmp::max(1, lrwork)).extract();
+                    let mut rwork = Vec::with_capacity(lrwork);
+
+                    let ref jobz = jobz;
+                    let ref lda = cmp::max(1, m);
+                    let ref ldu = cmp::max(1, u.nrows);
+                    let ref ldvt = cmp::max(1, vt.nrows);
+                    let ref mut info = 0;
+
+                    let a_ = *a.data;
+                    let s_ = *s.0.data;
+                    let u_ = *u.data;
+                    let vt_ = *vt.data;
+                    let iwork = iwork.as_mut_ptr();
+                    let rwork = rwork.as_mut_ptr();
+
+                    // workspace query
+                    let mut lwork = Complex { re: 0., im: 0. };
+                    backend.gesdd(
+                        jobz, &m, &n, a_, lda, s_, u_, ldu, vt_, ldvt, &mut lwork, &-1, rwork,
+                        iwork, info,
+                    );
+
+                    assert!(*info == 0);
+
+                    let ref lwork = lwork.re as i32;
+                    let mut work = Vec::with_capacity(usize::from_(*lwork).extract());
+                    let work = work.as_mut_ptr();
+
+                    backend.gesdd(
+                        jobz, &m, &n, a_, lda, s_, u_, ldu, vt_, ldvt, work, lwork, rwork, iwork,
+                        info,
+                    );
+
+                    try!(check_info(*info));
+
+                    Ok((u, s, vt))
+                }
+            }
+         )+
+    }
+}
+
+sdd!(f32, f64);
+
+macro_rules! svd {
+    ($($t:ty => $real:ty),+,) => {
+        $(
+            impl Mat<$t> {
+                /// Computes the singular value decomposition of a `m x n` matrix: `A = U * S * Vt`
+                ///
+                /// Returns the `m x m` unitary matrix `U`, the singular values (the diagonal of
+                /// `S`) in descending order, and the `n x n` unitary matrix `Vt`
+                ///
+                /// NOTE The matrix is taken by value, its buffer is used as workspace
+                ///
+                /// # Panics
+                ///
+                /// If the decomposition doesn't converge, see `try_svd()`
+                pub fn svd(self) -> (Mat<$t>, ColVec<$real>, Mat<$t>) {
+                    self.try_svd().unwrap()
+                }
+
+                /// Computes the thin singular value decomposition of a `m x n` matrix
+                ///
+                /// Same as `svd()`, but only the first `k = min(m, n)` columns of `U`, and the
+                /// first `k` rows of `Vt` are computed, i.e. `U` is `m x k` and `Vt` is `k x n`
+                ///
+                /// # Panics
+                ///
+                /// If the decomposition doesn't converge, see `try_svd_thin()`
+                pub fn svd_thin(self) -> (Mat<$t>, ColVec<$real>, Mat<$t>) {
+                    self.try_svd_thin().unwrap()
+                }
+
+                /// Returns the singular values of the matrix in descending order
+                ///
+                /// NOTE This is cheaper than `svd()`, because the singular vectors aren't computed
+                ///
+                /// # Panics
+                ///
+                /// If the decomposition doesn't converge, see `try_singular_values()`
+                pub fn singular_values(self) -> ColVec<$real> {
+                    self.try_singular_values().unwrap()
+                }
+
+                /// Like `svd()`, but returns an error instead of panicking
+                ///
+                /// # Errors
+                ///
+                /// `NoConvergence` if the decomposition doesn't converge. LAPACK doesn't report
+                /// the number of iterations, `iterations` holds its (positive) `info` instead
+                pub fn try_svd(self) -> Result<(Mat<$t>, ColVec<$real>, Mat<$t>), Error> {
+                    unsafe {
+                        Sdd::gesdd(b'A', self)
+                    }
+                }
+
+                /// Like `svd_thin()`, but returns an error instead of panicking
+                ///
+                /// # Errors
+                ///
+                /// `NoConvergence` if the decomposition doesn't converge, see `try_svd()`
+                pub fn try_svd_thin(self) -> Result<(Mat<$t>, ColVec<$real>, Mat<$t>), Error> {
+                    unsafe {
+                        Sdd::gesdd(b'S', self)
+                    }
+                }
+
+                /// Like `singular_values()`, but returns an error instead of panicking
+                ///
+                /// # Errors
+                ///
+                /// `NoConvergence` if the decomposition doesn't converge, see `try_svd()`
+                pub fn try_singular_values(self) -> Result<ColVec<$real>, Error> {
+                    unsafe {
+                        Sdd::gesdd(b'N', self).map(|(_, s, _)| s)
+                    }
+                }
+            }
+
+            impl<'a> SubMat<'a, $t> {
+                /// Computes the singular value decomposition of a `m x n` matrix
+                ///
+                /// See `Mat::svd()`. NOTE The view is copied into a new buffer
+                pub fn svd(self) -> (Mat<$t>, ColVec<$real>, Mat<$t>) {
+                    Mat::from(self).svd()
+                }
+
+                /// See `Mat::svd_thin()`. NOTE The view is copied into a new buffer
+                pub fn svd_thin(self) -> (Mat<$t>, ColVec<$real>, Mat<$t>) {
+                    Mat::from(self).svd_thin()
+                }
+
+                /// See `Mat::singular_values()`. NOTE The view is copied into a new buffer
+                pub fn singular_values(self) -> ColVec<$real> {
+                    Mat::from(self).singular_values()
+                }
+
+                /// See `Mat::try_svd()`. NOTE The view is copied into a new buffer
+                pub fn try_svd(self) -> Result<(Mat<$t>, ColVec<$real>, Mat<$t>), Error> {
+                    Mat::from(self).try_svd()
+                }
+
+                /// See `Mat::try_svd_thin()`. NOTE The view is copied into a new buffer
+                pub fn try_svd_thin(self) -> Result<(Mat<$t>, ColVec<$real>, Mat<$t>), Error> {
+                    Mat::from(self).try_svd_thin()
+                }
+
+                /// See `Mat::try_singular_values()`. NOTE The view is copied into a new buffer
+                pub fn try_singular_values(self) -> Result<ColVec<$real>, Error> {
+                    Mat::from(self).try_singular_values()
+                }
+            }
+         )+
+    }
+}
+
+svd! {
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => f32,
+    Complex<f64> => f64,
+}
@@ -0,0 +1,42 @@
+use traits::{Matrix, Slice, Sym};
+use {Mat, SubMat, SubMatMut, Symmetric, Uplo};
+
+impl<'a, T> Matrix for Symmetric<SubMat<'a, T>> {
+    type Elem = T;
+
+    fn nrows(&self) -> u32 {
+        self.0.nrows()
+    }
+
+    fn ncols(&self) -> u32 {
+        self.0.ncols()
+    }
+}
+
+// NOTE Core
+impl<'a, T> Sym for SubMat<'a, T> {
+    type Output = Symmetric<SubMat<'a, T>>;
+
+    fn sym(self, uplo: Uplo) -> Symmetric<SubMat<'a, T>> {
+        assert_eq!(self.nrows(), self.ncols());
+
+        Symmetric(self, uplo)
+    }
+}
+
+macro_rules! forward {
+    ($($ty:ty),+) => {
+        $(
+            // NOTE Forward
+            impl<'a, 'b, T> Sym for $ty {
+                type Output = Symmetric<SubMat<'a, T>>;
+
+                fn sym(self, uplo: Uplo) -> Symmetric<SubMat<'a, T>> {
+                    self.slice(..).sym(uplo)
+                }
+            }
+         )+
+    }
+}
+
+forward!(&'a Mat<T>, &'a SubMatMut<'b, T>);
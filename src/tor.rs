@@ -83,6 +83,16 @@ impl<T> Tor<T> {
         }
     }
 
+    pub fn into_vec(self) -> Vec<T> {
+        unsafe {
+            let len = usize::from(self.len).extract();
+            let v = Vec::from_raw_parts(*self.data, len, len);
+            mem::forget(self);
+
+            v
+        }
+    }
+
     pub fn len(&self) -> u32 {
         unsafe {
             u32::from(self.len).extract()
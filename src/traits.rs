@@ -4,11 +4,393 @@
 //!  programming, and will remain unstable. What's guaranteed to be (somewhat) stable is the
 //!  functionality, i.e. the methods, provided by them.
 
+use std::cell::RefCell;
+use std::mem;
+use std::ops::{Add, Mul};
+use std::rc::Rc;
+use std::thread::LocalKey;
+
+use onezero::{One, Zero};
+
 use {
-    Col, ColMut, Cols, ColsMut, Diag, DiagMut, HStripes, HStripesMut, Row, RowMut, Rows, RowsMut,
-    VStripes, VStripesMut, SubMat, SubMatMut,
+    Col, ColMut, ColVec, Cols, ColsMut, Diag, DiagMut, Diagonal, Error, HStripes, HStripesMut,
+    NormKind, Row, RowMut, RowVec, Rows, RowsMut, Side, Uplo, VStripes, VStripesMut, SubMat,
+    SubMatMut,
 };
 
+/// Reductions along the rows or the columns of a matrix
+///
+/// The `*_cols` methods reduce each column to a single element, and return a row vector. The
+/// `*_rows` methods reduce each row to a single element, and return a column vector.
+pub trait AxisReduce: Matrix {
+    /// Returns the position of the largest element of each column
+    ///
+    /// NaN elements are ignored, unless the whole column is NaN.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no rows
+    fn argmax_cols(&self) -> RowVec<u32> where Self::Elem: Copy + PartialOrd;
+
+    /// Returns the position of the largest element of each row
+    ///
+    /// NaN elements are ignored, unless the whole row is NaN.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no columns
+    fn argmax_rows(&self) -> ColVec<u32> where Self::Elem: Copy + PartialOrd;
+
+    /// Returns the position of the smallest element of each column
+    ///
+    /// NaN elements are ignored, unless the whole column is NaN.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no rows
+    fn argmin_cols(&self) -> RowVec<u32> where Self::Elem: Copy + PartialOrd;
+
+    /// Returns the position of the smallest element of each row
+    ///
+    /// NaN elements are ignored, unless the whole row is NaN.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no columns
+    fn argmin_rows(&self) -> ColVec<u32> where Self::Elem: Copy + PartialOrd;
+
+    /// Returns the largest element of each column
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no rows
+    fn max_cols(&self) -> RowVec<Self::Elem> where Self::Elem: Copy + PartialOrd;
+
+    /// Returns the largest element of each row
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no columns
+    fn max_rows(&self) -> ColVec<Self::Elem> where Self::Elem: Copy + PartialOrd;
+
+    /// Returns the mean of each column
+    ///
+    /// NOTE If the matrix has no rows, the means of a floating point matrix are `NaN`
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no rows and its elements are integers (division by zero)
+    fn mean_cols(&self) -> RowVec<Self::Elem> where Self::Elem: Field;
+
+    /// Returns the mean of each row
+    ///
+    /// NOTE If the matrix has no columns, the means of a floating point matrix are `NaN`
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no columns and its elements are integers (division by zero)
+    fn mean_rows(&self) -> ColVec<Self::Elem> where Self::Elem: Field;
+
+    /// Returns the smallest element of each column
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no rows
+    fn min_cols(&self) -> RowVec<Self::Elem> where Self::Elem: Copy + PartialOrd;
+
+    /// Returns the smallest element of each row
+    ///
+    /// # Panics
+    ///
+    /// If the matrix has no columns
+    fn min_rows(&self) -> ColVec<Self::Elem> where Self::Elem: Copy + PartialOrd;
+
+    /// Returns the sum of each column
+    fn sum_cols(&self) -> RowVec<Self::Elem> where Self::Elem: Scalar;
+
+    /// Returns the sum of each row
+    fn sum_rows(&self) -> ColVec<Self::Elem> where Self::Elem: Scalar;
+}
+
+/// The BLAS routines that perform the numerical work on elements of type `T`
+///
+/// The routines follow the conventions of the reference BLAS implementation: matrices are stored
+/// in column major order, scalars are passed by reference and arrays by pointer. See its
+/// documentation for the meaning of each argument.
+///
+/// The default backend is `Ffi`, or `Native` if the `blas` feature is disabled or the `pure-rust`
+/// feature is enabled. The backend is per thread: use `set_backend` to replace the backend used
+/// by the current thread, e.g. to count the calls made by some operation, or to plug in custom
+/// kernels. Other threads keep using their own backend.
+pub trait Backend<T> where T: Element {
+    /// y := alpha * x + y
+    unsafe fn axpy(&self, n: &i32, alpha: &T, x: *const T, incx: &i32, y: *mut T, incy: &i32);
+
+    /// y := x
+    unsafe fn copy(&self, n: &i32, x: *const T, incx: &i32, y: *mut T, incy: &i32);
+
+    /// Returns `x' * y`, where `x` is *not* conjugated
+    unsafe fn dot(&self, n: &i32, x: *const T, incx: &i32, y: *const T, incy: &i32) -> T;
+
+    /// y := alpha * op(A) * x + beta * y, where `A` is a band matrix with `kl` sub-diagonals and
+    /// `ku` super-diagonals
+    unsafe fn gbmv(
+        &self,
+        trans: &::Transpose,
+        m: &i32,
+        n: &i32,
+        kl: &i32,
+        ku: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    );
+
+    /// C := alpha * op(A) * op(B) + beta * C
+    unsafe fn gemm(
+        &self,
+        transa: &::Transpose,
+        transb: &::Transpose,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *const T,
+        ldb: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    );
+
+    /// y := alpha * op(A) * x + beta * y
+    unsafe fn gemv(
+        &self,
+        trans: &::Transpose,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    );
+
+    /// A := alpha * x * y' + A, `y` is not conjugated
+    unsafe fn ger(
+        &self,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        x: *const T,
+        incx: &i32,
+        y: *const T,
+        incy: &i32,
+        a: *mut T,
+        lda: &i32,
+    );
+
+    /// Returns the euclidean norm of `x`
+    unsafe fn nrm2(&self, n: &i32, x: *const T, incx: &i32) -> T::Real where T: Normed;
+
+    /// x := alpha * x, where `alpha` is a real number
+    ///
+    /// NOTE Only used when `T` is a complex number
+    unsafe fn rscal(&self, n: &i32, alpha: &T::Real, x: *mut T, incx: &i32) where T: Normed;
+
+    /// x := alpha * x
+    unsafe fn scal(&self, n: &i32, alpha: &T, x: *mut T, incx: &i32);
+
+    /// C := alpha * A * B + beta * C, or C := alpha * B * A + beta * C, where `A` is symmetric
+    unsafe fn symm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *const T,
+        ldb: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    );
+
+    /// y := alpha * A * x + beta * y, where `A` is symmetric
+    unsafe fn symv(
+        &self,
+        uplo: &Uplo,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        x: *const T,
+        incx: &i32,
+        beta: &T,
+        y: *mut T,
+        incy: &i32,
+    );
+
+    /// C := alpha * op(A) * op(A)' + beta * C, where only the `uplo` triangular part of `C` is
+    /// updated
+    unsafe fn syrk(
+        &self,
+        uplo: &Uplo,
+        trans: &::Transpose,
+        n: &i32,
+        k: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        beta: &T,
+        c: *mut T,
+        ldc: &i32,
+    );
+
+    /// B := alpha * op(A) * B, or B := alpha * B * op(A), where `A` is triangular
+    unsafe fn trmm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        transa: &::Transpose,
+        diag: &Diagonal,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+    );
+
+    /// x := op(A) * x, where `A` is triangular
+    unsafe fn trmv(
+        &self,
+        uplo: &Uplo,
+        trans: &::Transpose,
+        diag: &Diagonal,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        x: *mut T,
+        incx: &i32,
+    );
+
+    /// B := alpha * op(A)^-1 * B, or B := alpha * B * op(A)^-1, where `A` is triangular
+    unsafe fn trsm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        transa: &::Transpose,
+        diag: &Diagonal,
+        m: &i32,
+        n: &i32,
+        alpha: &T,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+    ) where
+        T: Field;
+
+    /// x := op(A)^-1 * x, where `A` is triangular
+    unsafe fn trsv(
+        &self,
+        uplo: &Uplo,
+        trans: &::Transpose,
+        diag: &Diagonal,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        x: *mut T,
+        incx: &i32,
+    ) where
+        T: Field;
+}
+
+/// "Immutable" views into the real and imaginary parts of a complex matrix
+///
+/// NOTE The views assume that `Complex<T>` has the same memory layout as `[T; 2]`: the real part
+/// followed by the imaginary part, without padding. This is checked when the view is created.
+pub trait ComplexParts<'a> {
+    /// The view
+    type Output;
+
+    /// Returns a view into the imaginary parts of the elements
+    fn imag(&'a self) -> Self::Output;
+
+    /// Returns a view into the real parts of the elements
+    fn real(&'a self) -> Self::Output;
+}
+
+/// Lazy conjugate transposition
+pub trait ConjTranspose {
+    /// The conjugated and transposed data
+    type Output;
+
+    /// Returns the conjugate (Hermitian) transpose of the input
+    fn h(self) -> Self::Output;
+}
+
+/// Lazy conjugation
+pub trait Conjugate {
+    /// The conjugated data
+    type Output;
+
+    /// Returns the complex conjugate of the input
+    fn conj(self) -> Self::Output;
+}
+
+/// The determinant of a square matrix
+pub trait Det {
+    /// The type of the determinant
+    type Output;
+    /// The type of the logarithm of the absolute value of the determinant
+    type Real;
+
+    /// Returns the determinant of the matrix
+    fn det(&self) -> Self::Output;
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant
+    fn slogdet(&self) -> (Self::Output, Self::Real);
+}
+
+/// An element type, i.e. a type that can be stored in a matrix and operated on by a `Backend`
+///
+/// `f32`, `f64`, `Complex<f32>`, `Complex<f64>` and the primitive integers implement this trait.
+/// Use the `element!` macro to implement this trait for other types.
+///
+/// NOTE Matrices of user-defined element types can only be scaled from the right, `A * alpha`:
+/// the coherence rules forbid this crate from implementing `Mul<Mat<T>>` for a foreign `T`.
+pub trait Element: Copy + 'static {
+    /// The type of the real part of the element
+    type Real;
+
+    /// Returns the per-thread storage of the backend
+    fn registry() -> &'static LocalKey<RefCell<Rc<Box<Backend<Self>>>>>;
+
+    /// Returns the backend used by the current thread
+    fn backend() -> Rc<Box<Backend<Self>>> {
+        Self::registry().with(|backend| backend.borrow().clone())
+    }
+
+    /// Replaces the backend used by the current thread, returns the previous backend
+    fn set_backend(backend: Box<Backend<Self>>) -> Rc<Box<Backend<Self>>> {
+        Self::registry().with(|current| mem::replace(&mut *current.borrow_mut(), Rc::new(backend)))
+    }
+}
+
 /// Force evaluation of lazy operations
 pub trait Eval {
     /// The output of the operation
@@ -18,6 +400,25 @@ pub trait Eval {
     fn eval(self) -> Self::Output;
 }
 
+/// Subtraction and division, required by the triangular solvers and by `mean_{cols,rows}`
+///
+/// NOTE The integer types implement this trait, their division truncates
+pub trait Field: Scalar {
+    /// Returns `self - rhs`
+    fn difference(self, rhs: Self) -> Self;
+
+    /// Returns `self / rhs`
+    fn quotient(self, rhs: Self) -> Self;
+}
+
+/// Element-wise fold
+pub trait Fold: MatrixCol {
+    /// Folds every element of the matrix into an accumulator, `f(acc, &A[i, j])`
+    ///
+    /// The elements are visited in column major order.
+    fn fold<B, F>(&self, init: B, f: F) -> B where F: FnMut(B, &Self::Elem) -> B;
+}
+
 /// "Immutable" horizontal splitting
 pub trait HSplit: Matrix {
     /// Splits a matrix horizontally at the `i`th row in two immutable pieces
@@ -52,6 +453,390 @@ pub trait IterMut<'a>: Iter<'a> {
     fn iter_mut(&'a mut self) -> Self::IterMut;
 }
 
+/// An element type whose LAPACK routines are provided by a `LapackBackend`
+///
+/// Only `f32`, `f64`, `Complex<f32>` and `Complex<f64>` implement this trait
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub trait Lapack: Element {
+    /// Returns the per-thread storage of the LAPACK backend
+    fn lapack_registry() -> &'static LocalKey<RefCell<Rc<Box<LapackBackend<Self>>>>>;
+
+    /// Returns the LAPACK backend used by the current thread
+    fn lapack_backend() -> Rc<Box<LapackBackend<Self>>> {
+        Self::lapack_registry().with(|backend| backend.borrow().clone())
+    }
+
+    /// Replaces the LAPACK backend used by the current thread, returns the previous backend
+    fn set_lapack_backend(backend: Box<LapackBackend<Self>>) -> Rc<Box<LapackBackend<Self>>> {
+        Self::lapack_registry().with(|current| {
+            mem::replace(&mut *current.borrow_mut(), Rc::new(backend))
+        })
+    }
+}
+
+/// The LAPACK routines that perform the numerical work on elements of type `T`
+///
+/// Like `Backend`, but the routines follow the conventions of the reference LAPACK
+/// implementation. The only implementor in this crate is `Ffi`. The LAPACK backend is also per
+/// thread, use `set_lapack_backend` to replace it.
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+pub trait LapackBackend<T> where T: Lapack {
+    /// Solves a banded linear system using the LU factorization of its coefficient matrix
+    unsafe fn gbsv(
+        &self,
+        n: &i32,
+        kl: &i32,
+        ku: &i32,
+        nrhs: &i32,
+        ab: *mut T,
+        ldab: &i32,
+        ipiv: *mut i32,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    );
+
+    /// Estimates the reciprocal condition number of a matrix from its LU factorization
+    ///
+    /// NB `rwork` is only referenced by complex element types, and `iwork` only by real ones
+    unsafe fn gecon(
+        &self,
+        norm: &u8,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        anorm: &T::Real,
+        rcond: &mut T::Real,
+        work: *mut T,
+        rwork: *mut T::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    );
+
+    /// Computes the eigenvalues and, optionally, the left and right eigenvectors of a matrix
+    ///
+    /// NB For real element types, `w` and `wi` receive the real and imaginary parts of the
+    /// eigenvalues, and `rwork` is not referenced. For complex element types, `w` receives the
+    /// eigenvalues, and `wi` is not referenced.
+    unsafe fn geev(
+        &self,
+        jobvl: &u8,
+        jobvr: &u8,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        w: *mut T,
+        wi: *mut T,
+        vl: *mut T,
+        ldvl: &i32,
+        vr: *mut T,
+        ldvr: &i32,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        info: &mut i32,
+    );
+
+    /// Computes the minimum norm solution of a least squares problem using the singular value
+    /// decomposition of its coefficient matrix
+    ///
+    /// NB `rwork` is only referenced by complex element types
+    unsafe fn gelsd(
+        &self,
+        m: &i32,
+        n: &i32,
+        nrhs: &i32,
+        a: *mut T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+        s: *mut T::Real,
+        rcond: &T::Real,
+        rank: &mut i32,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    );
+
+    /// Computes the QR factorization of a matrix
+    unsafe fn geqrf(
+        &self,
+        m: &i32,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        tau: *mut T,
+        work: *mut T,
+        lwork: &i32,
+        info: &mut i32,
+    );
+
+    /// Computes the singular value decomposition of a matrix using a divide and conquer algorithm
+    ///
+    /// NB `rwork` is only referenced by complex element types
+    unsafe fn gesdd(
+        &self,
+        jobz: &u8,
+        m: &i32,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: &i32,
+        vt: *mut T,
+        ldvt: &i32,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        iwork: *mut i32,
+        info: &mut i32,
+    );
+
+    /// Computes the LU factorization of a matrix
+    unsafe fn getrf(
+        &self,
+        m: &i32,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        ipiv: *mut i32,
+        info: &mut i32,
+    );
+
+    /// Computes the inverse of a matrix from its LU factorization
+    unsafe fn getri(
+        &self,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        ipiv: *const i32,
+        work: *mut T,
+        lwork: &i32,
+        info: &mut i32,
+    );
+
+    /// Solves a linear system using the LU factorization of its coefficient matrix
+    unsafe fn getrs(
+        &self,
+        trans: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const T,
+        lda: &i32,
+        ipiv: *const i32,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    );
+
+    /// Solves a tridiagonal linear system using Gaussian elimination with partial pivoting
+    unsafe fn gtsv(
+        &self,
+        n: &i32,
+        nrhs: &i32,
+        dl: *mut T,
+        d: *mut T,
+        du: *mut T,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    );
+
+    /// Computes the eigenvalues and, optionally, the eigenvectors of a symmetric (`syevd`) or
+    /// hermitian (`heevd`) matrix using a divide and conquer algorithm
+    ///
+    /// NB `rwork` and `lrwork` are only referenced by complex element types
+    unsafe fn heevd(
+        &self,
+        jobz: &u8,
+        uplo: &u8,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        w: *mut T::Real,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        lrwork: &i32,
+        iwork: *mut i32,
+        liwork: &i32,
+        info: &mut i32,
+    );
+
+    /// Computes selected eigenvalues and, optionally, eigenvectors of a symmetric (`syevr`) or
+    /// hermitian (`heevr`) matrix using the relatively robust representations
+    ///
+    /// NB `rwork` and `lrwork` are only referenced by complex element types
+    unsafe fn heevr(
+        &self,
+        jobz: &u8,
+        range: &u8,
+        uplo: &u8,
+        n: &i32,
+        a: *mut T,
+        lda: &i32,
+        vl: &T::Real,
+        vu: &T::Real,
+        il: &i32,
+        iu: &i32,
+        abstol: &T::Real,
+        m: &mut i32,
+        w: *mut T::Real,
+        z: *mut T,
+        ldz: &i32,
+        isuppz: *mut i32,
+        work: *mut T,
+        lwork: &i32,
+        rwork: *mut T::Real,
+        lrwork: &i32,
+        iwork: *mut i32,
+        liwork: &i32,
+        info: &mut i32,
+    );
+
+    /// Returns the one norm, the infinity norm, the Frobenius norm or the max-abs "norm" of a
+    /// matrix
+    unsafe fn lange(
+        &self,
+        norm: &u8,
+        m: &i32,
+        n: &i32,
+        a: *const T,
+        lda: &i32,
+        work: *mut T::Real,
+    ) -> T::Real;
+
+    /// Generates the `Q` matrix of a QR factorization
+    unsafe fn orgqr(
+        &self,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        a: *mut T,
+        lda: &i32,
+        tau: *const T,
+        work: *mut T,
+        lwork: &i32,
+        info: &mut i32,
+    );
+
+    /// Multiplies a matrix by the `Q` matrix of a QR factorization
+    unsafe fn ormqr(
+        &self,
+        side: &u8,
+        trans: &u8,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        a: *const T,
+        lda: &i32,
+        tau: *const T,
+        c: *mut T,
+        ldc: &i32,
+        work: *mut T,
+        lwork: &i32,
+        info: &mut i32,
+    );
+
+    /// Computes the Cholesky factorization of a positive definite matrix
+    unsafe fn potrf(&self, uplo: &u8, n: &i32, a: *mut T, lda: &i32, info: &mut i32);
+
+    /// Computes the inverse of a matrix from its Cholesky factorization
+    unsafe fn potri(&self, uplo: &u8, n: &i32, a: *mut T, lda: &i32, info: &mut i32);
+
+    /// Solves a linear system using the Cholesky factorization of its coefficient matrix
+    unsafe fn potrs(
+        &self,
+        uplo: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    );
+
+    /// Solves a triangular linear system
+    unsafe fn trtrs(
+        &self,
+        uplo: &u8,
+        trans: &u8,
+        diag: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const T,
+        lda: &i32,
+        b: *mut T,
+        ldb: &i32,
+        info: &mut i32,
+    );
+}
+
+/// Least squares solver
+pub trait Lstsq<B> {
+    /// The least squares solution
+    type Output;
+
+    /// Returns the `X` that minimizes the euclidean norm of `A * X - B`, where `A` is `self`
+    ///
+    /// If there are several such `X`, i.e. if `A` is rank deficient or has more columns than
+    /// rows, the one with minimum norm is returned.
+    ///
+    /// NOTE Owned right hand sides are consumed, and their buffer is re-used to store the solution
+    /// when `A` has at least as many rows as columns
+    ///
+    /// # Panics
+    ///
+    /// If:
+    ///
+    /// - `A.nrows() != B.nrows() ||`
+    /// - the singular value decomposition of `A` didn't converge
+    fn lstsq(self, b: B) -> Self::Output;
+}
+
+/// Element-wise operations that produce a new owned matrix
+///
+/// The elements are visited in column major order. The operands of `zip_with` and `zip3_with` are
+/// walked in the same order, regardless of their layout (e.g. when one of them is transposed).
+pub trait Map<U>: MatrixCol {
+    /// The owned output: `Mat<U>`, `ColVec<U>` or `RowVec<U>`
+    type Output;
+
+    /// Returns `B` where `B[i, j] = f(&A[i, j])`
+    fn map<F>(&self, f: F) -> Self::Output where F: FnMut(&Self::Elem) -> U;
+
+    /// Returns `C` where `C[i, j] = f(&A[i, j], &B[i, j])`
+    ///
+    /// # Panics
+    ///
+    /// If `A.size() != B.size()`
+    fn zip_with<B, F>(&self, b: &B, f: F) -> Self::Output where
+        B: MatrixCol,
+        F: FnMut(&Self::Elem, &B::Elem) -> U;
+
+    /// Returns `D` where `D[i, j] = f(&A[i, j], &B[i, j], &C[i, j])`
+    ///
+    /// # Panics
+    ///
+    /// If `A.size() != B.size() || A.size() != C.size()`
+    fn zip3_with<B, C, F>(&self, b: &B, c: &C, f: F) -> Self::Output where
+        B: MatrixCol,
+        C: MatrixCol,
+        F: FnMut(&Self::Elem, &B::Elem, &C::Elem) -> U;
+}
+
+/// In place element-wise operations
+pub trait MapInplace: MatrixColMut {
+    /// Applies `f` to every element of the matrix
+    ///
+    /// The elements are visited in column major order.
+    fn map_inplace<F>(&mut self, f: F) where F: FnMut(&mut Self::Elem);
+}
+
 /// The basic idea of a matrix: A rectangular array arranged in rows and columns
 pub trait Matrix: Sized {
     /// The type of the elements contained in the matrix
@@ -138,6 +923,15 @@ pub trait MatrixInverse {
     fn inv(self) -> Self::Output;
 }
 
+/// Matrix norms
+pub trait MatrixNorm {
+    /// The return value
+    type Output;
+
+    /// Returns the `kind` norm of the matrix
+    fn matrix_norm(&self, kind: NormKind) -> Self::Output;
+}
+
 /// Immutable view into the row of a matrix
 pub trait MatrixRow: Matrix {
     /// Returns an immutable "view" into the `i`th row of the matrix
@@ -175,6 +969,28 @@ pub trait Norm {
     fn norm(&self) -> Self::Output;
 }
 
+/// The euclidean norm and the scaling by a real number, required by the `nrm2` and `rscal`
+/// routines of the `Native` backend
+///
+/// Only `f32`, `f64`, `Complex<f32>` and `Complex<f64>` implement this trait
+pub trait Normed: Scalar {
+    /// Returns the euclidean norm of `x`
+    unsafe fn nrm2(n: &i32, x: *const Self, incx: &i32) -> Self::Real;
+
+    /// x := alpha * x, where `alpha` is a real number
+    unsafe fn rscal(n: &i32, alpha: &Self::Real, x: *mut Self, incx: &i32);
+}
+
+/// The arithmetic that the `Native` backend requires from the elements of type `Self`
+///
+/// The triangular solvers additionally require `Field`, and the euclidean norm requires `Normed`
+pub trait Scalar: Add<Output=Self> + Element + Mul<Output=Self> + One + PartialEq + Zero {
+    /// Returns the complex conjugate of `self`, real numbers are returned as they are
+    fn conj(self) -> Self {
+        self
+    }
+}
+
 /// Alternative to `IndexSet` (which doesn't exist)
 ///
 /// Usage: `a.col_mut(1).set(b.col(0))`
@@ -203,6 +1019,31 @@ pub trait SliceMut<'a, Range> {
     fn slice_mut(&'a mut self, Range) -> Self::Output;
 }
 
+/// Linear system solver
+pub trait Solve<B> {
+    /// The solution of the system
+    type Output;
+
+    /// Solves the linear system `A * X = B`, where `A` is `self`, and returns `X`
+    ///
+    /// NOTE Owned right hand sides are consumed, and their buffer is re-used to store the solution
+    fn solve(self, b: B) -> Self::Output;
+}
+
+/// Symmetric view into a square matrix
+pub trait Sym {
+    /// The symmetric view
+    type Output;
+
+    /// Returns a view into the matrix that only reads its `uplo` triangular part, the other part
+    /// is assumed to be its mirror image
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    fn sym(self, uplo: Uplo) -> Self::Output;
+}
+
 /// The transpose operator
 pub trait Transpose {
     /// The transposed data
@@ -212,6 +1053,83 @@ pub trait Transpose {
     fn t(self) -> Self::Output;
 }
 
+/// Triangular views into a square matrix
+pub trait Triangular {
+    /// View into the lower triangular part
+    type Lower;
+    /// View into the upper triangular part
+    type Upper;
+
+    /// Returns a view into the lower triangular part of the matrix
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    fn lower(self) -> Self::Lower;
+
+    /// Returns a view into the lower triangular part of the matrix, whose diagonal elements are
+    /// assumed to be ones
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    fn unit_lower(self) -> Self::Lower;
+
+    /// Returns a view into the upper triangular part of the matrix, whose diagonal elements are
+    /// assumed to be ones
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    fn unit_upper(self) -> Self::Upper;
+
+    /// Returns a view into the upper triangular part of the matrix
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    fn upper(self) -> Self::Upper;
+}
+
+/// Fallible version of `AddAssign`
+pub trait TryAddAssign<T> {
+    /// Performs `self += rhs`, or returns an error if the dimensions of the operands don't agree
+    fn try_add_assign(&mut self, rhs: T) -> Result<(), Error>;
+}
+
+/// Fallible version of `Eval`
+pub trait TryEval: Eval {
+    /// Evaluates the lazy operation, or returns an error if the dimensions of its operands don't
+    /// agree
+    fn try_eval(self) -> Result<Self::Output, Error>;
+}
+
+/// Fallible version of `MatrixInverse`
+pub trait TryInv: MatrixInverse {
+    /// Returns the inverse of the input matrix, or an error if the matrix is not invertible
+    fn try_inv(self) -> Result<Self::Output, Error>;
+}
+
+/// Fallible version of `Mul`
+pub trait TryMul<T>: Mul<T> {
+    /// Returns the lazy product `self * rhs`, or an error if the inner dimensions of the operands
+    /// don't agree
+    fn try_mul(self, rhs: T) -> Result<Self::Output, Error>;
+}
+
+/// Fallible version of `Solve`
+pub trait TrySolve<B>: Solve<B> {
+    /// Solves the linear system `A * X = B`, or returns an error if the dimensions of `A` and `B`
+    /// don't agree or if `A` is singular
+    fn try_solve(self, b: B) -> Result<Self::Output, Error>;
+}
+
+/// Fallible version of `Set`
+pub trait TrySet<T> {
+    /// Copies `RHS` into `self`, or returns an error if the dimensions of the operands don't agree
+    fn try_set(&mut self, rhs: T) -> Result<(), Error>;
+}
+
 /// "Immutable" vertical splitting
 pub trait VSplit: Matrix {
     /// Splits a matrix vertically at the `i`th column in two immutable pieces
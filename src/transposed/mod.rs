@@ -199,6 +199,14 @@ impl<M> Matrix for Transposed<M> where M: Matrix {
     }
 }
 
+impl<M> MatrixCol for Transposed<M> where M: MatrixRow {
+    fn col(&self, i: u32) -> Col<M::Elem> {
+        Col(self.0.row(i).0)
+    }
+}
+
+impl<M> MatrixColMut for Transposed<M> where M: MatrixRowMut {}
+
 impl<'a, M> Slice<'a, RangeFull> for Transposed<M> where
     M: Slice<'a, RangeFull>,
 {
@@ -0,0 +1,82 @@
+use traits::{Matrix, Slice, Triangular};
+use {Diagonal, LowerTri, Mat, SubMat, SubMatMut, UpperTri};
+
+macro_rules! matrix {
+    ($($tri:ident),+) => {
+        $(
+            impl<'a, T> Matrix for $tri<SubMat<'a, T>> {
+                type Elem = T;
+
+                fn nrows(&self) -> u32 {
+                    self.0.nrows()
+                }
+
+                fn ncols(&self) -> u32 {
+                    self.0.ncols()
+                }
+            }
+         )+
+    }
+}
+
+matrix!(LowerTri, UpperTri);
+
+// NOTE Core
+impl<'a, T> Triangular for SubMat<'a, T> {
+    type Lower = LowerTri<SubMat<'a, T>>;
+    type Upper = UpperTri<SubMat<'a, T>>;
+
+    fn lower(self) -> LowerTri<SubMat<'a, T>> {
+        assert_eq!(self.nrows(), self.ncols());
+
+        LowerTri(self, Diagonal::NonUnit)
+    }
+
+    fn unit_lower(self) -> LowerTri<SubMat<'a, T>> {
+        assert_eq!(self.nrows(), self.ncols());
+
+        LowerTri(self, Diagonal::Unit)
+    }
+
+    fn unit_upper(self) -> UpperTri<SubMat<'a, T>> {
+        assert_eq!(self.nrows(), self.ncols());
+
+        UpperTri(self, Diagonal::Unit)
+    }
+
+    fn upper(self) -> UpperTri<SubMat<'a, T>> {
+        assert_eq!(self.nrows(), self.ncols());
+
+        UpperTri(self, Diagonal::NonUnit)
+    }
+}
+
+macro_rules! forward {
+    ($($ty:ty),+) => {
+        $(
+            // NOTE Forward
+            impl<'a, 'b, T> Triangular for $ty {
+                type Lower = LowerTri<SubMat<'a, T>>;
+                type Upper = UpperTri<SubMat<'a, T>>;
+
+                fn lower(self) -> LowerTri<SubMat<'a, T>> {
+                    self.slice(..).lower()
+                }
+
+                fn unit_lower(self) -> LowerTri<SubMat<'a, T>> {
+                    self.slice(..).unit_lower()
+                }
+
+                fn unit_upper(self) -> UpperTri<SubMat<'a, T>> {
+                    self.slice(..).unit_upper()
+                }
+
+                fn upper(self) -> UpperTri<SubMat<'a, T>> {
+                    self.slice(..).upper()
+                }
+            }
+         )+
+    }
+}
+
+forward!(&'a Mat<T>, &'a SubMatMut<'b, T>);
@@ -0,0 +1,333 @@
+//! Test that:
+//!
+//! - `A.sum_cols()[c] == sum(A[r, c] for r in 0..m)`
+//! - `A.mean_rows()[r] == sum(A[r, c] for c in 0..n) / n`
+//! - `A.max_cols()[c] == A[A.argmax_cols()[c], c] >= A[r, c]`
+//! - `A.min_rows()[r] == A[r, A.argmin_rows()[r]] <= A[r, c]`
+//! - `A[r, A.argmax_rows()[r]] >= A[r, c]`
+//! - `A[A.argmin_cols()[c], c] <= A[r, c]`
+//! - `A.argmax_cols()` and `A.argmin_rows()` skip NaN elements, unless the whole column/row is NaN
+//!
+//! for any valid `r`, `c`, and for `A` being a matrix, a sub-matrix or a transposed matrix
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+// A.sum_cols()
+mod sum_cols {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((srow, scol): (u32, u32), (m, n): (u32, u32), col: u32) -> TestResult {
+                    enforce! {
+                        col < n,
+                    }
+
+                    let a = ::setup::rand::mat::<$ty>((srow + m, scol + n));
+                    let a = a.slice((srow.., scol..));
+
+                    let e = (0..m).fold($ty::zero(), |e, r| e + a[(r, col)]);
+
+                    test_approx_eq!(a.sum_cols()[col], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.t().sum_cols()
+mod sum_cols_transposed {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), col: u32) -> TestResult {
+                    enforce! {
+                        col < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((n, m));
+                    let at = a.t();
+
+                    let e = (0..m).fold($ty::zero(), |e, r| e + at[(r, col)]);
+
+                    test_approx_eq!(at.sum_cols()[col], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.mean_rows()
+mod mean_rows {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), row: u32) -> TestResult {
+                    enforce! {
+                        row < m,
+                        n != 0,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((m, n));
+
+                    let sum = (0..n).fold($ty::zero(), |e, c| e + a[(row, c)]);
+                    let count = (0..n).fold($ty::zero(), |e, _| e + $ty::one());
+
+                    test_approx_eq!(a.mean_rows()[row], sum / count)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.max_cols(), A.argmax_cols()
+mod max_cols {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((m, n));
+
+                    let max = a.max_cols()[col];
+                    let i = a.argmax_cols()[col];
+
+                    test!(a[(i, col)] == max && max >= a[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+}
+
+// A.t().min_rows(), A.t().argmin_rows()
+mod min_rows_transposed {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((n, m));
+                    let at = a.t();
+
+                    let min = at.min_rows()[row];
+                    let i = at.argmin_rows()[row];
+
+                    test!(at[(row, i)] == min && min <= at[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+}
+
+// A.slice(..).argmax_rows()
+mod argmax_rows_submat {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(
+                    (srow, scol): (u32, u32),
+                    (m, n): (u32, u32),
+                    (row, col): (u32, u32),
+                ) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let a = ::setup::rand::mat::<$ty>((srow + m, scol + n));
+                    let a = a.slice((srow.., scol..));
+
+                    let i = a.argmax_rows()[row];
+
+                    test!(a[(row, i)] >= a[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+}
+
+// A.t().argmin_cols()
+mod argmin_cols_transposed {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((n, m));
+                    let at = a.t();
+
+                    let i = at.argmin_cols()[col];
+
+                    test!(at[(i, col)] <= at[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+}
+
+// A.argmax_cols(), where the column contains a NaN
+mod argmax_cols_nan {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32), nan: u32) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                        nan < m,
+                        row != nan,
+                    }
+
+                    let mut a = ::setup::rand::mat::<$ty>((m, n));
+                    a[(nan, col)] = ::std::$ty::NAN;
+
+                    let i = a.argmax_cols()[col];
+
+                    test!(i != nan && a[(i, col)] >= a[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+}
+
+// A.t().argmin_rows(), where the row contains a NaN
+mod argmin_rows_nan_transposed {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32), nan: u32) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                        nan < n,
+                        col != nan,
+                    }
+
+                    let mut a = ::setup::rand::mat::<$ty>((n, m));
+                    a[(nan, row)] = ::std::$ty::NAN;
+                    let at = a.t();
+
+                    let i = at.argmin_rows()[row];
+
+                    test!(i != nan && at[(row, i)] <= at[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+}
+
+// A.argmax_cols() and A.t().argmin_rows(), where the whole column is NaN
+mod arg_all_nan {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), col: u32) -> TestResult {
+                    enforce! {
+                        m != 0,
+                        col < n,
+                    }
+
+                    let mut a = ::setup::rand::mat::<$ty>((m, n));
+
+                    for row in 0..m {
+                        a[(row, col)] = ::std::$ty::NAN;
+                    }
+
+                    let i = a.argmax_cols()[col];
+                    let j = a.t().argmin_rows()[col];
+
+                    test!(i < m && j < m)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+}
@@ -0,0 +1,278 @@
+//! Test that:
+//!
+//! - `(A * B).eval()` calls the `gemm` routine of the backend set with `set_backend`
+//! - restoring the default backend stops the calls to the replaced backend
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use linalg::prelude::*;
+use linalg::traits::Backend;
+use linalg::{Diagonal, Side, Transpose, Uplo};
+#[cfg(all(feature = "blas", not(feature = "pure-rust")))]
+use linalg::Ffi as DefaultBackend;
+#[cfg(any(not(feature = "blas"), feature = "pure-rust"))]
+use linalg::Native as DefaultBackend;
+use quickcheck::TestResult;
+
+/// Counts the calls to `gemm`, and forwards all the calls to the default backend
+struct Counting(Rc<Cell<usize>>);
+
+impl Backend<f64> for Counting {
+    unsafe fn axpy(
+        &self,
+        n: &i32,
+        alpha: &f64,
+        x: *const f64,
+        incx: &i32,
+        y: *mut f64,
+        incy: &i32,
+    ) {
+        DefaultBackend.axpy(n, alpha, x, incx, y, incy)
+    }
+
+    unsafe fn copy(&self, n: &i32, x: *const f64, incx: &i32, y: *mut f64, incy: &i32) {
+        DefaultBackend.copy(n, x, incx, y, incy)
+    }
+
+    unsafe fn dot(&self, n: &i32, x: *const f64, incx: &i32, y: *const f64, incy: &i32) -> f64 {
+        DefaultBackend.dot(n, x, incx, y, incy)
+    }
+
+    unsafe fn gbmv(
+        &self,
+        trans: &Transpose,
+        m: &i32,
+        n: &i32,
+        kl: &i32,
+        ku: &i32,
+        alpha: &f64,
+        a: *const f64,
+        lda: &i32,
+        x: *const f64,
+        incx: &i32,
+        beta: &f64,
+        y: *mut f64,
+        incy: &i32,
+    ) {
+        DefaultBackend.gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn gemm(
+        &self,
+        transa: &Transpose,
+        transb: &Transpose,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        alpha: &f64,
+        a: *const f64,
+        lda: &i32,
+        b: *const f64,
+        ldb: &i32,
+        beta: &f64,
+        c: *mut f64,
+        ldc: &i32,
+    ) {
+        self.0.set(self.0.get() + 1);
+
+        DefaultBackend.gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+
+    unsafe fn gemv(
+        &self,
+        trans: &Transpose,
+        m: &i32,
+        n: &i32,
+        alpha: &f64,
+        a: *const f64,
+        lda: &i32,
+        x: *const f64,
+        incx: &i32,
+        beta: &f64,
+        y: *mut f64,
+        incy: &i32,
+    ) {
+        DefaultBackend.gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn ger(
+        &self,
+        m: &i32,
+        n: &i32,
+        alpha: &f64,
+        x: *const f64,
+        incx: &i32,
+        y: *const f64,
+        incy: &i32,
+        a: *mut f64,
+        lda: &i32,
+    ) {
+        DefaultBackend.ger(m, n, alpha, x, incx, y, incy, a, lda)
+    }
+
+    unsafe fn nrm2(&self, n: &i32, x: *const f64, incx: &i32) -> f64 {
+        DefaultBackend.nrm2(n, x, incx)
+    }
+
+    unsafe fn rscal(&self, n: &i32, alpha: &f64, x: *mut f64, incx: &i32) {
+        DefaultBackend.rscal(n, alpha, x, incx)
+    }
+
+    unsafe fn scal(&self, n: &i32, alpha: &f64, x: *mut f64, incx: &i32) {
+        DefaultBackend.scal(n, alpha, x, incx)
+    }
+
+    unsafe fn symm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        m: &i32,
+        n: &i32,
+        alpha: &f64,
+        a: *const f64,
+        lda: &i32,
+        b: *const f64,
+        ldb: &i32,
+        beta: &f64,
+        c: *mut f64,
+        ldc: &i32,
+    ) {
+        DefaultBackend.symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+
+    unsafe fn symv(
+        &self,
+        uplo: &Uplo,
+        n: &i32,
+        alpha: &f64,
+        a: *const f64,
+        lda: &i32,
+        x: *const f64,
+        incx: &i32,
+        beta: &f64,
+        y: *mut f64,
+        incy: &i32,
+    ) {
+        DefaultBackend.symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+    }
+
+    unsafe fn syrk(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        n: &i32,
+        k: &i32,
+        alpha: &f64,
+        a: *const f64,
+        lda: &i32,
+        beta: &f64,
+        c: *mut f64,
+        ldc: &i32,
+    ) {
+        DefaultBackend.syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+    }
+
+    unsafe fn trmm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        transa: &Transpose,
+        diag: &Diagonal,
+        m: &i32,
+        n: &i32,
+        alpha: &f64,
+        a: *const f64,
+        lda: &i32,
+        b: *mut f64,
+        ldb: &i32,
+    ) {
+        DefaultBackend.trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+    }
+
+    unsafe fn trmv(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        diag: &Diagonal,
+        n: &i32,
+        a: *const f64,
+        lda: &i32,
+        x: *mut f64,
+        incx: &i32,
+    ) {
+        DefaultBackend.trmv(uplo, trans, diag, n, a, lda, x, incx)
+    }
+
+    unsafe fn trsm(
+        &self,
+        side: &Side,
+        uplo: &Uplo,
+        transa: &Transpose,
+        diag: &Diagonal,
+        m: &i32,
+        n: &i32,
+        alpha: &f64,
+        a: *const f64,
+        lda: &i32,
+        b: *mut f64,
+        ldb: &i32,
+    ) {
+        DefaultBackend.trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+    }
+
+    unsafe fn trsv(
+        &self,
+        uplo: &Uplo,
+        trans: &Transpose,
+        diag: &Diagonal,
+        n: &i32,
+        a: *const f64,
+        lda: &i32,
+        x: *mut f64,
+        incx: &i32,
+    ) {
+        DefaultBackend.trsv(uplo, trans, diag, n, a, lda, x, incx)
+    }
+
+}
+
+#[quickcheck]
+fn gemm((m, k, n): (u32, u32, u32)) -> TestResult {
+    enforce! {
+        m != 0,
+        k != 0,
+        n != 0,
+    }
+
+    let ref a = ::setup::rand::mat::<f64>((m, k));
+    let ref b = ::setup::rand::mat::<f64>((k, n));
+
+    let count = Rc::new(Cell::new(0));
+    linalg::set_backend::<f64>(Box::new(Counting(count.clone())));
+
+    let c = (a * b).eval();
+
+    let calls = count.get();
+
+    linalg::set_backend::<f64>(Box::new(DefaultBackend));
+
+    let d = (a * b).eval();
+
+    test_eq!(calls, 1);
+    test_eq!(count.get(), 1);
+    test_eq!(c, d)
+}
@@ -0,0 +1,223 @@
+//! Test that:
+//!
+//! - `(&A * x)[r] == sum(A.diag(k - r)[min(r, k)] * x[k] for k in r-kl..r+ku+1)`
+//! - `y.set(alpha * &A * x); y[r] == alpha * sum(A.diag(k - r)[min(r, k)] * x[k] for k in
+//!   r-kl..r+ku+1)`
+//! - `A.solve(&A * x)[r] == x[r]`, for banded and tridiagonal (`kl == ku == 1`) `A`
+//!
+//! for any valid `r`, and that `A.diag(0)` is empty if `A` is `0 x 0`
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use std::cmp;
+
+use linalg::prelude::*;
+use onezero::Zero;
+use rand::Rand;
+
+/// Returns a `n x n` band matrix whose band is filled with random elements
+fn band<T>(n: u32, (kl, ku): (u32, u32)) -> BandMat<T> where T: Clone + Rand + Zero {
+    let mut a = BandMat::zeros(n, (kl, ku));
+
+    for d in -(kl as i32)..ku as i32 + 1 {
+        for e in a.diag_mut(d) {
+            *e = ::setup::rand::scalar();
+        }
+    }
+
+    a
+}
+
+/// Returns the element at `(r, k)` of the band matrix `a`
+fn at<T>(a: &BandMat<T>, r: u32, k: u32) -> T where T: Copy + Zero {
+    let d = k as i32 - r as i32;
+
+    if d < -(a.kl() as i32) || d > a.ku() as i32 {
+        T::zero()
+    } else {
+        a.diag(d)[cmp::min(r, k)]
+    }
+}
+
+// A.diag(0), where A is 0 x 0
+mod diag_empty {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((kl, ku): (u32, u32)) -> TestResult {
+                    let a = BandMat::<$ty>::zeros(0, (kl, ku));
+
+                    test_eq!(a.diag(0).iter().count(), 0)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// &A * x
+mod mul_col {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, (kl, ku): (u32, u32), row: u32) -> TestResult {
+                    enforce! {
+                        row < n,
+                        kl < n,
+                        ku < n,
+                    }
+
+                    let ref a = ::band::<$ty>(n, (kl, ku));
+                    let ref x = ::setup::rand::col::<$ty>(n);
+
+                    let y = (a * x).eval();
+
+                    let e = (0..n).fold($ty::zero(), |e, k| e + ::at(a, row, k) * x[k]);
+
+                    test_approx_eq!(y[row], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// y.set(alpha * &A * x)
+mod set_col {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, (kl, ku): (u32, u32), row: u32) -> TestResult {
+                    enforce! {
+                        row < n,
+                        kl < n,
+                        ku < n,
+                    }
+
+                    let alpha: $ty = ::setup::rand::scalar();
+                    let ref a = ::band::<$ty>(n, (kl, ku));
+                    let ref x = ::setup::rand::col::<$ty>(n);
+
+                    let mut y = ColVec::<$ty>::zeros(n);
+                    y.set(alpha * a * x);
+
+                    let e = (0..n).fold($ty::zero(), |e, k| e + ::at(a, row, k) * x[k]);
+
+                    test_approx_eq!(y[row], alpha * e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.solve(&A * x)
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod solve {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::One;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, (kl, ku): (u32, u32), row: u32) -> TestResult {
+                    enforce! {
+                        row < n,
+                        kl < n,
+                        ku < n,
+                    }
+
+                    let mut a = ::band::<$ty>(n, (kl, ku));
+
+                    // Make the diagonal dominant, keeps the system well conditioned
+                    for e in a.diag_mut(0) {
+                        *e = (0..2 * (kl + ku + 1)).fold(*e, |e, _| e + $ty::one());
+                    }
+
+                    let ref x = ::setup::rand::col::<$ty>(n);
+                    let b = (&a * x).eval();
+
+                    let y = a.solve(b);
+
+                    test_approx_eq!(y[row], x[row])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.solve(&A * x), where A is tridiagonal
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod solve_tridiagonal {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::One;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, row: u32) -> TestResult {
+                    enforce! {
+                        row < n,
+                        n > 1,
+                    }
+
+                    let mut a = ::band::<$ty>(n, (1, 1));
+
+                    // Make the diagonal dominant, keeps the system well conditioned
+                    for e in a.diag_mut(0) {
+                        *e = (0..6).fold(*e, |e, _| e + $ty::one());
+                    }
+
+                    let ref x = ::setup::rand::col::<$ty>(n);
+                    let b = (&a * x).eval();
+
+                    let y = a.solve(b);
+
+                    test_approx_eq!(y[row], x[row])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
@@ -0,0 +1,155 @@
+//! Test that:
+//!
+//! - `{ A += x.broadcast_rows(); A }[r, c] == A[r, c] + x[c]`
+//! - `{ A -= y.broadcast_cols(); A }[r, c] == A[r, c] - y[r]`
+//!
+//! for any valid `r`, `c`, and for `A` being a matrix, a sub-matrix or a transposed matrix
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+// A += x.broadcast_rows()
+mod add_rows {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let mut a = ::setup::rand::mat::<$ty>((m, n));
+                    let ref x = ::setup::rand::row::<$ty>(n);
+
+                    let e = a[(row, col)] + x[col];
+
+                    a.add_assign(x.broadcast_rows());
+
+                    test_approx_eq!(a[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A[s..] -= y.broadcast_cols()
+mod sub_cols {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(
+                    (srow, scol): (u32, u32),
+                    (m, n): (u32, u32),
+                    (row, col): (u32, u32),
+                ) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let mut a = ::setup::rand::mat::<$ty>((srow + m, scol + n));
+                    let ref y = ::setup::rand::col::<$ty>(m);
+
+                    let e = a[(srow + row, scol + col)] - y[row];
+
+                    a.slice_mut((srow.., scol..)).sub_assign(y.broadcast_cols());
+
+                    test_approx_eq!(a[(srow + row, scol + col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.t() += y.broadcast_cols()
+mod add_cols_transposed {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let mut at = ::setup::rand::mat::<$ty>((n, m)).t();
+                    let ref y = ::setup::rand::col::<$ty>(m);
+
+                    let e = at[(row, col)] + y[row];
+
+                    at.add_assign(y.broadcast_cols());
+
+                    test_approx_eq!(at[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A[s..].t() -= x.broadcast_rows()
+mod sub_rows_transposed {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(
+                    (srow, scol): (u32, u32),
+                    (m, n): (u32, u32),
+                    (row, col): (u32, u32),
+                ) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let mut a = ::setup::rand::mat::<$ty>((srow + n, scol + m));
+                    let ref x = ::setup::rand::row::<$ty>(n);
+
+                    let e = a[(srow + col, scol + row)] - x[col];
+
+                    a.slice_mut((srow.., scol..)).t().sub_assign(x.broadcast_rows());
+
+                    test_approx_eq!(a[(srow + col, scol + row)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
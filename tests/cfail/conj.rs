@@ -0,0 +1,20 @@
+//! Test that the products that contain a conjugate transpose are rejected by the operations that
+//! can't handle them
+
+extern crate complex;
+extern crate linalg;
+
+use complex::c128;
+use linalg::prelude::*;
+
+// `(A^H * B)^T` would require the conjugate of `A`
+fn transpose(a: &Mat<c128>, b: &Mat<c128>) {
+    (a.h() * b).t();  //~ error: no method named `t` found
+}
+
+// `x * A^H * B` would require the conjugate of `A`
+fn row_mul(x: &RowVec<c128>, a: &Mat<c128>, b: &Mat<c128>) {
+    x * (a.h() * b);  //~ error: binary operation `*` cannot be applied
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! Test that the operations that an element type doesn't support are rejected at compile time
+
+extern crate linalg;
+
+use linalg::prelude::*;
+use linalg::traits::{MatrixInverse, Norm};
+
+// LAPACK routines are only available for `f32`, `f64`, `c64` and `c128`
+fn inv(a: Mat<i32>) {
+    MatrixInverse::inv(a);  //~ error: is not implemented for the type
+}
+
+fn lu(a: Mat<i64>) {
+    a.lu();  //~ error: is not implemented for the type
+}
+
+// The euclidean norm requires `Normed`, which integers don't implement
+fn norm(a: Mat<i32>) {
+    Norm::norm(&a);  //~ error: is not implemented for the type
+}
+
+fn main() {}
@@ -0,0 +1,160 @@
+//! Test that:
+//!
+//! - `A.cholesky().solve(A * x)[i] == x[i]`, where `A` is positive definite
+//! - `A[r, :] * A.cholesky().inv()[:, c] == if r == c { 1 } else { 0 }`
+//! - `A.cholesky().log_det() == A.lu().slogdet().1`
+//! - `A.cholesky().is_err()`, where `A` is negative definite
+//!
+//! for any valid `i`, `r`, `c`
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+mod inv {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32, (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < n,
+                    }
+
+                    let _0 = $t::zero();
+                    let _1 = $t::one();
+                    let ref b = ::setup::rand::mat::<$t>((n, n));
+
+                    // `B * B^H + n * I` is positive definite
+                    let mut a = (b * b.h()).eval();
+                    a.diag_mut(0).add_assign((0..n).fold(_0, |s, _| s + _1));
+
+                    // NB `inv` only computes the lower triangle and has to fill in the upper one
+                    let a_inv = a.clone().cholesky().unwrap().inv();
+
+                    if row == col {
+                        test_approx_eq!(a.row(row) * a_inv.col(col), _1)
+                    } else {
+                        test_approx_eq!(a.row(row) * a_inv.col(col), _0)
+                    }
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod log_det {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n < 8,
+                    }
+
+                    let _0 = $t::zero();
+                    let _1 = $t::one();
+                    let ref b = ::setup::rand::mat::<$t>((n, n));
+
+                    // `B * B^H + n * I` is positive definite
+                    let mut a = (b * b.h()).eval();
+                    a.diag_mut(0).add_assign((0..n).fold(_0, |s, _| s + _1));
+
+                    let e = a.clone().lu().slogdet().1;
+
+                    test_approx_eq!(a.cholesky().unwrap().log_det(), e)
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod not_positive_definite {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::Error;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n != 0,
+                    }
+
+                    let a = Mat::from_fn((n, n), |(r, c)| {
+                        if r == c { -$t::one() } else { $t::zero() }
+                    });
+
+                    test_eq!(a.cholesky().err(), Some(Error::NotPositiveDefinite { order: 1 }))
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod solve {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32, i: u32) -> TestResult {
+                    enforce! {
+                        i < n,
+                    }
+
+                    let ref b = ::setup::rand::mat::<$t>((n, n));
+                    let ref x = ::setup::rand::col::<$t>(n);
+
+                    // `B * B^H + n * I` is positive definite
+                    let mut a = (b * b.h()).eval();
+                    a.diag_mut(0).add_assign((0..n).fold($t::zero(), |s, _| s + $t::one()));
+
+                    let y = (&a * x).eval();
+                    let chol = a.cholesky().unwrap();
+
+                    test_approx_eq!(chol.solve(y)[i], x[i])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
@@ -0,0 +1,227 @@
+//! Test that:
+//!
+//! - `(A.h() * B)[r, c] == sum(conj(A[k, r]) * B[k, c] for k in 0..K)`
+//! - `(A * B.h() * C)[r, c] == sum(A[r, i] * conj(B[j, i]) * C[j, c] for i in 0..K, j in 0..N)`
+//! - `(A.h() * x)[r] == sum(conj(A[k, r]) * x[k] for k in 0..K)`
+//! - `Mat::from(A.h())[r, c] == conj(A[c, r])`
+//! - `Mat::from(A.conj())[r, c] == conj(A[r, c])`
+//! - `A.real()[r, c] == A[r, c].re` and `A.imag()[r, c] == A[r, c].im`
+//!
+//! for any valid `r`, `c`
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+// A.h() * B
+mod mul_mat {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::traits::Scalar;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, k, n): (u32, u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        k != 0,
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((k, m));
+                    let ref b = ::setup::rand::mat::<$ty>((k, n));
+
+                    let c = (a.h() * b).eval();
+
+                    let e = (0..k).fold($ty::zero(), |e, i| {
+                        e + Scalar::conj(a[(i, row)]) * b[(i, col)]
+                    });
+
+                    test_approx_eq!(c[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A * B.h() * C
+mod mul_chain {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::traits::Scalar;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, k, n, p): (u32, u32, u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        k != 0,
+                        n != 0,
+                        row < m,
+                        col < p,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((m, k));
+                    let ref b = ::setup::rand::mat::<$ty>((n, k));
+                    let ref c = ::setup::rand::mat::<$ty>((n, p));
+
+                    let d = (a * b.h() * c).eval();
+
+                    let e = (0..k).fold($ty::zero(), |e, i| {
+                        (0..n).fold(e, |e, j| {
+                            e + a[(row, i)] * Scalar::conj(b[(j, i)]) * c[(j, col)]
+                        })
+                    });
+
+                    test_approx_eq!(d[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.h() * x
+mod mul_col {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::traits::Scalar;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, k): (u32, u32), row: u32) -> TestResult {
+                    enforce! {
+                        k != 0,
+                        row < m,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((k, m));
+                    let ref x = ::setup::rand::col::<$ty>(k);
+
+                    let y = (a.h() * x).eval();
+
+                    let e = (0..k).fold($ty::zero(), |e, i| {
+                        e + Scalar::conj(a[(i, row)]) * x[i]
+                    });
+
+                    test_approx_eq!(y[row], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// Mat::from(A.h())
+mod from_h {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::traits::Scalar;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < ncols,
+                        col < nrows,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((nrows, ncols));
+                    let b = Mat::from(a.h());
+
+                    test_eq!(b[(row, col)], Scalar::conj(a[(col, row)]))
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// Mat::from(A.conj())
+mod from_conj {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::traits::Scalar;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((nrows, ncols));
+                    let b = Mat::from(a.conj());
+
+                    test_eq!(b[(row, col)], Scalar::conj(a[(row, col)]))
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.real(), A.imag()
+mod parts {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((nrows, ncols));
+                    let z = a[(row, col)];
+
+                    test_eq!(a.real()[(row, col)], z.re);
+                    test_eq!(a.imag()[(row, col)], z.im);
+                    test_eq!(a.real().col(col)[row], z.re);
+                    test_eq!(a.imag().row(row)[col], z.im)
+                }
+             )+
+        }
+    }
+
+    tests!(c64, c128);
+}
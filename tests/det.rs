@@ -0,0 +1,100 @@
+//! Test that:
+//!
+//! - `D.det() == D[0, 0] * D[1, 1] * ...`, where `D` is a diagonal matrix
+//! - `A.t().det() == A.det()`
+//! - `sign * exp(logdet) == A.det()`, where `(sign, logdet) = A.slogdet()`
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+mod diag {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n < 8,
+                    }
+
+                    let ref d = ::setup::rand::col::<$t>(n);
+                    let a = Mat::from_fn((n, n), |(r, c)| if r == c { d[r] } else { $t::zero() });
+
+                    let prod = d.iter().fold($t::one(), |acc, &x| acc * x);
+
+                    test_approx_eq!(a.det(), prod)
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod slogdet {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n < 8,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$t>((n, n));
+
+                    let (sign, logdet) = a.slogdet();
+
+                    test_approx_eq!(sign * logdet.exp(), a.det())
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64);
+}
+
+mod transposed {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n < 8,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$t>((n, n));
+
+                    test_approx_eq!(a.t().det(), a.det())
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
@@ -0,0 +1,57 @@
+//! Test that:
+//!
+//! - `(A * V[:, j])[i] == w[j] * V[i, j]`, where `(w, V) = A.eig()`
+//!
+//! for any valid `i`, `j`
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use complex::{Complex, c64, c128};
+use linalg::prelude::*;
+use quickcheck::TestResult;
+
+macro_rules! tests {
+    ($($t:ident => |$x:ident| $complex:expr),+,) => {
+        $(
+            #[quickcheck]
+            fn $t(n: u32, (i, j): (u32, u32)) -> TestResult {
+                enforce! {
+                    i < n,
+                    j < n,
+                }
+
+                let a = ::setup::rand::mat::<$t>((n, n));
+                let b = a.clone();
+
+                let (w, v) = a.eig();
+
+                let lhs = (0..n).fold(Complex { re: 0., im: 0. }, |acc, k| {
+                    let $x = b[(i, k)];
+
+                    acc + $complex * v[(k, j)]
+                });
+
+                test_approx_eq!(lhs, w[j] * v[(i, j)])
+            }
+         )+
+    };
+}
+
+tests! {
+    f32 => |x| Complex { re: x, im: 0. },
+    f64 => |x| Complex { re: x, im: 0. },
+    c64 => |x| x,
+    c128 => |x| x,
+}
@@ -0,0 +1,86 @@
+//! Test that:
+//!
+//! - `(A * V[:, j])[i] == w[j] * V[i, j]`, where `(w, V) = A.eigh()`
+//! - `A.eigh_range(0..k).0[j] == A.eigh().0[j]`
+//!
+//! for any valid `i`, `j`, `k`, and for any symmetric (hermitian) matrix `A`
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+mod range {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::traits::Scalar;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((n, k): (u32, u32), j: u32) -> TestResult {
+                    enforce! {
+                        j < k,
+                        k <= n,
+                    }
+
+                    let ref b = ::setup::rand::mat::<$t>((n, n));
+                    let a = Mat::from_fn((n, n), |(r, c)| b[(r, c)] + Scalar::conj(b[(c, r)]));
+
+                    let (w, _) = a.clone().eigh();
+                    let (w_k, v_k) = a.eigh_range(0..k);
+
+                    test_eq!(v_k.size(), (n, k));
+                    test_approx_eq!(w_k[j], w[j])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+use complex::{Complex, c64, c128};
+use linalg::prelude::*;
+use linalg::traits::Scalar;
+use quickcheck::TestResult;
+
+macro_rules! tests {
+    ($($t:ident => |$w:ident| $scalar:expr),+,) => {
+        $(
+            #[quickcheck]
+            fn $t(n: u32, (i, j): (u32, u32)) -> TestResult {
+                enforce! {
+                    i < n,
+                    j < n,
+                }
+
+                let ref b = ::setup::rand::mat::<$t>((n, n));
+                let ref a = Mat::from_fn((n, n), |(r, c)| b[(r, c)] + Scalar::conj(b[(c, r)]));
+
+                let (w, ref v) = a.clone().eigh();
+                let $w = w[j];
+
+                test_approx_eq!((a * v.col(j)).eval()[i], $scalar * v[(i, j)])
+            }
+         )+
+    };
+}
+
+tests! {
+    f32 => |w| w,
+    f64 => |w| w,
+    c64 => |w| Complex { re: w, im: 0. },
+    c128 => |w| Complex { re: w, im: 0. },
+}
@@ -0,0 +1,207 @@
+//! Test that:
+//!
+//! - `expm(D)[r, c] == if r == c { exp(D[r, r]) } else { 0 }`
+//! - `expm(N) == expm(a) * (I + b * E)`, where `N = a * I + b * E`
+//! - `(S * S)[r, c] == A[r, c]`, where `S = sqrtm(A)`
+//! - `expm(logm(A))[r, c] == A[r, c]`
+//! - `sqrtm(-I)` and `logm(-I)` fail
+//!
+//! for any valid `r`, `c`, where `D` is a diagonal matrix, `E` is zero everywhere but on its top
+//! right corner, which is one, `b` is large enough to require scaling and squaring, and `A` is a
+//! matrix whose eigenvalues lie in the right half-plane
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+// expm(D)
+mod expm_diag {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < n,
+                    }
+
+                    let ref d = ::setup::rand::col::<$ty>(n);
+                    let a = Mat::from_fn((n, n), |(r, c)| if r == c { d[r] } else { 0. });
+
+                    let e = if row == col { d[row].exp() } else { 0. };
+
+                    test_approx_eq!(a.expm()[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+}
+
+// expm(N)
+mod expm_nilpotent {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        n > 1,
+                        row < n,
+                        col < n,
+                    }
+
+                    let _0 = $ty::zero();
+                    let _1 = $ty::one();
+
+                    // NB `|b| >= 100`, which is well above the threshold of the degree 13 Padé
+                    // approximant
+                    let a = ::setup::rand::scalar::<$ty>();
+                    let hundred = (0..100).fold(_0, |s, _| s + _1);
+                    let b = hundred * (::setup::rand::scalar::<$ty>() + _1);
+                    let m = Mat::from_fn((n, n), |(r, c)| {
+                        if r == c { a } else if r == 0 && c == n - 1 { b } else { _0 }
+                    });
+
+                    // NB `exp(a)` is computed by the low degree Padé approximants
+                    let exp_a = Mat::from_elem((1, 1), a).expm()[(0, 0)];
+                    let e = if row == col {
+                        exp_a
+                    } else if row == 0 && col == n - 1 {
+                        exp_a * b
+                    } else {
+                        _0
+                    };
+
+                    test_approx_eq!(m.expm()[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// sqrtm(A)
+mod sqrtm {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < n,
+                    }
+
+                    // NB shift the spectrum to the right half-plane
+                    let shift = (0..n).fold($ty::zero(), |s, _| s + $ty::one());
+                    let a = ::setup::rand::mat::<$ty>((n, n));
+                    let a = Mat::from_fn((n, n), |(r, c)| {
+                        if r == c { a[(r, c)] + shift } else { a[(r, c)] }
+                    });
+
+                    let s = a.clone().sqrtm().unwrap();
+
+                    test_approx_eq!(s.row(row) * s.col(col), a[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// expm(logm(A))
+mod logm {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < n,
+                    }
+
+                    // NB see `sqrtm`
+                    let shift = (0..n).fold($ty::zero(), |s, _| s + $ty::one());
+                    let a = ::setup::rand::mat::<$ty>((n, n));
+                    let a = Mat::from_fn((n, n), |(r, c)| {
+                        if r == c { a[(r, c)] + shift } else { a[(r, c)] }
+                    });
+
+                    let b = a.clone().logm().unwrap().expm();
+
+                    test_approx_eq!(b[(row, col)], a[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// sqrtm(-I), logm(-I)
+mod negative_identity {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::Error;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32) -> TestResult {
+                    enforce! {
+                        n != 0,
+                    }
+
+                    let _0 = $ty::zero();
+                    let _1 = $ty::one();
+
+                    // NB the first Denman-Beavers step maps `-I` to a singular matrix
+                    let a = Mat::from_fn((n, n), |(r, c)| if r == c { _0 - _1 } else { _0 });
+                    let e = Some(Error::Singular { pivot: 0 });
+
+                    test!(a.clone().sqrtm().err() == e && a.logm().err() == e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
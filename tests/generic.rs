@@ -0,0 +1,260 @@
+//! Test that, for element types that are not supported by BLAS:
+//!
+//! - `(A + B)[r, c] == A[r, c] + B[r, c]`
+//! - `(A * B)[r, c] == sum(A[r, k] * B[k, c] for k in 0..K)`
+//! - `(A * x)[r] == sum(A[r, k] * x[k] for k in 0..K)`
+//! - `A[r, :] * B[:, c] == sum(A[r, k] * B[k, c] for k in 0..K)`
+//! - `(alpha * A * x)[r] == alpha * sum(A[r, k] * x[k] for k in 0..K)`, for primitive integers
+//!
+//! for any valid `r`, `c`
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+#[macro_use]
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use std::ops::{Add, Mul};
+
+use linalg::Native;
+use linalg::traits::Scalar;
+use onezero::{One, Zero};
+
+/// Integers modulo 7
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Mod7(u8);
+
+impl Add for Mod7 {
+    type Output = Mod7;
+
+    fn add(self, rhs: Mod7) -> Mod7 {
+        Mod7((self.0 + rhs.0) % 7)
+    }
+}
+
+impl Mul for Mod7 {
+    type Output = Mod7;
+
+    fn mul(self, rhs: Mod7) -> Mod7 {
+        Mod7((self.0 * rhs.0) % 7)
+    }
+}
+
+impl One for Mod7 {
+    fn one() -> Mod7 {
+        Mod7(1)
+    }
+}
+
+impl Zero for Mod7 {
+    fn zero() -> Mod7 {
+        Mod7(0)
+    }
+}
+
+element!(Native {
+    Mod7 => Mod7,
+});
+
+impl Scalar for Mod7 {}
+
+/// Small elements, to avoid overflow
+trait Elem: Copy {
+    fn elem((u32, u32)) -> Self;
+}
+
+impl Elem for i64 {
+    fn elem((r, c): (u32, u32)) -> i64 {
+        (r % 5) as i64 - (c % 3) as i64
+    }
+}
+
+impl Elem for u8 {
+    fn elem((r, c): (u32, u32)) -> u8 {
+        ((r + c) % 2) as u8
+    }
+}
+
+impl Elem for Mod7 {
+    fn elem((r, c): (u32, u32)) -> Mod7 {
+        Mod7(((r + 2 * c) % 7) as u8)
+    }
+}
+
+// A + B
+mod add {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    use {Elem, Mod7};
+
+    macro_rules! tests {
+        ($($name:ident: $t:ty),+) => {
+            $(
+                #[quickcheck]
+                fn $name((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let a = Mat::<$t>::from_fn((nrows, ncols), Elem::elem);
+                    let b = Mat::<$t>::from_fn((nrows, ncols), |(r, c)| Elem::elem((c, r)));
+
+                    let e = a[(row, col)] + b[(row, col)];
+                    let c = a + b;
+
+                    test_eq!(c[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(i64: i64, u8: u8, mod7: Mod7);
+}
+
+// A * B
+mod mul {
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    use {Elem, Mod7};
+
+    macro_rules! tests {
+        ($($name:ident: $t:ty),+) => {
+            $(
+                #[quickcheck]
+                fn $name((m, k, n): (u32, u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        k != 0,
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref a = Mat::<$t>::from_fn((m, k), Elem::elem);
+                    let ref b = Mat::<$t>::from_fn((k, n), |(r, c)| Elem::elem((c, r)));
+
+                    let c = (a * b).eval();
+
+                    let e = (0..k).fold(<$t>::zero(), |e, i| e + a[(row, i)] * b[(i, col)]);
+
+                    test_eq!(c[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(i64: i64, u8: u8, mod7: Mod7);
+}
+
+// A * x
+mod mul_col {
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    use {Elem, Mod7};
+
+    macro_rules! tests {
+        ($($name:ident: $t:ty),+) => {
+            $(
+                #[quickcheck]
+                fn $name((m, k): (u32, u32), row: u32) -> TestResult {
+                    enforce! {
+                        k != 0,
+                        row < m,
+                    }
+
+                    let ref a = Mat::<$t>::from_fn((m, k), Elem::elem);
+                    let ref x: ColVec<$t> = (0..k).map(|i| Elem::elem((i, 0))).collect();
+
+                    let y = (a * x).eval();
+
+                    let e = (0..k).fold(<$t>::zero(), |e, i| e + a[(row, i)] * x[i]);
+
+                    test_eq!(y[row], e)
+                }
+             )+
+        }
+    }
+
+    tests!(i64: i64, u8: u8, mod7: Mod7);
+}
+
+// A[r, :] * B[:, c]
+mod row_col {
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    use {Elem, Mod7};
+
+    macro_rules! tests {
+        ($($name:ident: $t:ty),+) => {
+            $(
+                #[quickcheck]
+                fn $name((m, k, n): (u32, u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        k != 0,
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref a = Mat::<$t>::from_fn((m, k), Elem::elem);
+                    let ref b = Mat::<$t>::from_fn((k, n), |(r, c)| Elem::elem((c, r)));
+
+                    let e = (0..k).fold(<$t>::zero(), |e, i| e + a[(row, i)] * b[(i, col)]);
+
+                    test_eq!(a.row(row) * b.col(col), e)
+                }
+             )+
+        }
+    }
+
+    tests!(i64: i64, u8: u8, mod7: Mod7);
+}
+
+// alpha * A * x
+mod scaled {
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    use Elem;
+
+    macro_rules! tests {
+        ($($name:ident: $t:ty),+) => {
+            $(
+                #[quickcheck]
+                fn $name((m, k): (u32, u32), row: u32) -> TestResult {
+                    enforce! {
+                        k != 0,
+                        row < m,
+                    }
+
+                    let alpha = <$t>::one() + <$t>::one();
+                    let ref a = Mat::<$t>::from_fn((m, k), Elem::elem);
+                    let ref x: ColVec<$t> = (0..k).map(|i| Elem::elem((i, 0))).collect();
+
+                    let y = (alpha * a * x).eval();
+
+                    let e = (0..k).fold(<$t>::zero(), |e, i| e + a[(row, i)] * x[i]);
+
+                    test_eq!(y[row], alpha * e)
+                }
+             )+
+        }
+    }
+
+    tests!(i64: i64, u8: u8);
+}
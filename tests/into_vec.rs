@@ -0,0 +1,86 @@
+//! Test that:
+//!
+//! - `Mat::from_vec((m, n), v)[r, c] == v[c * m + r]`
+//! - `Mat::from_vec((m, n), v).into_vec() == v`, and the allocation is re-used
+//! - `A.reshape((p, q))[r, c] == A.into_vec()[c * p + r]`
+//! - `A.into_col()[c * m + r] == A[r, c]`
+//! - `x.into_mat((m, n))[r, c] == x[c * m + r]`
+//!
+//! for any valid `r`, `c`
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use linalg::prelude::*;
+use quickcheck::TestResult;
+
+#[quickcheck]
+fn from_vec((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+    enforce! {
+        row < m,
+        col < n,
+    }
+
+    let v = (0..m * n).collect::<Vec<_>>();
+    let a = Mat::from_vec((m, n), v);
+
+    test_eq!(a[(row, col)], col * m + row)
+}
+
+#[quickcheck]
+fn into_vec((m, n): (u32, u32)) -> bool {
+    let v = (0..m * n).collect::<Vec<_>>();
+    let ptr = v.as_ptr();
+    let v = Mat::from_vec((m, n), v).into_vec();
+
+    v.as_ptr() == ptr && v == (0..m * n).collect::<Vec<_>>()
+}
+
+#[quickcheck]
+fn reshape((m, n): (u32, u32), p: u32, (row, col): (u32, u32)) -> TestResult {
+    enforce! {
+        p != 0,
+        (m * n) % p == 0,
+        row < p,
+        col < m * n / p,
+    }
+
+    let a = Mat::from_fn((m, n), |(r, c)| c * m + r);
+    let b = a.reshape((p, m * n / p));
+
+    test_eq!(b[(row, col)], col * p + row)
+}
+
+#[quickcheck]
+fn into_col((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+    enforce! {
+        row < m,
+        col < n,
+    }
+
+    let a = Mat::from_fn((m, n), |i| i);
+    let x = a.clone().into_col();
+
+    test_eq!(x[col * m + row], a[(row, col)])
+}
+
+#[quickcheck]
+fn into_mat((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+    enforce! {
+        row < m,
+        col < n,
+    }
+
+    let x = (0..m * n).collect::<ColVec<_>>();
+    let a = x.into_mat((m, n));
+
+    test_eq!(a[(row, col)], col * m + row)
+}
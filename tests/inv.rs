@@ -3,6 +3,7 @@
 //! - `A[r, :] * (A^-1)[:, c] == if r == c { 1 } else { 0 }`
 //! - `(A^-1)[r, :] * A[:, c] == if r == c { 1 } else { 0 }`
 
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
 #![feature(custom_attribute)]
 #![feature(plugin)]
 #![plugin(quickcheck_macros)]
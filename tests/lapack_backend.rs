@@ -0,0 +1,459 @@
+//! Test that:
+//!
+//! - `A.lu().rcond()` calls the `gecon` routine of the LAPACK backend set with
+//!   `set_lapack_backend`
+//! - `A.lstsq(b)` calls the `gelsd` routine of the LAPACK backend
+//! - `A.matrix_norm(NormKind::Spectral)` calls the `gesdd` routine of the LAPACK backend
+//! - `A.eig()` calls the `geev` routine of the LAPACK backend
+//! - `A.eigh()` calls the `heevd` routine of the LAPACK backend
+//! - restoring the default LAPACK backend stops the calls to the replaced backend
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use linalg::prelude::*;
+use linalg::traits::LapackBackend;
+use linalg::{Ffi, NormKind};
+use quickcheck::TestResult;
+
+/// Records the names of the LAPACK routines that get called, and forwards all the calls to `Ffi`
+struct Tracing(Rc<RefCell<Vec<&'static str>>>);
+
+impl LapackBackend<f64> for Tracing {
+    unsafe fn gbsv(
+        &self,
+        n: &i32,
+        kl: &i32,
+        ku: &i32,
+        nrhs: &i32,
+        ab: *mut f64,
+        ldab: &i32,
+        ipiv: *mut i32,
+        b: *mut f64,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("gbsv");
+
+        Ffi.gbsv(n, kl, ku, nrhs, ab, ldab, ipiv, b, ldb, info)
+    }
+
+    unsafe fn gecon(
+        &self,
+        norm: &u8,
+        n: &i32,
+        a: *const f64,
+        lda: &i32,
+        anorm: &f64,
+        rcond: &mut f64,
+        work: *mut f64,
+        rwork: *mut f64,
+        iwork: *mut i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("gecon");
+
+        Ffi.gecon(norm, n, a, lda, anorm, rcond, work, rwork, iwork, info)
+    }
+
+    unsafe fn geev(
+        &self,
+        jobvl: &u8,
+        jobvr: &u8,
+        n: &i32,
+        a: *mut f64,
+        lda: &i32,
+        w: *mut f64,
+        wi: *mut f64,
+        vl: *mut f64,
+        ldvl: &i32,
+        vr: *mut f64,
+        ldvr: &i32,
+        work: *mut f64,
+        lwork: &i32,
+        rwork: *mut f64,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("geev");
+
+        Ffi.geev(jobvl, jobvr, n, a, lda, w, wi, vl, ldvl, vr, ldvr, work, lwork, rwork, info)
+    }
+
+    unsafe fn gelsd(
+        &self,
+        m: &i32,
+        n: &i32,
+        nrhs: &i32,
+        a: *mut f64,
+        lda: &i32,
+        b: *mut f64,
+        ldb: &i32,
+        s: *mut f64,
+        rcond: &f64,
+        rank: &mut i32,
+        work: *mut f64,
+        lwork: &i32,
+        rwork: *mut f64,
+        iwork: *mut i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("gelsd");
+
+        Ffi.gelsd(m, n, nrhs, a, lda, b, ldb, s, rcond, rank, work, lwork, rwork, iwork, info)
+    }
+
+    unsafe fn geqrf(
+        &self,
+        m: &i32,
+        n: &i32,
+        a: *mut f64,
+        lda: &i32,
+        tau: *mut f64,
+        work: *mut f64,
+        lwork: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("geqrf");
+
+        Ffi.geqrf(m, n, a, lda, tau, work, lwork, info)
+    }
+
+    unsafe fn gesdd(
+        &self,
+        jobz: &u8,
+        m: &i32,
+        n: &i32,
+        a: *mut f64,
+        lda: &i32,
+        s: *mut f64,
+        u: *mut f64,
+        ldu: &i32,
+        vt: *mut f64,
+        ldvt: &i32,
+        work: *mut f64,
+        lwork: &i32,
+        rwork: *mut f64,
+        iwork: *mut i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("gesdd");
+
+        Ffi.gesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, rwork, iwork, info)
+    }
+
+    unsafe fn getrf(
+        &self,
+        m: &i32,
+        n: &i32,
+        a: *mut f64,
+        lda: &i32,
+        ipiv: *mut i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("getrf");
+
+        Ffi.getrf(m, n, a, lda, ipiv, info)
+    }
+
+    unsafe fn getri(
+        &self,
+        n: &i32,
+        a: *mut f64,
+        lda: &i32,
+        ipiv: *const i32,
+        work: *mut f64,
+        lwork: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("getri");
+
+        Ffi.getri(n, a, lda, ipiv, work, lwork, info)
+    }
+
+    unsafe fn getrs(
+        &self,
+        trans: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const f64,
+        lda: &i32,
+        ipiv: *const i32,
+        b: *mut f64,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("getrs");
+
+        Ffi.getrs(trans, n, nrhs, a, lda, ipiv, b, ldb, info)
+    }
+
+    unsafe fn gtsv(
+        &self,
+        n: &i32,
+        nrhs: &i32,
+        dl: *mut f64,
+        d: *mut f64,
+        du: *mut f64,
+        b: *mut f64,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("gtsv");
+
+        Ffi.gtsv(n, nrhs, dl, d, du, b, ldb, info)
+    }
+
+    unsafe fn heevd(
+        &self,
+        jobz: &u8,
+        uplo: &u8,
+        n: &i32,
+        a: *mut f64,
+        lda: &i32,
+        w: *mut f64,
+        work: *mut f64,
+        lwork: &i32,
+        rwork: *mut f64,
+        lrwork: &i32,
+        iwork: *mut i32,
+        liwork: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("heevd");
+
+        Ffi.heevd(jobz, uplo, n, a, lda, w, work, lwork, rwork, lrwork, iwork, liwork, info)
+    }
+
+    unsafe fn heevr(
+        &self,
+        jobz: &u8,
+        range: &u8,
+        uplo: &u8,
+        n: &i32,
+        a: *mut f64,
+        lda: &i32,
+        vl: &f64,
+        vu: &f64,
+        il: &i32,
+        iu: &i32,
+        abstol: &f64,
+        m: &mut i32,
+        w: *mut f64,
+        z: *mut f64,
+        ldz: &i32,
+        isuppz: *mut i32,
+        work: *mut f64,
+        lwork: &i32,
+        rwork: *mut f64,
+        lrwork: &i32,
+        iwork: *mut i32,
+        liwork: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("heevr");
+
+        Ffi.heevr(
+            jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz, isuppz, work,
+            lwork, rwork, lrwork, iwork, liwork, info,
+        )
+    }
+
+    unsafe fn lange(
+        &self,
+        norm: &u8,
+        m: &i32,
+        n: &i32,
+        a: *const f64,
+        lda: &i32,
+        work: *mut f64,
+    ) -> f64 {
+        self.0.borrow_mut().push("lange");
+
+        Ffi.lange(norm, m, n, a, lda, work)
+    }
+
+    unsafe fn orgqr(
+        &self,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        a: *mut f64,
+        lda: &i32,
+        tau: *const f64,
+        work: *mut f64,
+        lwork: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("orgqr");
+
+        Ffi.orgqr(m, n, k, a, lda, tau, work, lwork, info)
+    }
+
+    unsafe fn ormqr(
+        &self,
+        side: &u8,
+        trans: &u8,
+        m: &i32,
+        n: &i32,
+        k: &i32,
+        a: *const f64,
+        lda: &i32,
+        tau: *const f64,
+        c: *mut f64,
+        ldc: &i32,
+        work: *mut f64,
+        lwork: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("ormqr");
+
+        Ffi.ormqr(side, trans, m, n, k, a, lda, tau, c, ldc, work, lwork, info)
+    }
+
+    unsafe fn potrf(&self, uplo: &u8, n: &i32, a: *mut f64, lda: &i32, info: &mut i32) {
+        self.0.borrow_mut().push("potrf");
+
+        Ffi.potrf(uplo, n, a, lda, info)
+    }
+
+    unsafe fn potri(&self, uplo: &u8, n: &i32, a: *mut f64, lda: &i32, info: &mut i32) {
+        self.0.borrow_mut().push("potri");
+
+        Ffi.potri(uplo, n, a, lda, info)
+    }
+
+    unsafe fn potrs(
+        &self,
+        uplo: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const f64,
+        lda: &i32,
+        b: *mut f64,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("potrs");
+
+        Ffi.potrs(uplo, n, nrhs, a, lda, b, ldb, info)
+    }
+
+    unsafe fn trtrs(
+        &self,
+        uplo: &u8,
+        trans: &u8,
+        diag: &u8,
+        n: &i32,
+        nrhs: &i32,
+        a: *const f64,
+        lda: &i32,
+        b: *mut f64,
+        ldb: &i32,
+        info: &mut i32,
+    ) {
+        self.0.borrow_mut().push("trtrs");
+
+        Ffi.trtrs(uplo, trans, diag, n, nrhs, a, lda, b, ldb, info)
+    }
+}
+
+/// Runs `f` with the `Tracing` LAPACK backend, returns the routines that `f` called
+fn trace<F, R>(f: F) -> (R, Vec<&'static str>) where F: FnOnce() -> R {
+    let trace = Rc::new(RefCell::new(vec![]));
+    linalg::set_lapack_backend::<f64>(Box::new(Tracing(trace.clone())));
+
+    let r = f();
+
+    linalg::set_lapack_backend::<f64>(Box::new(Ffi));
+
+    let calls = trace.borrow().clone();
+
+    (r, calls)
+}
+
+#[quickcheck]
+fn gecon(n: u32) -> TestResult {
+    enforce! {
+        n != 0,
+    }
+
+    let ref lu = ::setup::rand::mat::<f64>((n, n)).lu();
+
+    let (rcond, calls) = trace(|| lu.rcond());
+
+    test!(calls.iter().all(|&name| name == "gecon") && !calls.is_empty());
+    test_eq!(rcond, lu.rcond())
+}
+
+#[quickcheck]
+fn gelsd((m, n): (u32, u32)) -> TestResult {
+    enforce! {
+        m != 0,
+        n != 0,
+    }
+
+    let ref a = ::setup::rand::mat::<f64>((m, n));
+    let ref b = ::setup::rand::col::<f64>(m);
+
+    let (x, calls) = trace(|| a.lstsq(b));
+
+    test!(calls.iter().all(|&name| name == "gelsd") && !calls.is_empty());
+    test_eq!(x, a.lstsq(b))
+}
+
+#[quickcheck]
+fn gesdd((m, n): (u32, u32)) -> TestResult {
+    enforce! {
+        m != 0,
+        n != 0,
+    }
+
+    let ref a = ::setup::rand::mat::<f64>((m, n));
+
+    let (norm, calls) = trace(|| a.matrix_norm(NormKind::Spectral));
+
+    test!(calls.iter().all(|&name| name == "gesdd") && !calls.is_empty());
+    test_eq!(norm, a.matrix_norm(NormKind::Spectral))
+}
+
+#[quickcheck]
+fn geev(n: u32) -> TestResult {
+    enforce! {
+        n != 0,
+    }
+
+    let ref a = ::setup::rand::mat::<f64>((n, n));
+
+    let (w, calls) = trace(|| a.clone().eigvals());
+
+    test!(calls.iter().all(|&name| name == "geev") && !calls.is_empty());
+    test_eq!(w, a.clone().eigvals())
+}
+
+#[quickcheck]
+fn heevd(n: u32) -> TestResult {
+    enforce! {
+        n != 0,
+    }
+
+    let ref a = ::setup::rand::mat::<f64>((n, n));
+
+    let ((w, _), calls) = trace(|| a.clone().eigh());
+
+    test!(calls.iter().all(|&name| name == "heevd") && !calls.is_empty());
+    test_eq!(w, a.clone().eigh().0)
+}
@@ -0,0 +1,239 @@
+//! Test that:
+//!
+//! - `A.lu().solve(A * x)[i] == x[i]`
+//! - `D.lu().det() == D[0, 0] * D[1, 1] * ...`, where `D` is a diagonal matrix
+//! - `(P * D).lu().det() == sign(P) * D[0, 0] * D[1, 1] * ...`, where `P` is a cyclic permutation
+//! - `(A * B).lu().det() == A.lu().det() * B.lu().det()`
+//! - `A[r, :] * A.lu().inv()[:, c] == if r == c { 1 } else { 0 }`
+//! - `D.lu().rcond() == min(|D[i, i]|) / max(|D[i, i]|)`
+//! - `A.lu().rcond() == 0`, where `A` is singular
+//!
+//! for any valid `i`, `r`, `c`
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+mod det {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n < 8,
+                    }
+
+                    let ref d = ::setup::rand::col::<$t>(n);
+                    let a = Mat::from_fn((n, n), |(r, c)| if r == c { d[r] } else { $t::zero() });
+
+                    let prod = d.iter().fold($t::one(), |acc, &x| acc * x);
+
+                    test_approx_eq!(a.lu().det(), prod)
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// NB `getrf` has to pivot every row of the permuted matrix
+mod det_permuted {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n < 8,
+                    }
+
+                    let ref d = ::setup::rand::col::<$t>(n);
+                    let a = Mat::from_fn((n, n), |(r, c)| {
+                        if c == (r + 1) % n { d[r] } else { $t::zero() }
+                    });
+
+                    let prod = d.iter().fold($t::one(), |acc, &x| acc * x);
+                    // NB a cycle of length `n` is the composition of `n - 1` transpositions
+                    let e = if n % 2 == 0 { -prod } else { prod };
+
+                    test_approx_eq!(a.lu().det(), e)
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod det_product {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n < 8,
+                    }
+
+                    let a = ::setup::rand::mat::<$t>((n, n));
+                    let b = ::setup::rand::mat::<$t>((n, n));
+                    let c = (&a * &b).eval();
+
+                    test_approx_eq!(c.lu().det(), a.lu().det() * b.lu().det())
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod inv {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::{One, Zero};
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32, (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < n,
+                    }
+
+                    let _0 = $t::zero();
+                    let _1 = $t::one();
+                    let a = ::setup::rand::mat::<$t>((n, n));
+                    let a_inv = a.clone().lu().inv();
+
+                    if row == col {
+                        test_approx_eq!(a.row(row) * a_inv.col(col), _1)
+                    } else {
+                        test_approx_eq!(a.row(row) * a_inv.col(col), _0)
+                    }
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod rcond {
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n != 0,
+                    }
+
+                    // NB keep the diagonal away from zero
+                    let d = ::setup::rand::col::<$t>(n);
+                    let d: ColVec<$t> = d.iter().map(|&x| x + 1.).collect();
+                    let a = Mat::from_fn((n, n), |(r, c)| if r == c { d[r] } else { 0. });
+
+                    let min = d.iter().fold(d[0], |min, &x| min.min(x));
+                    let max = d.iter().fold(d[0], |max, &x| max.max(x));
+
+                    test_approx_eq!(a.lu().rcond(), min / max)
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64);
+}
+
+mod rcond_singular {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32, col: u32) -> TestResult {
+                    enforce! {
+                        col < n,
+                    }
+
+                    let mut a = ::setup::rand::mat::<$t>((n, n));
+                    a.col_mut(col).set($t::zero());
+
+                    test_eq!(a.lu().rcond(), 0.)
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod solve {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32, i: u32) -> TestResult {
+                    enforce! {
+                        i < n,
+                    }
+
+                    let a = ::setup::rand::mat::<$t>((n, n));
+                    let ref x = ::setup::rand::col::<$t>(n);
+                    let ref y = ::setup::rand::col::<$t>(n);
+                    let b = (&a * x).eval();
+                    let c = (&a * y).eval();
+
+                    let lu = a.lu();
+
+                    test_approx_eq!(lu.solve(b)[i], x[i]);
+                    test_approx_eq!(lu.solve(c)[i], y[i])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
@@ -0,0 +1,101 @@
+//! Test that:
+//!
+//! - `A.map(f)[r, c] == f(&A[r, c])`
+//! - `A.map_inplace(f); A[r, c] == f(A_old[r, c])`
+//! - `A.zip_with(&B, f)[r, c] == f(&A[r, c], &B[r, c])`
+//! - `A.zip3_with(&B, &C, f)[r, c] == f(&A[r, c], &B[r, c], &C[r, c])`
+//! - `A.fold(init, f)` visits the elements in column major order
+//!
+//! for any valid `r`, `c`, and where the operands may be transposed (or row/column vectors)
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use linalg::prelude::*;
+use quickcheck::TestResult;
+
+#[quickcheck]
+fn map((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+    enforce! {
+        row < m,
+        col < n,
+    }
+
+    let a = Mat::from_fn((m, n), |i| i);
+    let b = a.map(|&(r, c)| r * n + c);
+
+    test_eq!(b[(row, col)], row * n + col)
+}
+
+#[quickcheck]
+fn map_inplace((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+    enforce! {
+        row < m,
+        col < n,
+    }
+
+    let mut a = Mat::from_fn((n, m), |(r, c)| (c, r));
+    (&mut a).t().map_inplace(|e| e.0 += 1);
+
+    test_eq!((&a).t()[(row, col)], (row + 1, col))
+}
+
+#[quickcheck]
+fn zip_with((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+    enforce! {
+        row < m,
+        col < n,
+    }
+
+    let a = Mat::from_fn((m, n), |i| i);
+    let b = Mat::from_fn((n, m), |(r, c)| (c, r));
+    let c = a.zip_with(&b.t(), |a, b| a == b);
+
+    test_eq!(c[(row, col)], true)
+}
+
+#[quickcheck]
+fn zip3_with((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+    enforce! {
+        row < m,
+        col < n,
+    }
+
+    let a = Mat::from_fn((m, n), |i| i);
+    let b = Mat::from_fn((n, m), |(r, c)| (c, r));
+    let c = Mat::from_fn((m + 1, n + 1), |i| i);
+    let c = c.slice((..m, 1..));
+    let d = a.zip3_with(&b.t(), &c, |a, b, c| (*a, *b, *c));
+
+    test_eq!(d[(row, col)], ((row, col), (row, col), (row, col + 1)))
+}
+
+#[quickcheck]
+fn zip_with_row(n: u32, col: u32) -> TestResult {
+    enforce! {
+        col < n,
+    }
+
+    let a = Mat::from_fn((2, n), |i| i);
+    let x = (0..n).collect::<RowVec<_>>();
+    let y = a.row(1).zip_with(&x, |a, x| a.1 == *x);
+
+    test_eq!(y[col], true)
+}
+
+#[quickcheck]
+fn fold((m, n): (u32, u32)) -> bool {
+    let a = Mat::from_fn((m, n), |(r, c)| c * m + r);
+    let v = a.t().fold(vec![], |mut v, &e| { v.push(e); v });
+    let e = (0..m).flat_map(|c| (0..n).map(move |r| r * m + c)).collect::<Vec<_>>();
+
+    v == e
+}
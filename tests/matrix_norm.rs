@@ -0,0 +1,167 @@
+//! Test that:
+//!
+//! - `A.matrix_norm(One) == max(sum(abs(A[:, j])))`
+//! - `A.matrix_norm(Inf) == max(sum(abs(A[i, :])))`
+//! - `A.matrix_norm(MaxAbs) == max(abs(A[i, j]))`
+//! - `D.matrix_norm(Spectral) == max(abs(D[i, i]))`, where `D` is a diagonal matrix
+//! - `D.matrix_norm(Nuclear) == sum(abs(D[i, i]))`, where `D` is a diagonal matrix
+//!
+//! where `A` is a (possibly non-contiguous) view into a matrix
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use linalg::NormKind;
+use linalg::prelude::*;
+use quickcheck::TestResult;
+
+fn max<T>(x: T, y: T) -> T where T: PartialOrd {
+    if x > y { x } else { y }
+}
+
+macro_rules! tests {
+    ($($t:ident),+) => {
+        mod inf {
+            use linalg::NormKind;
+            use linalg::prelude::*;
+            use quickcheck::TestResult;
+
+            $(
+                #[quickcheck]
+                fn $t((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let ref m = ::setup::rand::mat::<$t>((nrows, ncols));
+                    let a = m.slice((row.., col..));
+
+                    let expected = a.rows().map(|r| {
+                        r.iter().fold(0., |acc, x| acc + x.abs())
+                    }).fold(0., ::max);
+
+                    test_approx_eq!(a.matrix_norm(NormKind::Inf), expected)
+                }
+             )+
+        }
+
+        mod max_abs {
+            use linalg::NormKind;
+            use linalg::prelude::*;
+            use quickcheck::TestResult;
+
+            $(
+                #[quickcheck]
+                fn $t((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let ref m = ::setup::rand::mat::<$t>((nrows, ncols));
+                    let a = m.slice((row.., col..));
+
+                    let expected = a.iter().map(|x| x.abs()).fold(0., ::max);
+
+                    test_approx_eq!(a.matrix_norm(NormKind::MaxAbs), expected)
+                }
+             )+
+        }
+
+        mod nuclear {
+            use linalg::NormKind;
+            use linalg::prelude::*;
+            use quickcheck::TestResult;
+
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    let ref d = ::setup::rand::col::<$t>(n);
+                    let a = Mat::from_fn((n, n), |(r, c)| if r == c { d[r] } else { 0. });
+
+                    let expected = d.iter().fold(0., |acc, x| acc + x.abs());
+
+                    test_approx_eq!(a.matrix_norm(NormKind::Nuclear), expected)
+                }
+             )+
+        }
+
+        mod one {
+            use linalg::NormKind;
+            use linalg::prelude::*;
+            use quickcheck::TestResult;
+
+            $(
+                #[quickcheck]
+                fn $t((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let ref m = ::setup::rand::mat::<$t>((nrows, ncols));
+                    let a = m.slice((row.., col..));
+
+                    let expected = a.cols().map(|c| {
+                        c.iter().fold(0., |acc, x| acc + x.abs())
+                    }).fold(0., ::max);
+
+                    test_approx_eq!(a.matrix_norm(NormKind::One), expected)
+                }
+             )+
+        }
+
+        mod spectral {
+            use linalg::NormKind;
+            use linalg::prelude::*;
+            use quickcheck::TestResult;
+
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    let ref d = ::setup::rand::col::<$t>(n);
+                    let a = Mat::from_fn((n, n), |(r, c)| if r == c { d[r] } else { 0. });
+
+                    let expected = d.iter().map(|x| x.abs()).fold(0., ::max);
+
+                    test_approx_eq!(a.matrix_norm(NormKind::Spectral), expected)
+                }
+             )+
+        }
+
+        mod transposed {
+            use linalg::NormKind;
+            use linalg::prelude::*;
+            use quickcheck::TestResult;
+
+            $(
+                #[quickcheck]
+                fn $t((nrows, ncols): (u32, u32)) -> TestResult {
+                    let ref a = ::setup::rand::mat::<$t>((nrows, ncols));
+
+                    test_approx_eq!(a.t().matrix_norm(NormKind::One), a.matrix_norm(NormKind::Inf))
+                }
+             )+
+        }
+    };
+}
+
+tests!(f32, f64);
+
+#[quickcheck]
+fn frobenius((nrows, ncols): (u32, u32)) -> TestResult {
+    let ref a = ::setup::rand::mat::<f64>((nrows, ncols));
+
+    test_approx_eq!(a.matrix_norm(NormKind::Frobenius), a.norm())
+}
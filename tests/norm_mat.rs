@@ -1,6 +1,7 @@
 //! Test that:
 //!
-//! `norm(a)^2 == a.iter().map(|&x| x^2).sum()`
+//! `norm(a)^2 == a.iter().map(|&x| x^2).sum()`, where `a` is a matrix, a transposed matrix, or a
+//! (possibly non-contiguous) view into a matrix
 
 #![feature(core)]
 #![feature(custom_attribute)]
@@ -20,6 +21,60 @@ use quickcheck::TestResult;
 #[macro_use]
 mod setup;
 
+mod strided {
+    use complex::{Math, c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let ref m = ::setup::rand::mat::<$t>((nrows, ncols));
+                    let a = m.slice((row.., col..));
+
+                    let norm = a.norm();
+                    let ssq = a.iter().map(|&x| x * x).sum();
+
+                    test_approx_eq!(norm * norm, ssq)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64);
+
+    macro_rules! complex_tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let ref m = ::setup::rand::mat::<$t>((nrows, ncols));
+                    let a = m.slice((row.., col..));
+
+                    let norm = a.norm();
+                    let ssq = a.iter().map(|&x| x.abs() * x.abs()).sum();
+
+                    test_approx_eq!(norm * norm, ssq)
+                }
+             )+
+        }
+    }
+
+    complex_tests!(c64, c128);
+}
+
 mod transposed {
     use complex::{Math, c64, c128};
     use linalg::prelude::*;
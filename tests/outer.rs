@@ -0,0 +1,122 @@
+//! Test that:
+//!
+//! - `(x * y)[r, c] == x[r] * y[c]`
+//! - `{ A += alpha * x * y; A }[r, c] == A[r, c] + alpha * x[r] * y[c]`
+//! - `{ A -= x * y; A }[r, c] == A[r, c] - x[r] * y[c]`
+//!
+//! for any valid `r`, `c`, where `x` is a column vector and `y` is a row vector, and for `A` being
+//! a matrix, a sub-matrix or a transposed matrix
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+// (x * y).eval()
+mod eval {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref x = ::setup::rand::col::<$ty>(m);
+                    let ref y = ::setup::rand::row::<$ty>(n);
+
+                    let a = (x * y).eval();
+
+                    test_approx_eq!(a[(row, col)], x[row] * y[col])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A[s..] += alpha * x * y
+mod add_assign {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(
+                    (srow, scol): (u32, u32),
+                    (m, n): (u32, u32),
+                    (row, col): (u32, u32),
+                ) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let alpha: $ty = ::setup::rand::scalar();
+                    let mut a = ::setup::rand::mat::<$ty>((srow + m, scol + n));
+                    let ref x = ::setup::rand::col::<$ty>(m);
+                    let ref y = ::setup::rand::row::<$ty>(n);
+
+                    let e = a[(srow + row, scol + col)] + alpha * x[row] * y[col];
+
+                    a.slice_mut((srow.., scol..)).add_assign(alpha * x * y);
+
+                    test_approx_eq!(a[(srow + row, scol + col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.t() -= x * y.t()
+mod sub_assign_transposed {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let mut at = ::setup::rand::mat::<$ty>((n, m)).t();
+                    let ref x = ::setup::rand::col::<$ty>(m);
+                    let ref y = ::setup::rand::col::<$ty>(n);
+
+                    let e = at[(row, col)] - x[row] * y[col];
+
+                    at.sub_assign(x * y.t());
+
+                    test_approx_eq!(at[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
@@ -0,0 +1,245 @@
+//! Test that:
+//!
+//! - `(Q * R)[r, c] == A[r, c]`, where `Q` and `R` are the factors of `A.qr()`
+//! - `A.qr().solve(A * x)[i] == x[i]`
+//! - `A.lstsq(A * x)[i] == x[i]`
+//! - `A.lstsq(A * X)[r, c] == X[r, c]`
+//! - `(A * A.lstsq(A * x))[i] == (A * x)[i]`, where `A` is rank deficient
+//! - `A.qr().qh_mul(A.qr().q_mul(C))[r, c] == C[r, c]`
+//! - `(A.qr().r_tri() * x)[i] == (A.qr().r() * x)[i]`
+//!
+//! for any valid `i`, `r`, `c`, and for any `A` with at least as many rows as columns
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+mod factors {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((nrows, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < nrows,
+                        col < ncols,
+                    }
+
+                    let a = ::setup::rand::mat::<$t>((nrows, ncols));
+                    let b = a.clone();
+
+                    let qr = a.qr();
+                    let q = qr.q();
+                    let r = qr.r();
+
+                    test_approx_eq!((&q * &r).eval()[(row, col)], b[(row, col)])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod lstsq {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((m, n): (u32, u32), i: u32) -> TestResult {
+                    enforce! {
+                        i < n,
+                        n <= m,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$t>((m, n));
+                    let ref x = ::setup::rand::col::<$t>(n);
+                    let b = (a * x).eval();
+
+                    let y = a.lstsq(b);
+
+                    test_approx_eq!(y[i], x[i])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod solve {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((m, n): (u32, u32), i: u32) -> TestResult {
+                    enforce! {
+                        i < n,
+                        n <= m,
+                    }
+
+                    let a = ::setup::rand::mat::<$t>((m, n));
+                    let ref x = ::setup::rand::col::<$t>(n);
+                    let b = (&a * x).eval();
+
+                    let y = a.qr().solve(b);
+
+                    test_approx_eq!(y[i], x[i])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod lstsq_mat {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((m, n): (u32, u32), (row, col): (u32, u32), nrhs: u32) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < nrhs,
+                        n <= m,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$t>((m, n));
+                    let ref x = ::setup::rand::mat::<$t>((n, nrhs));
+                    let b = (a * x).eval();
+
+                    let y = a.lstsq(b);
+
+                    test_eq!(y.size(), x.size());
+                    test_approx_eq!(y[(row, col)], x[(row, col)])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod lstsq_rank_deficient {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((m, n): (u32, u32), i: u32) -> TestResult {
+                    enforce! {
+                        i < m,
+                        n >= 2,
+                        n <= m,
+                    }
+
+                    // the last column is a copy of the first one
+                    let ref a = {
+                        let a = ::setup::rand::mat::<$t>((m, n));
+
+                        Mat::from_fn((m, n), |(r, c)| {
+                            if c == n - 1 { a[(r, 0)] } else { a[(r, c)] }
+                        })
+                    };
+                    let ref x = ::setup::rand::col::<$t>(n);
+                    let ref b = (a * x).eval();
+
+                    let ref y = a.lstsq(b);
+
+                    test_approx_eq!((a * y).eval()[i], b[i])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod q_mul {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((m, n): (u32, u32), (row, col): (u32, u32), ncols: u32) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < ncols,
+                    }
+
+                    let qr = ::setup::rand::mat::<$t>((m, n)).qr();
+                    let c = ::setup::rand::mat::<$t>((m, ncols));
+
+                    let d = qr.qh_mul(qr.q_mul(c.clone()));
+
+                    test_approx_eq!(d[(row, col)], c[(row, col)])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod r_tri {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t((m, n): (u32, u32), i: u32) -> TestResult {
+                    enforce! {
+                        i < n,
+                        n <= m,
+                    }
+
+                    let qr = ::setup::rand::mat::<$t>((m, n)).qr();
+                    let ref x = ::setup::rand::col::<$t>(n);
+
+                    let y = (qr.r_tri() * x).eval();
+                    let z = (&qr.r() * x).eval();
+
+                    test_approx_eq!(y[i], z[i])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
@@ -0,0 +1,220 @@
+//! Test that:
+//!
+//! - `SubMat::reshape_row_major(a, (m, n))[r, c] == a[r * n + c]`
+//! - `SubMatMut::reshape_row_major(a, (m, n))[r, c] = x; a[r * n + c] == x`
+//! - `C.set(alpha * A * B); C[r, c] == alpha * A[r, :] * B[:, c]`, where `A`, `B` and `C` are
+//!   row-major
+//! - `RowMat::from_vec((m, n), a)[r, c] == a[r * n + c]`
+//! - `RowMat::from_vec((m, n), a).into_vec() == a`
+//! - `C.set(&A * &B); C[r, c] == A[r, :] * B[:, c]`, where `A` and `C` are `RowMat`s, and `B` is
+//!   a (column-major) `Mat`
+//!
+//! for any valid `r`, `c`
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use rand::Rand;
+
+/// Returns a vector of `n` random elements
+fn vec<T>(n: u32) -> Vec<T> where T: Rand {
+    (0..n).map(|_| ::setup::rand::scalar()).collect()
+}
+
+// A[r, c]
+mod index {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref a = ::vec::<$ty>(m * n);
+                    let a_ = SubMat::reshape_row_major(a, (m, n));
+
+                    test_eq!(a_[(row, col)], a[(row * n + col) as usize])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A[r, c] = x
+mod index_mut {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let x: $ty = ::setup::rand::scalar();
+                    let mut a = ::vec::<$ty>(m * n);
+
+                    SubMatMut::reshape_row_major(&mut a, (m, n))[(row, col)] = x;
+
+                    test_eq!(a[(row * n + col) as usize], x)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// C <- alpha * A * B
+mod set_mat_mat {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, k, n): (u32, u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                        k != 0,
+                    }
+
+                    let alpha: $ty = ::setup::rand::scalar();
+                    let ref a = ::vec::<$ty>(m * k);
+                    let ref b = ::vec::<$ty>(k * n);
+                    let mut c = vec![$ty::zero(); (m * n) as usize];
+
+                    {
+                        let a = SubMat::reshape_row_major(a, (m, k));
+                        let b = SubMat::reshape_row_major(b, (k, n));
+
+                        SubMatMut::reshape_row_major(&mut c, (m, n)).set(alpha * a * b);
+                    }
+
+                    let e = (0..k).fold($ty::zero(), |e, i| {
+                        e + a[(row * k + i) as usize] * b[(i * n + col) as usize]
+                    });
+
+                    test_approx_eq!(c[(row * n + col) as usize], alpha * e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// RowMat::from_vec(a)[r, c]
+mod from_vec {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let a = ::vec::<$ty>(m * n);
+                    let e = a[(row * n + col) as usize];
+                    let a = RowMat::from_vec((m, n), a);
+
+                    test_eq!(a[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// RowMat::from_vec(a).into_vec()
+mod into_vec {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32)) -> TestResult {
+                    let a = ::vec::<$ty>(m * n);
+
+                    test_eq!(RowMat::from_vec((m, n), a.clone()).into_vec(), a)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// C <- &A * &B, where A and C are row-major, and B is column-major
+mod set_owned {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, k, n): (u32, u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                        k != 0,
+                    }
+
+                    let ref a = RowMat::from_vec((m, k), ::vec::<$ty>(m * k));
+                    let ref b = ::setup::rand::mat::<$ty>((k, n));
+
+                    let mut c = RowMat::zeros((m, n));
+                    c.set(a * b);
+
+                    let e = (0..k).fold($ty::zero(), |e, i| e + a[(row, i)] * b[(i, col)]);
+
+                    test_approx_eq!(c[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
@@ -0,0 +1,76 @@
+//! Test that:
+//!
+//! - `A.solve(A * x)[i] == x[i]`
+//! - `A'.solve(A' * x)[i] == x[i]`
+//!
+//! for any valid `i`
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use complex::{c64, c128};
+use linalg::prelude::*;
+use quickcheck::TestResult;
+
+mod transposed {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32, i: u32) -> TestResult {
+                    enforce! {
+                        i < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$t>((n, n));
+                    let ref x = ::setup::rand::col::<$t>(n);
+                    let b = (a.t() * x).eval();
+
+                    let y = a.t().solve(b);
+
+                    test_approx_eq!(y[i], x[i])
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+macro_rules! tests {
+    ($($t:ident),+) => {
+        $(
+            #[quickcheck]
+            fn $t(n: u32, i: u32) -> TestResult {
+                enforce! {
+                    i < n,
+                }
+
+                let ref a = ::setup::rand::mat::<$t>((n, n));
+                let ref x = ::setup::rand::col::<$t>(n);
+                let b = (a * x).eval();
+
+                let y = a.solve(b);
+
+                test_approx_eq!(y[i], x[i])
+            }
+         )+
+    };
+}
+
+tests!(f32, f64, c64, c128);
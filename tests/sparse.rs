@@ -0,0 +1,215 @@
+//! Test that:
+//!
+//! - `(&S * x)[r] == sum(S[r, k] * x[k] for k in 0..n)`
+//! - `C.set(alpha * &S * B); C[r, c] == alpha * sum(S[r, k] * B[k, c] for k in 0..n)`
+//! - `C.add_assign(&S * B); C[r, c] == C_old[r, c] + sum(S[r, k] * B[k, c] for k in 0..n)`
+//! - `Mat::from(&S.t())[c, r] == S[r, c]`
+//! - `Mat::from(&SparseMat::from(&A))[r, c] == A[r, c]`
+//!
+//! for any valid `r`, `c`, where `S` has been built from random (possibly duplicated) triplets in
+//! either the CSC or the CSR format
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use std::ops::Add;
+
+use linalg::SparseFormat;
+use linalg::prelude::*;
+use onezero::Zero;
+use rand::Rand;
+
+/// Returns `nnz` random triplets of a `(nrows, ncols)` matrix, some positions may be repeated
+fn triplets<T>((nrows, ncols): (u32, u32), nnz: u32) -> Vec<(u32, u32, T)> where T: Rand {
+    (0..nnz).map(|_| {
+        let row = ::setup::rand::scalar::<u32>() % nrows;
+        let col = ::setup::rand::scalar::<u32>() % ncols;
+
+        (row, col, ::setup::rand::scalar())
+    }).collect()
+}
+
+/// Returns the dense matrix described by `triplets`, the values of repeated positions are summed
+fn dense<T>(size: (u32, u32), triplets: &[(u32, u32, T)]) -> Mat<T> where
+    T: Add<Output=T> + Copy + Zero,
+{
+    let mut m = Mat::zeros(size);
+
+    for &(row, col, value) in triplets {
+        m[(row, col)] = m[(row, col)] + value;
+    }
+
+    m
+}
+
+fn format(csr: bool) -> SparseFormat {
+    if csr { SparseFormat::Csr } else { SparseFormat::Csc }
+}
+
+// &S * x
+mod mul_col {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), nnz: u32, csr: bool, row: u32) -> TestResult {
+                    enforce! {
+                        row < m,
+                        n != 0,
+                    }
+
+                    let ref t = ::triplets::<$ty>((m, n), nnz);
+                    let ref s = SparseMat::from_triplets((m, n), ::format(csr), t);
+                    let ref a = ::dense((m, n), t);
+                    let ref x = ::setup::rand::col::<$ty>(n);
+
+                    let y = (s * x).eval();
+
+                    let e = (0..n).fold($ty::zero(), |e, k| e + a[(row, k)] * x[k]);
+
+                    test_approx_eq!(y[row], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// C.set(alpha * &S * B)
+mod set_mat {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(
+                    (m, k, n): (u32, u32, u32),
+                    nnz: u32,
+                    csr: bool,
+                    (row, col): (u32, u32),
+                ) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                        k != 0,
+                    }
+
+                    let alpha: $ty = ::setup::rand::scalar();
+                    let ref t = ::triplets::<$ty>((m, k), nnz);
+                    let ref s = SparseMat::from_triplets((m, k), ::format(csr), t);
+                    let ref a = ::dense((m, k), t);
+                    let ref b = ::setup::rand::mat::<$ty>((k, n));
+
+                    let mut c = Mat::<$ty>::zeros((m, n));
+                    c.set(alpha * s * b);
+
+                    let e = (0..k).fold($ty::zero(), |e, i| e + a[(row, i)] * b[(i, col)]);
+
+                    test_approx_eq!(c[(row, col)], alpha * e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// C.add_assign(&S * B)
+mod add_assign_mat {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(
+                    (m, k, n): (u32, u32, u32),
+                    nnz: u32,
+                    csr: bool,
+                    (row, col): (u32, u32),
+                ) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                        k != 0,
+                    }
+
+                    let ref t = ::triplets::<$ty>((m, k), nnz);
+                    let ref s = SparseMat::from_triplets((m, k), ::format(csr), t);
+                    let ref a = ::dense((m, k), t);
+                    let ref b = ::setup::rand::mat::<$ty>((k, n));
+                    let mut c = ::setup::rand::mat::<$ty>((m, n));
+
+                    let e = (0..k).fold(c[(row, col)], |e, i| e + a[(row, i)] * b[(i, col)]);
+
+                    c.add_assign(s * b);
+
+                    test_approx_eq!(c[(row, col)], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// Mat::from(&S.t()), Mat::from(&SparseMat::from(&A))
+mod convert {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::traits::Transpose;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(
+                    (m, n): (u32, u32),
+                    nnz: u32,
+                    csr: bool,
+                    (row, col): (u32, u32),
+                ) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref t = ::triplets::<$ty>((m, n), nnz);
+                    let s = SparseMat::from_triplets((m, n), ::format(csr), t);
+                    let ref a = ::dense((m, n), t);
+
+                    let at = Mat::from(&s.t());
+                    let b = Mat::from(&SparseMat::from(a));
+
+                    test_eq!((at[(col, row)], b[(row, col)]), (a[(row, col)], a[(row, col)]))
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
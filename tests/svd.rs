@@ -0,0 +1,147 @@
+//! Test that:
+//!
+//! - `(U * S * Vt)[r, c] == A[r, c]`, where `(U, s, Vt) = A.svd()` and `S = diag(s)`
+//! - `(U * S * Vt)[r, c] == A[r, c]`, where `(U, s, Vt) = A.svd_thin()` and `S = diag(s)`
+//! - `(U * S * Vt)[r, c] == V[r, c]`, where `(U, s, Vt) = V.try_svd_thin().unwrap()`,
+//!   `S = diag(s)` and `V` is a sub-matrix view
+//!
+//! for any valid `r`, `c`
+
+#![cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+mod thin {
+    use complex::{Complex, c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident => |$s:ident| $scalar:expr),+,) => {
+            $(
+                #[quickcheck]
+                fn $t((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let a = ::setup::rand::mat::<$t>((m, n));
+                    let b = a.clone();
+
+                    let (u, s, vt) = a.svd_thin();
+
+                    let x = (0..s.nrows()).fold($t::zero(), |acc, i| {
+                        let $s = s[i];
+
+                        acc + u[(row, i)] * $scalar * vt[(i, col)]
+                    });
+
+                    test_approx_eq!(x, b[(row, col)])
+                }
+             )+
+        };
+    }
+
+    tests! {
+        f32 => |s| s,
+        f64 => |s| s,
+        c64 => |s| Complex { re: s, im: 0. },
+        c128 => |s| Complex { re: s, im: 0. },
+    }
+}
+
+mod submat {
+    use complex::{Complex, c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident => |$s:ident| $scalar:expr),+,) => {
+            $(
+                #[quickcheck]
+                fn $t((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < m,
+                        col < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$t>((m + 1, n + 1));
+                    let v = a.slice((1.., 1..));
+
+                    let (u, s, vt) = v.try_svd_thin().unwrap();
+
+                    let x = (0..s.nrows()).fold($t::zero(), |acc, i| {
+                        let $s = s[i];
+
+                        acc + u[(row, i)] * $scalar * vt[(i, col)]
+                    });
+
+                    test_approx_eq!(x, v[(row, col)])
+                }
+             )+
+        };
+    }
+
+    tests! {
+        f32 => |s| s,
+        f64 => |s| s,
+        c64 => |s| Complex { re: s, im: 0. },
+        c128 => |s| Complex { re: s, im: 0. },
+    }
+}
+
+use complex::{Complex, c64, c128};
+use linalg::prelude::*;
+use onezero::Zero;
+use quickcheck::TestResult;
+
+macro_rules! tests {
+    ($($t:ident => |$s:ident| $scalar:expr),+,) => {
+        $(
+            #[quickcheck]
+            fn $t((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                enforce! {
+                    row < m,
+                    col < n,
+                }
+
+                let a = ::setup::rand::mat::<$t>((m, n));
+                let b = a.clone();
+
+                let (u, s, vt) = a.svd();
+
+                test_eq!(u.size(), (m, m));
+                test_eq!(vt.size(), (n, n));
+
+                let x = (0..s.nrows()).fold($t::zero(), |acc, i| {
+                    let $s = s[i];
+
+                    acc + u[(row, i)] * $scalar * vt[(i, col)]
+                });
+
+                test_approx_eq!(x, b[(row, col)])
+            }
+         )+
+    };
+}
+
+tests! {
+    f32 => |s| s,
+    f64 => |s| s,
+    c64 => |s| Complex { re: s, im: 0. },
+    c128 => |s| Complex { re: s, im: 0. },
+}
@@ -0,0 +1,138 @@
+//! Test that:
+//!
+//! - `(A.sym(Upper) * x)[r] == sum(A[min(r, k), max(r, k)] * x[k] for k in 0..n)`
+//! - `(alpha * A.sym(Lower) * B)[r, c] == alpha * sum(A[max(r, k), min(r, k)] * B[k, c] for k in
+//!   0..n)`
+//! - `(A.t() * A)[r, c] == sum(A[k, r] * A[k, c] for k in 0..K)`
+//! - `C.set(A * A.t()); C[r, c] == sum(A[r, k] * A[c, k] for k in 0..K)`
+//!
+//! for any valid `r`, `c`
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+// A.sym(Upper) * x
+mod mul_col {
+    use std::cmp;
+
+    use complex::{c64, c128};
+    use linalg::Uplo;
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, row: u32) -> TestResult {
+                    enforce! {
+                        row < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((n, n));
+                    let ref x = ::setup::rand::col::<$ty>(n);
+
+                    let y = (a.sym(Uplo::Upper) * x).eval();
+
+                    let e = (0..n).fold($ty::zero(), |e, k| {
+                        e + a[(cmp::min(row, k), cmp::max(row, k))] * x[k]
+                    });
+
+                    test_approx_eq!(y[row], e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// alpha * A.sym(Lower) * B
+mod mul_mat {
+    use std::cmp;
+
+    use complex::{c64, c128};
+    use linalg::Uplo;
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((n, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < ncols,
+                    }
+
+                    let alpha: $ty = ::setup::rand::scalar();
+                    let ref a = ::setup::rand::mat::<$ty>((n, n));
+                    let ref b = ::setup::rand::mat::<$ty>((n, ncols));
+
+                    let c = (alpha * a.sym(Uplo::Lower) * b).eval();
+
+                    let e = (0..n).fold($ty::zero(), |e, k| {
+                        e + a[(cmp::max(row, k), cmp::min(row, k))] * b[(k, col)]
+                    });
+
+                    test_approx_eq!(c[(row, col)], alpha * e)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.t() * A, A * A.t()
+mod gram {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((m, n): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        m != 0,
+                        row < n,
+                        col < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((m, n));
+                    let ref b = ::setup::rand::mat::<$ty>((n, m));
+
+                    let c = (a.t() * a).eval();
+                    let mut d = Mat::<$ty>::zeros((n, n));
+                    d.set(b * b.t());
+
+                    let _0 = $ty::zero();
+                    let e = (0..m).fold(_0, |e, k| e + a[(k, row)] * a[(k, col)]);
+                    let f = (0..m).fold(_0, |f, k| f + b[(row, k)] * b[(col, k)]);
+
+                    test_approx_eq!(c[(row, col)], e);
+                    test_approx_eq!(d[(row, col)], f)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
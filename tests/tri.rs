@@ -0,0 +1,179 @@
+//! Test that:
+//!
+//! - `(A.lower() * x)[r] == sum(A[r, k] * x[k] for k in 0..r+1)`
+//! - `(A.unit_upper() * x)[r] == x[r] + sum(A[r, k] * x[k] for k in r+1..n)`
+//! - `(alpha * A.upper() * B)[r, c] == alpha * sum(A[r, k] * B[k, c] for k in r..n)`
+//! - `C.set(A.unit_lower() * B); C[r, c] == B[r, c] + sum(A[r, k] * B[k, c] for k in 0..r)`
+//! - `A.lower().solve(A.lower() * x)[r] == x[r]`
+//! - `A.upper().solve(alpha * (A.upper() * X))[r, c] == alpha * X[r, c]`
+//!
+//! for any valid `r`, `c`
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate onezero;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+use std::ops::Add;
+
+use linalg::prelude::*;
+use onezero::One;
+
+/// Makes the diagonal of `a` dominant, keeps the triangular systems well conditioned
+fn dominant<T>(a: &mut Mat<T>) where T: Add<Output=T> + Copy + One {
+    let n = a.nrows();
+
+    for i in 0..n {
+        a[(i, i)] = (0..n).fold(a[(i, i)], |d, _| d + T::one());
+    }
+}
+
+// A.lower() * x, A.unit_upper() * x
+mod mul_col {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, row: u32) -> TestResult {
+                    enforce! {
+                        row < n,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$ty>((n, n));
+                    let ref x = ::setup::rand::col::<$ty>(n);
+
+                    let y = (a.lower() * x).eval();
+                    let z = (a.unit_upper() * x).eval();
+
+                    let _0 = $ty::zero();
+                    let e = (0..row + 1).fold(_0, |e, k| e + a[(row, k)] * x[k]);
+                    let f = (row + 1..n).fold(x[row], |f, k| f + a[(row, k)] * x[k]);
+
+                    test_approx_eq!(y[row], e);
+                    test_approx_eq!(z[row], f)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// alpha * A.upper() * B, C.set(A.unit_lower() * B)
+mod mul_mat {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use onezero::Zero;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((n, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < ncols,
+                    }
+
+                    let alpha: $ty = ::setup::rand::scalar();
+                    let ref a = ::setup::rand::mat::<$ty>((n, n));
+                    let ref b = ::setup::rand::mat::<$ty>((n, ncols));
+
+                    let c = (alpha * a.upper() * b).eval();
+                    let mut d = Mat::<$ty>::zeros((n, ncols));
+                    d.set(a.unit_lower() * b);
+
+                    let _0 = $ty::zero();
+                    let e = (row..n).fold(_0, |e, k| e + a[(row, k)] * b[(k, col)]);
+                    let f = (0..row).fold(b[(row, col)], |f, k| f + a[(row, k)] * b[(k, col)]);
+
+                    test_approx_eq!(c[(row, col)], alpha * e);
+                    test_approx_eq!(d[(row, col)], f)
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.lower().solve(A.lower() * x)
+mod solve_col {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty(n: u32, row: u32) -> TestResult {
+                    enforce! {
+                        row < n,
+                    }
+
+                    let ref mut a = ::setup::rand::mat::<$ty>((n, n));
+                    ::dominant(a);
+                    let a = &*a;
+                    let ref x = ::setup::rand::col::<$ty>(n);
+
+                    let b = (a.lower() * x).eval();
+                    let y = a.lower().solve(b);
+
+                    test_approx_eq!(y[row], x[row])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+// A.upper().solve(alpha * (A.upper() * X))
+mod solve_mat {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($ty:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $ty((n, ncols): (u32, u32), (row, col): (u32, u32)) -> TestResult {
+                    enforce! {
+                        row < n,
+                        col < ncols,
+                    }
+
+                    let alpha: $ty = ::setup::rand::scalar();
+                    let ref mut a = ::setup::rand::mat::<$ty>((n, n));
+                    ::dominant(a);
+                    let a = &*a;
+                    let ref x = ::setup::rand::mat::<$ty>((n, ncols));
+
+                    let b = (a.upper() * x).eval();
+                    let y = a.upper().solve(alpha * b);
+
+                    test_approx_eq!(y[(row, col)], alpha * x[(row, col)])
+                }
+             )+
+        }
+    }
+
+    tests!(f32, f64, c64, c128);
+}
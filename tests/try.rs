@@ -0,0 +1,193 @@
+//! Test that:
+//!
+//! - `A.try_add_assign(B)` fails iff `A.size() != B.size()`
+//! - `Z.try_inv()` fails, where `Z` is a square matrix of zeros
+//! - `A.try_mul(B)` fails iff `A.ncols() != B.nrows()`
+//! - `A.try_set(B)` fails iff `A.size() != B.size()`
+//! - `Z.try_solve(b)` fails, where `Z` is a square band matrix of zeros
+//! - `Z.lu().try_solve(b)` fails, where `Z` is a square matrix of zeros
+
+#![feature(custom_attribute)]
+#![feature(plugin)]
+#![plugin(quickcheck_macros)]
+
+extern crate approx;
+extern crate complex;
+extern crate linalg;
+extern crate quickcheck;
+extern crate rand;
+
+#[macro_use]
+mod setup;
+
+mod add_assign {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::Error;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(lhs: (u32, u32), rhs: (u32, u32)) -> TestResult {
+                    let mut a = ::setup::rand::mat::<$t>(lhs);
+                    let ref b = ::setup::rand::mat::<$t>(rhs);
+
+                    if lhs == rhs {
+                        test!(a.try_add_assign(b).is_ok())
+                    } else {
+                        let error = Error::DimensionMismatch { lhs: lhs, rhs: rhs };
+
+                        test_eq!(a.try_add_assign(b).err(), Some(error))
+                    }
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod inv {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::Error;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n != 0,
+                    }
+
+                    let z = Mat::<$t>::zeros((n, n));
+
+                    test_eq!(z.try_inv().err(), Some(Error::Singular { pivot: 0 }))
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod mul {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::Error;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(lhs: (u32, u32), rhs: (u32, u32)) -> TestResult {
+                    enforce! {
+                        lhs.1 != rhs.0,
+                    }
+
+                    let ref a = ::setup::rand::mat::<$t>(lhs);
+                    let ref b = ::setup::rand::mat::<$t>(rhs);
+
+                    let error = Error::DimensionMismatch { lhs: lhs, rhs: rhs };
+
+                    test_eq!(a.try_mul(b).err(), Some(error))
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+mod set {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::Error;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(lhs: (u32, u32), rhs: (u32, u32)) -> TestResult {
+                    let mut a = ::setup::rand::mat::<$t>(lhs);
+                    let ref b = ::setup::rand::mat::<$t>(rhs);
+
+                    if lhs == rhs {
+                        test!(a.try_set(b.slice(..)).is_ok())
+                    } else {
+                        let error = Error::DimensionMismatch { lhs: lhs, rhs: rhs };
+
+                        test_eq!(a.try_set(b.slice(..)).err(), Some(error))
+                    }
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod solve_band {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::Error;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32, (kl, ku): (u32, u32)) -> TestResult {
+                    enforce! {
+                        n != 0,
+                        kl < n,
+                        ku < n,
+                    }
+
+                    let z = BandMat::<$t>::zeros(n, (kl, ku));
+                    let ref b = ::setup::rand::col::<$t>(n);
+
+                    test_eq!(z.try_solve(b).err(), Some(Error::Singular { pivot: 0 }))
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}
+
+#[cfg(all(feature = "lapack", not(feature = "pure-rust")))]
+mod solve_lu {
+    use complex::{c64, c128};
+    use linalg::prelude::*;
+    use linalg::Error;
+    use quickcheck::TestResult;
+
+    macro_rules! tests {
+        ($($t:ident),+) => {
+            $(
+                #[quickcheck]
+                fn $t(n: u32) -> TestResult {
+                    enforce! {
+                        n != 0,
+                    }
+
+                    let ref lu = Mat::<$t>::zeros((n, n)).lu();
+                    let ref b = ::setup::rand::col::<$t>(n);
+
+                    test_eq!(lu.try_solve(b).err(), Some(Error::Singular { pivot: 0 }))
+                }
+             )+
+        };
+    }
+
+    tests!(f32, f64, c64, c128);
+}